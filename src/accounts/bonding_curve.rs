@@ -19,15 +19,193 @@
 //! # Methods
 //!
 //! - `new`: Creates a new bonding curve instance
+//! - `decode_versioned`: Decodes a bonding curve account written with any known layout
 //! - `get_buy_price`: Calculates the amount of tokens received for a given SOL amount
 //! - `get_sell_price`: Calculates the amount of SOL received for selling tokens
 //! - `get_market_cap_sol`: Calculates the current market cap in SOL
+//! - `get_market_cap_usd`: Calculates the current market cap in USD
 //! - `get_final_market_cap_sol`: Calculates the final market cap in SOL after all tokens are sold
 //! - `get_buy_out_price`: Calculates the price to buy out all remaining tokens
+//! - `get_buy_quote`: Quotes a buy for a given SOL amount, including fees
+//! - `get_sell_quote`: Quotes a sell of a given token amount, including fees
+//! - `get_buy_quote_exact_tokens`: Quotes the SOL needed to buy a given token amount, including fees
+//! - `get_sell_quote_exact_sol`: Quotes the tokens needed to receive a given SOL amount after fees
+//! - `apply_buy`: Applies a buy to the curve's reserves as the program does
+//! - `apply_sell`: Applies a sell to the curve's reserves as the program does
+//! - `apply_trade_event`: Updates the curve's reserves from a trade event
+//! - `get_graduation_progress`: Calculates how close the curve is to graduating
+//! - `get_buy_completion`: Checks whether a buy for a given SOL amount completes the curve
+//! - `get_buy_completion_exact_tokens`: Checks whether a buy of a given token amount completes the curve
+//!
+//! All calculations use checked arithmetic and report failures as a `CurveMathError`
+//! instead of panicking or wrapping.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
 
+use super::{Fees, GlobalAccount};
+use crate::{
+    common::{price::lamports_to_usd, types::Decoded},
+    error::CurveMathError,
+};
+
+/// Breakdown of a buy on a bonding curve
+///
+/// The amounts match the ones charged by the program and reported in the resulting
+/// `TradeEvent`: `net_sol_amount` is the event's `sol_amount`, and the fees are its
+/// `fee` and `creator_fee`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuyQuote {
+    /// Tokens received by the buyer
+    pub token_amount: u64,
+    /// SOL paid by the buyer, including fees
+    pub gross_sol_amount: u64,
+    /// SOL entering the bonding curve, excluding fees
+    pub net_sol_amount: u64,
+    /// Fee paid to the protocol fee recipient
+    pub protocol_fee: u64,
+    /// Fee paid to the creator vault
+    pub creator_fee: u64,
+    /// Protocol fee in basis points
+    pub fee_basis_points: u64,
+    /// Creator fee in basis points, zero if the curve has no creator
+    pub creator_fee_basis_points: u64,
+    /// Average price paid including fees, in lamports per token unit
+    pub effective_price: f64,
+    /// Relative increase of the average price excluding fees over the spot price
+    pub price_impact: f64,
+}
+
+/// Breakdown of a sell on a bonding curve
+///
+/// The amounts match the ones paid out by the program and reported in the resulting
+/// `TradeEvent`: `gross_sol_amount` is the event's `sol_amount`, and the fees are its
+/// `fee` and `creator_fee`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SellQuote {
+    /// Tokens sold by the seller
+    pub token_amount: u64,
+    /// SOL leaving the bonding curve, including fees
+    pub gross_sol_amount: u64,
+    /// SOL received by the seller, excluding fees
+    pub net_sol_amount: u64,
+    /// Fee paid to the protocol fee recipient
+    pub protocol_fee: u64,
+    /// Fee paid to the creator vault
+    pub creator_fee: u64,
+    /// Protocol fee in basis points
+    pub fee_basis_points: u64,
+    /// Creator fee in basis points, zero if the curve has no creator
+    pub creator_fee_basis_points: u64,
+    /// Average price received after fees, in lamports per token unit
+    pub effective_price: f64,
+    /// Relative decrease of the average price before fees below the spot price
+    pub price_impact: f64,
+}
+
+/// Amounts of a buy or sell quote valued in USD
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UsdQuote {
+    /// Value of the gross SOL amount, including fees
+    pub gross_usd: f64,
+    /// Value of the net SOL amount, excluding fees
+    pub net_usd: f64,
+    /// Value of the protocol fee
+    pub protocol_fee_usd: f64,
+    /// Value of the creator fee
+    pub creator_fee_usd: f64,
+}
+
+impl UsdQuote {
+    /// Values the SOL amounts of a quote at a given SOL price
+    fn new(
+        gross_sol_amount: u64,
+        net_sol_amount: u64,
+        protocol_fee: u64,
+        creator_fee: u64,
+        sol_price_usd: f64,
+    ) -> Self {
+        Self {
+            gross_usd: lamports_to_usd(gross_sol_amount as u128, sol_price_usd),
+            net_usd: lamports_to_usd(net_sol_amount as u128, sol_price_usd),
+            protocol_fee_usd: lamports_to_usd(protocol_fee as u128, sol_price_usd),
+            creator_fee_usd: lamports_to_usd(creator_fee as u128, sol_price_usd),
+        }
+    }
+}
+
+impl BuyQuote {
+    /// Values the quote in USD
+    ///
+    /// # Arguments
+    /// * `sol_price_usd` - Price of one SOL in USD, see `SolPriceSource`
+    pub fn to_usd(&self, sol_price_usd: f64) -> UsdQuote {
+        UsdQuote::new(
+            self.gross_sol_amount,
+            self.net_sol_amount,
+            self.protocol_fee,
+            self.creator_fee,
+            sol_price_usd,
+        )
+    }
+}
+
+impl SellQuote {
+    /// Values the quote in USD
+    ///
+    /// # Arguments
+    /// * `sol_price_usd` - Price of one SOL in USD, see `SolPriceSource`
+    pub fn to_usd(&self, sol_price_usd: f64) -> UsdQuote {
+        UsdQuote::new(
+            self.gross_sol_amount,
+            self.net_sol_amount,
+            self.protocol_fee,
+            self.creator_fee,
+            sol_price_usd,
+        )
+    }
+}
+
+/// Progress of a bonding curve towards graduating
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraduationProgress {
+    /// Tokens sold out of the initial real token reserves
+    pub tokens_sold: u64,
+    /// Tokens left to sell before the curve completes
+    pub tokens_remaining: u64,
+    /// Share of the initial real token reserves sold, from 0 to 100
+    pub progress_percent: f64,
+    /// SOL needed to buy the remaining tokens, including fees
+    pub sol_to_complete: u64,
+    /// SOL entering the bonding curve when buying the remaining tokens, excluding fees
+    pub net_sol_to_complete: u64,
+    /// Whether the curve is complete
+    pub complete: bool,
+}
+
+/// Outcome of a buy with respect to completing a bonding curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuyCompletion {
+    /// Buy as executed by the program, after capping
+    pub quote: BuyQuote,
+    /// Whether the buy sells the remaining real token reserves and completes the curve
+    pub completes_curve: bool,
+    /// Tokens requested beyond the real token reserves, which are not bought
+    pub capped_token_amount: u64,
+    /// Part of the SOL amount the buy does not charge, mostly because it is capped
+    pub refunded_sol_amount: u64,
+}
+
+/// Calculates a fee the way the program does, rounding up
+fn calculate_fee(amount: u64, fee_basis_points: u64) -> Result<u64, CurveMathError> {
+    to_u64(((amount as u128) * (fee_basis_points as u128)).div_ceil(10_000))
+}
+
+/// Converts an intermediate `u128` result back to `u64`
+fn to_u64(value: u128) -> Result<u64, CurveMathError> {
+    u64::try_from(value).map_err(|_| CurveMathError::Overflow)
+}
+
 /// Represents a bonding curve for token pricing and liquidity management
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct BondingCurveAccount {
@@ -50,6 +228,15 @@ pub struct BondingCurveAccount {
 }
 
 impl BondingCurveAccount {
+    /// Account discriminator used to identify this account
+    pub const DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+    /// Serialized lengths of the known account layouts, oldest first
+    ///
+    /// 1. Original layout, up to `complete`
+    /// 2. Adds the `creator`
+    const LAYOUTS: [usize; 2] = [49, 81];
+
     /// Creates a new bonding curve instance
     ///
     /// # Arguments
@@ -83,6 +270,20 @@ impl BondingCurveAccount {
         }
     }
 
+    /// Decodes a bonding curve account written with any known layout
+    ///
+    /// Fields missing from older layouts take their default value, so curves created
+    /// before the creator was recorded decode with a default `creator`.
+    ///
+    /// # Arguments
+    /// * `data` - Account data, including the discriminator
+    ///
+    /// # Returns
+    /// The decoded account and the version of the layout it was written with
+    pub fn decode_versioned(data: &[u8]) -> std::io::Result<Decoded<Self>> {
+        super::decode_with_layouts(data, &Self::LAYOUTS)
+    }
+
     /// Calculates the amount of tokens received for a given SOL amount
     ///
     /// # Arguments
@@ -90,10 +291,10 @@ impl BondingCurveAccount {
     ///
     /// # Returns
     /// * `Ok(u64)` - Amount of tokens that would be received
-    /// * `Err(&str)` - Error message if curve is complete
-    pub fn get_buy_price(&self, amount: u64) -> Result<u64, &'static str> {
+    /// * `Err(CurveMathError)` - Error if curve is complete or the reserves cannot support the buy
+    pub fn get_buy_price(&self, amount: u64) -> Result<u64, CurveMathError> {
         if self.complete {
-            return Err("Curve is complete");
+            return Err(CurveMathError::CurveComplete);
         }
 
         if amount == 0 {
@@ -110,15 +311,12 @@ impl BondingCurveAccount {
         let r: u128 = n / i + 1;
 
         // Calculate the amount of tokens to be purchased
-        let s: u128 = (self.virtual_token_reserves as u128) - r;
+        let s: u128 = (self.virtual_token_reserves as u128)
+            .checked_sub(r)
+            .ok_or(CurveMathError::InsufficientTokenReserves)?;
 
-        // Convert back to u64 and return the minimum of calculated tokens and real reserves
-        let s_u64 = s as u64;
-        Ok(if s_u64 < self.real_token_reserves {
-            s_u64
-        } else {
-            self.real_token_reserves
-        })
+        // Return the minimum of calculated tokens and real reserves
+        Ok(to_u64(s)?.min(self.real_token_reserves))
     }
 
     /// Calculates the amount of SOL received for selling tokens
@@ -129,10 +327,14 @@ impl BondingCurveAccount {
     ///
     /// # Returns
     /// * `Ok(u64)` - Amount of SOL that would be received after fees
-    /// * `Err(&str)` - Error message if curve is complete
-    pub fn get_sell_price(&self, amount: u64, fee_basis_points: u64) -> Result<u64, &'static str> {
+    /// * `Err(CurveMathError)` - Error if curve is complete or the fee exceeds the proceeds
+    pub fn get_sell_price(
+        &self,
+        amount: u64,
+        fee_basis_points: u64,
+    ) -> Result<u64, CurveMathError> {
         if self.complete {
-            return Err("Curve is complete");
+            return Err(CurveMathError::CurveComplete);
         }
 
         if amount == 0 {
@@ -146,36 +348,55 @@ impl BondingCurveAccount {
         // Calculate the fee amount in the same units
         let a: u128 = (n * (fee_basis_points as u128)) / 10000;
 
-        // Return the net amount after deducting the fee, converting back to u64
-        Ok((n - a) as u64)
+        // Return the net amount after deducting the fee
+        to_u64(n.checked_sub(a).ok_or(CurveMathError::Underflow)?)
     }
 
     /// Calculates the current market cap in SOL
-    pub fn get_market_cap_sol(&self) -> u64 {
+    pub fn get_market_cap_sol(&self) -> Result<u64, CurveMathError> {
+        to_u64(self.get_market_cap_lamports())
+    }
+
+    /// Calculates the current market cap in USD
+    ///
+    /// # Arguments
+    /// * `sol_price_usd` - Price of one SOL in USD, see `SolPriceSource`
+    pub fn get_market_cap_usd(&self, sol_price_usd: f64) -> f64 {
+        lamports_to_usd(self.get_market_cap_lamports(), sol_price_usd)
+    }
+
+    /// Calculates the current market cap in lamports without narrowing it to `u64`
+    pub(crate) fn get_market_cap_lamports(&self) -> u128 {
         if self.virtual_token_reserves == 0 {
             return 0;
         }
 
-        ((self.token_total_supply as u128) * (self.virtual_sol_reserves as u128)
-            / (self.virtual_token_reserves as u128)) as u64
+        (self.token_total_supply as u128) * (self.virtual_sol_reserves as u128)
+            / (self.virtual_token_reserves as u128)
     }
 
     /// Calculates the final market cap in SOL after all tokens are sold
     ///
     /// # Arguments
     /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
-    pub fn get_final_market_cap_sol(&self, fee_basis_points: u64) -> u64 {
+    pub fn get_final_market_cap_sol(&self, fee_basis_points: u64) -> Result<u64, CurveMathError> {
         let total_sell_value: u128 =
-            self.get_buy_out_price(self.real_token_reserves, fee_basis_points) as u128;
+            self.get_buy_out_price(self.real_token_reserves, fee_basis_points)? as u128;
         let total_virtual_value: u128 = (self.virtual_sol_reserves as u128) + total_sell_value;
-        let total_virtual_tokens: u128 =
-            (self.virtual_token_reserves as u128) - (self.real_token_reserves as u128);
+        let total_virtual_tokens: u128 = (self.virtual_token_reserves as u128)
+            .checked_sub(self.real_token_reserves as u128)
+            .ok_or(CurveMathError::InsufficientTokenReserves)?;
 
         if total_virtual_tokens == 0 {
-            return 0;
+            return Ok(0);
         }
 
-        ((self.token_total_supply as u128) * total_virtual_value / total_virtual_tokens) as u64
+        to_u64(
+            (self.token_total_supply as u128)
+                .checked_mul(total_virtual_value)
+                .ok_or(CurveMathError::Overflow)?
+                / total_virtual_tokens,
+        )
     }
 
     /// Calculates the price to buy out all remaining tokens
@@ -183,7 +404,11 @@ impl BondingCurveAccount {
     /// # Arguments
     /// * `amount` - Amount of tokens to buy
     /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
-    pub fn get_buy_out_price(&self, amount: u64, fee_basis_points: u64) -> u64 {
+    pub fn get_buy_out_price(
+        &self,
+        amount: u64,
+        fee_basis_points: u64,
+    ) -> Result<u64, CurveMathError> {
         // Get the effective amount of sol tokens
         let sol_tokens: u128 = if amount < self.real_sol_reserves {
             self.real_sol_reserves as u128
@@ -192,21 +417,550 @@ impl BondingCurveAccount {
         };
 
         // Calculate total sell value
+        let remaining_tokens = (self.virtual_token_reserves as u128)
+            .checked_sub(sol_tokens)
+            .ok_or(CurveMathError::InsufficientTokenReserves)?;
         let total_sell_value: u128 = (sol_tokens * (self.virtual_sol_reserves as u128))
-            / ((self.virtual_token_reserves as u128) - sol_tokens)
+            .checked_div(remaining_tokens)
+            .ok_or(CurveMathError::DivisionByZero)?
             + 1;
 
         // Calculate fee
-        let fee: u128 = (total_sell_value * (fee_basis_points as u128)) / 10000;
+        let fee: u128 = total_sell_value
+            .checked_mul(fee_basis_points as u128)
+            .ok_or(CurveMathError::Overflow)?
+            / 10000;
+
+        // Return total including fee
+        to_u64(
+            total_sell_value
+                .checked_add(fee)
+                .ok_or(CurveMathError::Overflow)?,
+        )
+    }
+
+    /// Calculates the current spot price in lamports per token unit
+    pub fn get_spot_price(&self) -> f64 {
+        if self.virtual_token_reserves == 0 {
+            return 0.0;
+        }
+
+        self.virtual_sol_reserves as f64 / self.virtual_token_reserves as f64
+    }
+
+    /// Quotes a buy for a given amount of SOL, including fees
+    ///
+    /// The SOL amount is the total budget of the buyer. The quote contains the tokens
+    /// that budget buys after protocol and creator fees, and the exact amounts the
+    /// program charges for them. The creator fee only applies if the curve has a creator.
+    ///
+    /// # Arguments
+    /// * `amount_sol` - Amount of SOL to spend, including fees
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyQuote)` - Breakdown of the buy
+    /// * `Err(CurveMathError)` - Error if curve is complete or the amounts do not fit
+    pub fn get_buy_quote(&self, amount_sol: u64, fees: &Fees) -> Result<BuyQuote, CurveMathError> {
+        let (fee_basis_points, creator_fee_basis_points) = self.get_fee_basis_points(fees);
+        self.get_buy_quote_with_fees(amount_sol, fee_basis_points, creator_fee_basis_points)
+    }
+
+    /// Quotes a sell of a given amount of tokens, including fees
+    ///
+    /// The curve can only pay out its real SOL reserves, as the program does, so sells
+    /// paying out more fail.
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens to sell
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(SellQuote)` - Breakdown of the sell
+    /// * `Err(CurveMathError)` - Error if curve is complete, the real SOL reserves cannot
+    ///   pay out the sell or the amounts do not fit
+    pub fn get_sell_quote(
+        &self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<SellQuote, CurveMathError> {
+        if self.complete {
+            return Err(CurveMathError::CurveComplete);
+        }
+
+        let (fee_basis_points, creator_fee_basis_points) = self.get_fee_basis_points(fees);
+        let gross_sol_amount = self.get_gross_sell_amount(amount_token)?;
+        if gross_sol_amount > self.real_sol_reserves {
+            return Err(CurveMathError::InsufficientSolReserves);
+        }
+        let protocol_fee = calculate_fee(gross_sol_amount, fee_basis_points)?;
+        let creator_fee = calculate_fee(gross_sol_amount, creator_fee_basis_points)?;
+        let net_sol_amount = gross_sol_amount.saturating_sub(
+            protocol_fee
+                .checked_add(creator_fee)
+                .ok_or(CurveMathError::Overflow)?,
+        );
+
+        let spot_price = self.get_spot_price();
+        let (effective_price, price_impact) = if amount_token == 0 || spot_price == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let execution_price = gross_sol_amount as f64 / amount_token as f64;
+            (
+                net_sol_amount as f64 / amount_token as f64,
+                (spot_price - execution_price) / spot_price,
+            )
+        };
+
+        Ok(SellQuote {
+            token_amount: amount_token,
+            gross_sol_amount,
+            net_sol_amount,
+            protocol_fee,
+            creator_fee,
+            fee_basis_points,
+            creator_fee_basis_points,
+            effective_price,
+            price_impact,
+        })
+    }
+
+    /// Quotes the SOL needed to buy a given amount of tokens, including fees
+    ///
+    /// The token amount is capped at the real token reserves of the curve, as the program
+    /// does. The quoted `gross_sol_amount` is the exact amount the buy costs, and the basis
+    /// for the `max_sol_cost` of the buy instruction.
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens to buy
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyQuote)` - Breakdown of the buy
+    /// * `Err(CurveMathError)` - Error if curve is complete or the amounts do not fit
+    pub fn get_buy_quote_exact_tokens(
+        &self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<BuyQuote, CurveMathError> {
+        let (fee_basis_points, creator_fee_basis_points) = self.get_fee_basis_points(fees);
+        self.get_buy_quote_exact_tokens_with_fees(
+            amount_token,
+            fee_basis_points,
+            creator_fee_basis_points,
+        )
+    }
+
+    /// Quotes the tokens needed to receive a given amount of SOL after fees
+    ///
+    /// Finds the smallest token amount whose sell pays out at least `amount_sol` after the
+    /// protocol and creator fees, taking the rounding of the program into account.
+    ///
+    /// # Arguments
+    /// * `amount_sol` - Amount of SOL to receive, excluding fees
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(SellQuote)` - Breakdown of the sell
+    /// * `Err(CurveMathError)` - Error if curve is complete or cannot pay out the amount
+    pub fn get_sell_quote_exact_sol(
+        &self,
+        amount_sol: u64,
+        fees: &Fees,
+    ) -> Result<SellQuote, CurveMathError> {
+        if amount_sol == 0 {
+            return self.get_sell_quote(0, fees);
+        }
+
+        // The payout grows with the token amount, so search for the largest amount the
+        // real SOL reserves can pay out. `low` always can, `high` never does.
+        let (mut low, mut high) = (0u64, u64::MAX);
+        if self.get_gross_sell_amount(high)? <= self.real_sol_reserves {
+            low = high;
+        }
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.get_gross_sell_amount(mid)? <= self.real_sol_reserves {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let max_amount_token = low;
+
+        if self.get_sell_quote(max_amount_token, fees)?.net_sol_amount < amount_sol {
+            return Err(CurveMathError::InsufficientSolReserves);
+        }
+
+        // Then search for the smallest amount reaching the target. `high` always pays out
+        // enough, `low` never does.
+        let (mut low, mut high) = (0u64, max_amount_token);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.get_sell_quote(mid, fees)?.net_sol_amount >= amount_sol {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        self.get_sell_quote(high, fees)
+    }
+
+    /// Calculates the SOL a sell of a given amount of tokens takes from the curve, before
+    /// fees and regardless of the real SOL reserves
+    fn get_gross_sell_amount(&self, amount_token: u64) -> Result<u64, CurveMathError> {
+        if amount_token == 0 || self.virtual_token_reserves == 0 {
+            return Ok(0);
+        }
+
+        to_u64(
+            (amount_token as u128) * (self.virtual_sol_reserves as u128)
+                / ((self.virtual_token_reserves as u128) + (amount_token as u128)),
+        )
+    }
+
+    /// Applies a buy of a given amount of tokens to the curve
+    ///
+    /// Updates the virtual and real reserves the way the buy instruction does, and marks
+    /// the curve complete once its real token reserves are sold out. The curve is left
+    /// unchanged if the buy fails.
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens bought, capped at the real token reserves
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyQuote)` - Breakdown of the applied buy
+    /// * `Err(CurveMathError)` - Error if curve is complete or the reserves would overflow
+    pub fn apply_buy(
+        &mut self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<BuyQuote, CurveMathError> {
+        let quote = self.get_buy_quote_exact_tokens(amount_token, fees)?;
+
+        let virtual_token_reserves = self
+            .virtual_token_reserves
+            .checked_sub(quote.token_amount)
+            .ok_or(CurveMathError::InsufficientTokenReserves)?;
+        let real_token_reserves = self
+            .real_token_reserves
+            .checked_sub(quote.token_amount)
+            .ok_or(CurveMathError::InsufficientTokenReserves)?;
+        let virtual_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_add(quote.net_sol_amount)
+            .ok_or(CurveMathError::Overflow)?;
+        let real_sol_reserves = self
+            .real_sol_reserves
+            .checked_add(quote.net_sol_amount)
+            .ok_or(CurveMathError::Overflow)?;
+
+        self.virtual_token_reserves = virtual_token_reserves;
+        self.real_token_reserves = real_token_reserves;
+        self.virtual_sol_reserves = virtual_sol_reserves;
+        self.real_sol_reserves = real_sol_reserves;
+        if self.real_token_reserves == 0 {
+            self.complete = true;
+        }
+
+        Ok(quote)
+    }
+
+    /// Applies a sell of a given amount of tokens to the curve
+    ///
+    /// Updates the virtual and real reserves the way the sell instruction does. The curve
+    /// is left unchanged if the sell fails.
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens sold
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(SellQuote)` - Breakdown of the applied sell
+    /// * `Err(CurveMathError)` - Error if curve is complete or cannot pay out the sell
+    pub fn apply_sell(
+        &mut self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<SellQuote, CurveMathError> {
+        let quote = self.get_sell_quote(amount_token, fees)?;
+
+        let real_sol_reserves = self
+            .real_sol_reserves
+            .checked_sub(quote.gross_sol_amount)
+            .ok_or(CurveMathError::InsufficientSolReserves)?;
+        let virtual_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_sub(quote.gross_sol_amount)
+            .ok_or(CurveMathError::InsufficientSolReserves)?;
+        let virtual_token_reserves = self
+            .virtual_token_reserves
+            .checked_add(quote.token_amount)
+            .ok_or(CurveMathError::Overflow)?;
+        let real_token_reserves = self
+            .real_token_reserves
+            .checked_add(quote.token_amount)
+            .ok_or(CurveMathError::Overflow)?;
+
+        self.virtual_token_reserves = virtual_token_reserves;
+        self.real_token_reserves = real_token_reserves;
+        self.virtual_sol_reserves = virtual_sol_reserves;
+        self.real_sol_reserves = real_sol_reserves;
+
+        Ok(quote)
+    }
+
+    /// Updates the curve from a trade event of the program
+    ///
+    /// Trade events report the reserves of the curve after the trade, so applying the
+    /// events of a curve in order keeps a snapshot in sync without fetching the account.
+    ///
+    /// # Arguments
+    /// * `event` - Trade event emitted for this curve's mint
+    #[cfg(feature = "stream")]
+    pub fn apply_trade_event(&mut self, event: &crate::common::stream::TradeEvent) {
+        self.virtual_token_reserves = event.virtual_token_reserves;
+        self.virtual_sol_reserves = event.virtual_sol_reserves;
+        self.real_token_reserves = event.real_token_reserves;
+        self.real_sol_reserves = event.real_sol_reserves;
+        if event.is_buy && event.real_token_reserves == 0 {
+            self.complete = true;
+        }
+        if event.creator != Pubkey::default() {
+            self.creator = event.creator;
+        }
+    }
+
+    /// Calculates how close the curve is to graduating
+    ///
+    /// Progress is measured against the initial real token reserves of the global
+    /// account: the curve completes once all of them are sold.
+    ///
+    /// # Arguments
+    /// * `global` - Global account holding the initial curve parameters
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(GraduationProgress)` - Tokens sold and left, and the SOL needed to complete
+    /// * `Err(CurveMathError)` - Error if the reserves cannot support buying the rest
+    pub fn get_graduation_progress(
+        &self,
+        global: &GlobalAccount,
+        fees: &Fees,
+    ) -> Result<GraduationProgress, CurveMathError> {
+        let tokens_remaining = if self.complete {
+            0
+        } else {
+            self.real_token_reserves
+        };
+        let tokens_sold = global
+            .initial_real_token_reserves
+            .saturating_sub(tokens_remaining);
+        let progress_percent = if self.complete {
+            100.0
+        } else if global.initial_real_token_reserves == 0 {
+            0.0
+        } else {
+            tokens_sold as f64 * 100.0 / global.initial_real_token_reserves as f64
+        };
+
+        let (sol_to_complete, net_sol_to_complete) = if self.complete {
+            (0, 0)
+        } else {
+            let quote = self.get_buy_quote_exact_tokens(tokens_remaining, fees)?;
+            (quote.gross_sol_amount, quote.net_sol_amount)
+        };
+
+        Ok(GraduationProgress {
+            tokens_sold,
+            tokens_remaining,
+            progress_percent,
+            sol_to_complete,
+            net_sol_to_complete,
+            complete: self.complete,
+        })
+    }
 
-        // Return total including fee, converting back to u64
-        (total_sell_value + fee) as u64
+    /// Checks whether a buy for a given amount of SOL would complete the curve
+    ///
+    /// A budget larger than needed to buy the remaining tokens is capped by the program:
+    /// only the cost of the remaining tokens is charged and the rest stays with the buyer.
+    ///
+    /// # Arguments
+    /// * `amount_sol` - Amount of SOL to spend, including fees
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyCompletion)` - The quoted buy and what of it is capped or refunded
+    /// * `Err(CurveMathError)` - Error if curve is complete or the amounts do not fit
+    pub fn get_buy_completion(
+        &self,
+        amount_sol: u64,
+        fees: &Fees,
+    ) -> Result<BuyCompletion, CurveMathError> {
+        let quote = self.get_buy_quote(amount_sol, fees)?;
+        let (fee_basis_points, creator_fee_basis_points) = self.get_fee_basis_points(fees);
+        let requested_token_amount =
+            self.get_buy_token_amount(amount_sol, fee_basis_points, creator_fee_basis_points)?;
+
+        Ok(self.get_completion(quote, requested_token_amount, amount_sol))
+    }
+
+    /// Checks whether a buy of a given amount of tokens would complete the curve
+    ///
+    /// Tokens requested beyond the real token reserves are capped by the program.
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens to buy
+    /// * `fees` - Fees applying to the curve, see `GlobalAccount::get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyCompletion)` - The quoted buy and how many tokens are capped
+    /// * `Err(CurveMathError)` - Error if curve is complete or the amounts do not fit
+    pub fn get_buy_completion_exact_tokens(
+        &self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<BuyCompletion, CurveMathError> {
+        let quote = self.get_buy_quote_exact_tokens(amount_token, fees)?;
+        Ok(self.get_completion(quote, amount_token, quote.gross_sol_amount))
+    }
+
+    /// Quotes a buy for a given amount of SOL with explicit fee rates
+    pub(crate) fn get_buy_quote_with_fees(
+        &self,
+        amount_sol: u64,
+        fee_basis_points: u64,
+        creator_fee_basis_points: u64,
+    ) -> Result<BuyQuote, CurveMathError> {
+        if self.complete {
+            return Err(CurveMathError::CurveComplete);
+        }
+
+        let token_amount = self
+            .get_buy_token_amount(amount_sol, fee_basis_points, creator_fee_basis_points)?
+            .min(self.real_token_reserves);
+
+        self.get_buy_quote_exact_tokens_with_fees(
+            token_amount,
+            fee_basis_points,
+            creator_fee_basis_points,
+        )
+    }
+
+    /// Calculates the tokens a SOL budget buys before capping at the real token reserves
+    fn get_buy_token_amount(
+        &self,
+        amount_sol: u64,
+        fee_basis_points: u64,
+        creator_fee_basis_points: u64,
+    ) -> Result<u64, CurveMathError> {
+        // Share of the budget entering the curve, leaving room for the rounded up fees
+        let total_fee_basis_points =
+            (fee_basis_points as u128) + (creator_fee_basis_points as u128);
+        let input_amount =
+            (amount_sol.saturating_sub(1) as u128) * 10_000 / (10_000 + total_fee_basis_points);
+        if input_amount == 0 || self.virtual_token_reserves == 0 {
+            return Ok(0);
+        }
+
+        to_u64(
+            input_amount * (self.virtual_token_reserves as u128)
+                / ((self.virtual_sol_reserves as u128) + input_amount),
+        )
+    }
+
+    /// Quotes the exact amounts the program charges for buying a given amount of tokens,
+    /// with explicit fee rates
+    pub(crate) fn get_buy_quote_exact_tokens_with_fees(
+        &self,
+        token_amount: u64,
+        fee_basis_points: u64,
+        creator_fee_basis_points: u64,
+    ) -> Result<BuyQuote, CurveMathError> {
+        if self.complete {
+            return Err(CurveMathError::CurveComplete);
+        }
+
+        // The program never sells more than the real token reserves
+        let token_amount = token_amount.min(self.real_token_reserves);
+        if token_amount >= self.virtual_token_reserves && token_amount > 0 {
+            return Err(CurveMathError::InsufficientTokenReserves);
+        }
+
+        let net_sol_amount = if token_amount == 0 {
+            0
+        } else {
+            to_u64(
+                (token_amount as u128) * (self.virtual_sol_reserves as u128)
+                    / ((self.virtual_token_reserves - token_amount) as u128)
+                    + 1,
+            )?
+        };
+        let protocol_fee = calculate_fee(net_sol_amount, fee_basis_points)?;
+        let creator_fee = calculate_fee(net_sol_amount, creator_fee_basis_points)?;
+        let gross_sol_amount = net_sol_amount
+            .checked_add(protocol_fee)
+            .and_then(|amount| amount.checked_add(creator_fee))
+            .ok_or(CurveMathError::Overflow)?;
+
+        let spot_price = self.get_spot_price();
+        let (effective_price, price_impact) = if token_amount == 0 || spot_price == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let execution_price = net_sol_amount as f64 / token_amount as f64;
+            (
+                gross_sol_amount as f64 / token_amount as f64,
+                (execution_price - spot_price) / spot_price,
+            )
+        };
+
+        Ok(BuyQuote {
+            token_amount,
+            gross_sol_amount,
+            net_sol_amount,
+            protocol_fee,
+            creator_fee,
+            fee_basis_points,
+            creator_fee_basis_points,
+            effective_price,
+            price_impact,
+        })
+    }
+
+    /// Compares a quoted buy with the requested amounts
+    fn get_completion(
+        &self,
+        quote: BuyQuote,
+        requested_token_amount: u64,
+        amount_sol: u64,
+    ) -> BuyCompletion {
+        BuyCompletion {
+            quote,
+            completes_curve: quote.token_amount > 0
+                && quote.token_amount == self.real_token_reserves,
+            capped_token_amount: requested_token_amount.saturating_sub(quote.token_amount),
+            refunded_sol_amount: amount_sol.saturating_sub(quote.gross_sol_amount),
+        }
+    }
+
+    /// Returns the protocol and creator fee rates charged on this curve
+    fn get_fee_basis_points(&self, fees: &Fees) -> (u64, u64) {
+        let creator_fee_basis_points = if self.creator != Pubkey::default() {
+            fees.creator_fee_bps
+        } else {
+            0
+        };
+        (fees.protocol_fee_bps, creator_fee_basis_points)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     fn get_bonding_curve() -> BondingCurveAccount {
         BondingCurveAccount::new(
@@ -252,6 +1006,34 @@ mod tests {
         assert!(sell_price > 0);
     }
 
+    #[test]
+    fn test_decode_versioned() {
+        let bonding_curve = get_pump_bonding_curve();
+        let data = borsh::to_vec(&bonding_curve).unwrap();
+
+        let decoded = BondingCurveAccount::decode_versioned(&data).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.creator, bonding_curve.creator);
+
+        // Curves created before the creator was recorded
+        let decoded = BondingCurveAccount::decode_versioned(&data[..49]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(
+            decoded.value.virtual_token_reserves,
+            bonding_curve.virtual_token_reserves
+        );
+        assert_eq!(decoded.value.creator, Pubkey::default());
+
+        // Accounts extended past the known layouts
+        let mut extended = data.clone();
+        extended.resize(150, 0);
+        let decoded = BondingCurveAccount::decode_versioned(&extended).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.creator, bonding_curve.creator);
+
+        assert!(BondingCurveAccount::decode_versioned(&data[..40]).is_err());
+    }
+
     #[test]
     fn test_bonding_curve_complete() {
         let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
@@ -273,10 +1055,10 @@ mod tests {
         let bonding_curve: BondingCurveAccount = get_bonding_curve();
 
         // Test market cap calculations
-        let market_cap = bonding_curve.get_market_cap_sol();
+        let market_cap = bonding_curve.get_market_cap_sol().unwrap();
         assert!(market_cap > 0);
 
-        let final_market_cap = bonding_curve.get_final_market_cap_sol(250);
+        let final_market_cap = bonding_curve.get_final_market_cap_sol(250).unwrap();
         assert!(final_market_cap > 0);
     }
 
@@ -284,14 +1066,422 @@ mod tests {
     fn test_buy_out_price() {
         let bonding_curve: BondingCurveAccount = get_bonding_curve();
 
-        let buy_out_price = bonding_curve.get_buy_out_price(100, 250);
+        let buy_out_price = bonding_curve.get_buy_out_price(100, 250).unwrap();
         assert!(buy_out_price > 0);
 
         // Test with amount less than real_sol_reserves
-        let small_buy_out = bonding_curve.get_buy_out_price(400, 250);
+        let small_buy_out = bonding_curve.get_buy_out_price(400, 250).unwrap();
         assert!(small_buy_out > 0);
     }
 
+    fn get_pump_bonding_curve() -> BondingCurveAccount {
+        BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000, // initial virtual token reserves
+            30_000_000_000,        // initial virtual sol reserves
+            793_100_000_000_000,   // initial real token reserves
+            0,
+            1_000_000_000_000_000,
+            false,
+            Pubkey::new_unique(),
+        )
+    }
+
+    fn get_fees() -> Fees {
+        Fees {
+            lp_fee_bps: 0,
+            protocol_fee_bps: 95,
+            creator_fee_bps: 30,
+        }
+    }
+
+    #[test]
+    fn test_buy_quote() {
+        let bonding_curve = get_pump_bonding_curve();
+        let quote = bonding_curve
+            .get_buy_quote(1_000_000_000, &get_fees())
+            .unwrap();
+
+        // Amounts as charged by the program for the quoted tokens
+        assert_eq!(quote.token_amount, 34_199_203_154_141);
+        assert_eq!(quote.net_sol_amount, 987_654_320);
+        assert_eq!(quote.protocol_fee, 9_382_717);
+        assert_eq!(quote.creator_fee, 2_962_963);
+        assert_eq!(
+            quote.gross_sol_amount,
+            quote.net_sol_amount + quote.protocol_fee + quote.creator_fee
+        );
+        assert_eq!(quote.gross_sol_amount, 1_000_000_000);
+        assert_eq!(quote.fee_basis_points, 95);
+        assert_eq!(quote.creator_fee_basis_points, 30);
+
+        assert!(quote.effective_price > bonding_curve.get_spot_price());
+        assert!(quote.price_impact > 0.0 && quote.price_impact < 0.05);
+    }
+
+    #[test]
+    fn test_usd_values() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.real_sol_reserves = 5_000_000_000;
+        let market_cap = bonding_curve.get_market_cap_sol().unwrap();
+        assert_eq!(market_cap, 27_958_993_476);
+        assert_eq!(
+            bonding_curve.get_market_cap_usd(150.0),
+            market_cap as f64 / 1e9 * 150.0
+        );
+
+        let quote = bonding_curve
+            .get_buy_quote(1_000_000_000, &get_fees())
+            .unwrap()
+            .to_usd(100.0);
+        assert_eq!(quote.gross_usd, 100.0);
+        assert!(
+            (quote.net_usd + quote.protocol_fee_usd + quote.creator_fee_usd - quote.gross_usd)
+                .abs()
+                < 1e-9
+        );
+
+        let quote = bonding_curve
+            .get_sell_quote(34_199_203_154_141, &get_fees())
+            .unwrap()
+            .to_usd(100.0);
+        assert!((quote.net_usd - 91.505_791_3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_buy_quote_without_creator() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.creator = Pubkey::default();
+        let quote = bonding_curve
+            .get_buy_quote(1_000_000_000, &get_fees())
+            .unwrap();
+
+        assert_eq!(quote.creator_fee, 0);
+        assert_eq!(quote.creator_fee_basis_points, 0);
+    }
+
+    #[test]
+    fn test_buy_quote_capped_by_real_reserves() {
+        let bonding_curve = get_bonding_curve();
+        let quote = bonding_curve
+            .get_buy_quote(u32::MAX as u64, &get_fees())
+            .unwrap();
+
+        assert_eq!(quote.token_amount, bonding_curve.real_token_reserves);
+        assert_eq!(quote.net_sol_amount, 1001);
+    }
+
+    #[test]
+    fn test_sell_quote() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.real_sol_reserves = 5_000_000_000;
+        let quote = bonding_curve
+            .get_sell_quote(34_199_203_154_141, &get_fees())
+            .unwrap();
+
+        assert_eq!(quote.gross_sol_amount, 926_640_925);
+        assert_eq!(quote.protocol_fee, 8_803_089);
+        assert_eq!(quote.creator_fee, 2_779_923);
+        assert_eq!(quote.net_sol_amount, 915_057_913);
+
+        assert!(quote.effective_price < bonding_curve.get_spot_price());
+        assert!(quote.price_impact > 0.0 && quote.price_impact < 0.05);
+    }
+
+    #[test]
+    fn test_buy_quote_exact_tokens() {
+        let bonding_curve = get_pump_bonding_curve();
+        let quote = bonding_curve
+            .get_buy_quote_exact_tokens(34_199_203_154_141, &get_fees())
+            .unwrap();
+
+        // Inverse of the 1 SOL buy quote
+        assert_eq!(quote.token_amount, 34_199_203_154_141);
+        assert_eq!(quote.gross_sol_amount, 1_000_000_000);
+
+        // Requests above the real reserves are capped
+        let quote = bonding_curve
+            .get_buy_quote_exact_tokens(u64::MAX, &get_fees())
+            .unwrap();
+        assert_eq!(quote.token_amount, bonding_curve.real_token_reserves);
+    }
+
+    #[test]
+    fn test_sell_quote_exact_sol() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.real_sol_reserves = 5_000_000_000;
+        let fees = get_fees();
+
+        for amount_sol in [1, 1_000, 915_057_913, 2_000_000_000] {
+            let quote = bonding_curve
+                .get_sell_quote_exact_sol(amount_sol, &fees)
+                .unwrap();
+            assert!(quote.net_sol_amount >= amount_sol);
+
+            // One token less no longer pays out the requested amount
+            let smaller = bonding_curve
+                .get_sell_quote(quote.token_amount - 1, &fees)
+                .unwrap();
+            assert!(smaller.net_sol_amount < amount_sol);
+        }
+
+        assert!(bonding_curve
+            .get_sell_quote_exact_sol(bonding_curve.virtual_sol_reserves, &fees)
+            .is_err());
+    }
+
+    #[test]
+    fn test_sell_quotes_capped_by_real_reserves() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.real_sol_reserves = 1_000_000_000;
+        let fees = get_fees();
+
+        // 2 SOL is below the virtual SOL reserves, but above the real ones
+        assert_eq!(
+            bonding_curve
+                .get_sell_quote_exact_sol(2_000_000_000, &fees)
+                .unwrap_err(),
+            CurveMathError::InsufficientSolReserves
+        );
+        assert_eq!(
+            bonding_curve
+                .get_sell_quote(100_000_000_000_000, &fees)
+                .unwrap_err(),
+            CurveMathError::InsufficientSolReserves
+        );
+
+        // The largest payout stays within the real reserves, for a realistic token amount
+        let quote = bonding_curve
+            .get_sell_quote_exact_sol(980_000_000, &fees)
+            .unwrap();
+        assert!(quote.net_sol_amount >= 980_000_000);
+        assert!(quote.gross_sol_amount <= bonding_curve.real_sol_reserves);
+        assert!(quote.token_amount < bonding_curve.token_total_supply);
+        assert!(bonding_curve.apply_sell(quote.token_amount, &fees).is_ok());
+    }
+
+    #[test]
+    fn test_apply_buy_and_sell() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        let initial = bonding_curve.clone();
+        let fees = get_fees();
+
+        let buy = bonding_curve.apply_buy(34_199_203_154_141, &fees).unwrap();
+        assert_eq!(
+            bonding_curve.virtual_token_reserves,
+            initial.virtual_token_reserves - buy.token_amount
+        );
+        assert_eq!(
+            bonding_curve.real_token_reserves,
+            initial.real_token_reserves - buy.token_amount
+        );
+        assert_eq!(
+            bonding_curve.virtual_sol_reserves,
+            initial.virtual_sol_reserves + buy.net_sol_amount
+        );
+        assert_eq!(bonding_curve.real_sol_reserves, buy.net_sol_amount);
+        assert!(!bonding_curve.complete);
+
+        // Selling the tokens back returns the curve to its initial token reserves
+        let sell = bonding_curve.apply_sell(buy.token_amount, &fees).unwrap();
+        assert_eq!(
+            bonding_curve.virtual_token_reserves,
+            initial.virtual_token_reserves
+        );
+        assert_eq!(
+            bonding_curve.real_token_reserves,
+            initial.real_token_reserves
+        );
+        assert!(sell.gross_sol_amount <= buy.net_sol_amount);
+        assert_eq!(
+            bonding_curve.real_sol_reserves,
+            buy.net_sol_amount - sell.gross_sol_amount
+        );
+    }
+
+    #[test]
+    fn test_apply_buy_completes_curve() {
+        let mut bonding_curve = get_pump_bonding_curve();
+        let fees = get_fees();
+
+        let buy = bonding_curve.apply_buy(u64::MAX, &fees).unwrap();
+        assert_eq!(buy.token_amount, 793_100_000_000_000);
+        assert_eq!(bonding_curve.real_token_reserves, 0);
+        assert!(bonding_curve.complete);
+
+        assert!(bonding_curve.apply_buy(1, &fees).is_err());
+        assert!(bonding_curve.apply_sell(1, &fees).is_err());
+    }
+
+    #[test]
+    fn test_apply_sell_insufficient_reserves() {
+        let mut bonding_curve = get_pump_bonding_curve();
+
+        // The curve holds no real SOL to pay out yet
+        assert!(bonding_curve.apply_sell(1_000_000, &get_fees()).is_err());
+        assert_eq!(bonding_curve.real_sol_reserves, 0);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn test_apply_trade_event() {
+        use crate::common::stream::TradeEvent;
+
+        let mut expected = get_pump_bonding_curve();
+        let buy = expected.apply_buy(1_000_000_000_000, &get_fees()).unwrap();
+
+        let mut bonding_curve = get_pump_bonding_curve();
+        bonding_curve.apply_trade_event(&TradeEvent {
+            mint: Pubkey::new_unique(),
+            sol_amount: buy.net_sol_amount,
+            token_amount: buy.token_amount,
+            is_buy: true,
+            user: Pubkey::new_unique(),
+            timestamp: 0,
+            virtual_sol_reserves: expected.virtual_sol_reserves,
+            virtual_token_reserves: expected.virtual_token_reserves,
+            real_sol_reserves: expected.real_sol_reserves,
+            real_token_reserves: expected.real_token_reserves,
+            fee_recipient: Pubkey::new_unique(),
+            fee_basis_points: buy.fee_basis_points,
+            fee: buy.protocol_fee,
+            creator: expected.creator,
+            creator_fee_basis_points: buy.creator_fee_basis_points,
+            creator_fee: buy.creator_fee,
+            track_volume: false,
+            total_unclaimed_tokens: 0,
+            total_claimed_tokens: 0,
+            current_sol_volume: 0,
+            last_update_timestamp: 0,
+            ix_name: None,
+        });
+
+        assert_eq!(
+            bonding_curve.virtual_token_reserves,
+            expected.virtual_token_reserves
+        );
+        assert_eq!(
+            bonding_curve.virtual_sol_reserves,
+            expected.virtual_sol_reserves
+        );
+        assert_eq!(
+            bonding_curve.real_token_reserves,
+            expected.real_token_reserves
+        );
+        assert_eq!(bonding_curve.real_sol_reserves, expected.real_sol_reserves);
+        assert!(!bonding_curve.complete);
+    }
+
+    fn get_pump_global() -> GlobalAccount {
+        GlobalAccount::new(
+            1,
+            true,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            1_000_000_000_000_000,
+            95,
+            Pubkey::new_unique(),
+            true,
+            15_000_001,
+            30,
+            [Pubkey::new_unique(); 7],
+            Pubkey::new_unique(),
+        )
+    }
+
+    #[test]
+    fn test_graduation_progress() {
+        let global = get_pump_global();
+        let fees = get_fees();
+        let mut bonding_curve = get_pump_bonding_curve();
+
+        let progress = bonding_curve
+            .get_graduation_progress(&global, &fees)
+            .unwrap();
+        assert_eq!(progress.tokens_sold, 0);
+        assert_eq!(progress.tokens_remaining, 793_100_000_000_000);
+        assert_eq!(progress.progress_percent, 0.0);
+        assert_eq!(progress.net_sol_to_complete, 85_005_359_057);
+        assert_eq!(progress.sol_to_complete, 86_067_926_047);
+        assert!(!progress.complete);
+
+        bonding_curve.apply_buy(34_199_203_154_141, &fees).unwrap();
+        let progress = bonding_curve
+            .get_graduation_progress(&global, &fees)
+            .unwrap();
+        assert_eq!(progress.tokens_sold, 34_199_203_154_141);
+        assert_eq!(
+            progress.tokens_sold + progress.tokens_remaining,
+            global.initial_real_token_reserves
+        );
+        assert!((progress.progress_percent - 4.312_092_189_4).abs() < 1e-9);
+        assert_eq!(progress.sol_to_complete, 85_067_926_048);
+
+        bonding_curve.apply_buy(u64::MAX, &fees).unwrap();
+        let progress = bonding_curve
+            .get_graduation_progress(&global, &fees)
+            .unwrap();
+        assert_eq!(progress.tokens_remaining, 0);
+        assert_eq!(progress.progress_percent, 100.0);
+        assert_eq!(progress.sol_to_complete, 0);
+        assert!(progress.complete);
+    }
+
+    #[test]
+    fn test_buy_completion() {
+        let bonding_curve = get_pump_bonding_curve();
+        let fees = get_fees();
+
+        let completion = bonding_curve
+            .get_buy_completion(1_000_000_000, &fees)
+            .unwrap();
+        assert!(!completion.completes_curve);
+        assert_eq!(completion.capped_token_amount, 0);
+        assert_eq!(completion.refunded_sol_amount, 0);
+
+        // A budget above the cost of the remaining tokens is capped
+        let completion = bonding_curve
+            .get_buy_completion(100_000_000_000, &fees)
+            .unwrap();
+        assert!(completion.completes_curve);
+        assert_eq!(
+            completion.quote.token_amount,
+            bonding_curve.real_token_reserves
+        );
+        assert_eq!(completion.quote.gross_sol_amount, 86_067_926_047);
+        assert_eq!(completion.refunded_sol_amount, 13_932_073_953);
+        assert!(completion.capped_token_amount > 0);
+
+        let completion = bonding_curve
+            .get_buy_completion_exact_tokens(u64::MAX, &fees)
+            .unwrap();
+        assert!(completion.completes_curve);
+        assert_eq!(
+            completion.capped_token_amount,
+            u64::MAX - bonding_curve.real_token_reserves
+        );
+        assert_eq!(completion.refunded_sol_amount, 0);
+    }
+
+    #[test]
+    fn test_quotes_zero_and_complete() {
+        let mut bonding_curve = get_pump_bonding_curve();
+
+        let buy = bonding_curve.get_buy_quote(0, &get_fees()).unwrap();
+        assert_eq!(buy.token_amount, 0);
+        assert_eq!(buy.gross_sol_amount, 0);
+
+        let sell = bonding_curve.get_sell_quote(0, &get_fees()).unwrap();
+        assert_eq!(sell.net_sol_amount, 0);
+
+        bonding_curve.complete = true;
+        assert!(bonding_curve.get_buy_quote(100, &get_fees()).is_err());
+        assert!(bonding_curve.get_sell_quote(100, &get_fees()).is_err());
+    }
+
     #[test]
     fn test_overflow_buy_price() {
         let bonding_curve = get_large_bonding_curve();
@@ -316,11 +1506,14 @@ mod tests {
         let bonding_curve = get_large_bonding_curve();
 
         // Test market cap with large values
-        let market_cap = bonding_curve.get_market_cap_sol();
+        let market_cap = bonding_curve.get_market_cap_sol().unwrap();
         assert!(market_cap > 0);
 
-        let final_market_cap = bonding_curve.get_final_market_cap_sol(250);
-        assert!(final_market_cap > 0);
+        // The final market cap exceeds u64::MAX and is reported instead of wrapped
+        assert_eq!(
+            bonding_curve.get_final_market_cap_sol(250),
+            Err(CurveMathError::Overflow)
+        );
     }
 
     #[test]
@@ -328,7 +1521,107 @@ mod tests {
         let bonding_curve = get_large_bonding_curve();
 
         // Test buy out with large token amount
-        let buy_out_price = bonding_curve.get_buy_out_price(u64::MAX / 4, 250);
+        let buy_out_price = bonding_curve.get_buy_out_price(u64::MAX / 4, 250).unwrap();
         assert!(buy_out_price > 0);
     }
+
+    fn arb_bonding_curve() -> impl Strategy<Value = BondingCurveAccount> {
+        (
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    virtual_token_reserves,
+                    virtual_sol_reserves,
+                    real_token_reserves,
+                    real_sol_reserves,
+                    token_total_supply,
+                    complete,
+                    has_creator,
+                )| {
+                    BondingCurveAccount::new(
+                        1,
+                        virtual_token_reserves,
+                        virtual_sol_reserves,
+                        real_token_reserves,
+                        real_sol_reserves,
+                        token_total_supply,
+                        complete,
+                        if has_creator {
+                            Pubkey::new_unique()
+                        } else {
+                            Pubkey::default()
+                        },
+                    )
+                },
+            )
+    }
+
+    fn arb_fees() -> impl Strategy<Value = Fees> {
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(
+            |(lp_fee_bps, protocol_fee_bps, creator_fee_bps)| Fees {
+                lp_fee_bps,
+                protocol_fee_bps,
+                creator_fee_bps,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn prop_curve_math_never_panics(
+            bonding_curve in arb_bonding_curve(),
+            amount in any::<u64>(),
+            fee_basis_points in any::<u64>(),
+            fees in arb_fees(),
+        ) {
+            let _ = bonding_curve.get_buy_price(amount);
+            let _ = bonding_curve.get_sell_price(amount, fee_basis_points);
+            let _ = bonding_curve.get_market_cap_sol();
+            let _ = bonding_curve.get_final_market_cap_sol(fee_basis_points);
+            let _ = bonding_curve.get_buy_out_price(amount, fee_basis_points);
+            let _ = bonding_curve.get_spot_price();
+            let _ = bonding_curve.get_buy_quote(amount, &fees);
+            let _ = bonding_curve.get_sell_quote(amount, &fees);
+            let _ = bonding_curve.get_buy_quote_exact_tokens(amount, &fees);
+            let _ = bonding_curve.get_graduation_progress(&get_pump_global(), &fees);
+            let _ = bonding_curve.get_buy_completion(amount, &fees);
+            let _ = bonding_curve.get_buy_completion_exact_tokens(amount, &fees);
+        }
+
+        #[test]
+        fn prop_sell_quote_exact_sol_never_panics(
+            bonding_curve in arb_bonding_curve(),
+            amount_sol in any::<u64>(),
+            fees in arb_fees(),
+        ) {
+            let _ = bonding_curve.get_sell_quote_exact_sol(amount_sol, &fees);
+        }
+
+        #[test]
+        fn prop_apply_trades_never_panic(
+            bonding_curve in arb_bonding_curve(),
+            amount_token in any::<u64>(),
+            fees in arb_fees(),
+        ) {
+            let before = borsh::to_vec(&bonding_curve).unwrap();
+
+            // A failed trade leaves the curve untouched
+            let mut bought = bonding_curve.clone();
+            if bought.apply_buy(amount_token, &fees).is_err() {
+                prop_assert_eq!(borsh::to_vec(&bought).unwrap(), before.clone());
+            }
+
+            let mut sold = bonding_curve.clone();
+            if sold.apply_sell(amount_token, &fees).is_err() {
+                prop_assert_eq!(borsh::to_vec(&sold).unwrap(), before);
+            }
+        }
+    }
 }
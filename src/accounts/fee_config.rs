@@ -0,0 +1,209 @@
+//! Fee configuration account for the Pump.fun fee program
+//!
+//! This module contains the definition for the fee configuration account.
+//!
+//! # Fee Config Account
+//!
+//! The fee configuration account is owned by the Pump.fun fee program and defines the
+//! trading fees charged by the bonding curve. Fees are tiered by the market cap of the
+//! bonding curve: each tier applies from its market cap threshold up to the threshold of
+//! the next tier.
+//!
+//! # Fields
+//!
+//! - `discriminator`: Unique identifier for the fee configuration account
+//! - `bump`: Bump seed of the fee configuration PDA
+//! - `admin`: Authority that can modify the fee configuration
+//! - `flat_fees`: Fees applied when no tiers are configured
+//! - `fee_tiers`: Fee tiers ordered by ascending market cap threshold
+//!
+//! # Methods
+//!
+//! - `new`: Creates a new fee configuration instance
+//! - `get_fees`: Resolves the fees applying at a given market cap
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Trading fees in basis points (1/100th of a percent)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Fees {
+    /// Fee paid to liquidity providers, only charged by the AMM after migration
+    pub lp_fee_bps: u64,
+    /// Fee paid to the protocol fee recipients
+    pub protocol_fee_bps: u64,
+    /// Fee paid to the creator vault of the token
+    pub creator_fee_bps: u64,
+}
+
+impl Fees {
+    /// Calculates the total fee charged by the bonding curve on a trade
+    ///
+    /// The bonding curve charges the protocol fee on every trade, and the creator fee only
+    /// when the curve has a creator. The LP fee is not charged by the bonding curve.
+    ///
+    /// # Arguments
+    /// * `has_creator` - Whether the bonding curve has a creator set
+    ///
+    /// # Returns
+    /// Total fee in basis points
+    pub fn get_trade_fee_basis_points(&self, has_creator: bool) -> u64 {
+        if has_creator {
+            self.protocol_fee_bps + self.creator_fee_bps
+        } else {
+            self.protocol_fee_bps
+        }
+    }
+}
+
+/// A fee tier applying from a market cap threshold
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeTier {
+    /// Market cap in lamports from which the tier applies
+    pub market_cap_lamports_threshold: u128,
+    /// Fees charged within the tier
+    pub fees: Fees,
+}
+
+/// Represents the fee configuration of the bonding curve
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct FeeConfig {
+    /// Unique identifier for the fee configuration account
+    pub discriminator: u64,
+    /// Bump seed of the fee configuration PDA
+    pub bump: u8,
+    /// Authority that can modify the fee configuration
+    pub admin: Pubkey,
+    /// Fees applied when no tiers are configured
+    pub flat_fees: Fees,
+    /// Fee tiers ordered by ascending market cap threshold
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+impl FeeConfig {
+    /// Account discriminator used to identify this account
+    pub const DISCRIMINATOR: [u8; 8] = [143, 52, 146, 187, 219, 123, 76, 155];
+
+    /// Creates a new fee configuration instance
+    ///
+    /// # Arguments
+    /// * `discriminator` - Unique identifier for the account
+    /// * `bump` - Bump seed of the fee configuration PDA
+    /// * `admin` - Authority that can modify the fee configuration
+    /// * `flat_fees` - Fees applied when no tiers are configured
+    /// * `fee_tiers` - Fee tiers ordered by ascending market cap threshold
+    pub fn new(
+        discriminator: u64,
+        bump: u8,
+        admin: Pubkey,
+        flat_fees: Fees,
+        fee_tiers: Vec<FeeTier>,
+    ) -> Self {
+        Self {
+            discriminator,
+            bump,
+            admin,
+            flat_fees,
+            fee_tiers,
+        }
+    }
+
+    /// Resolves the fees applying at a given market cap
+    ///
+    /// Market caps below the first threshold use the first tier. Otherwise the tier with
+    /// the highest threshold not above the market cap applies.
+    ///
+    /// # Arguments
+    /// * `market_cap_lamports` - Market cap of the bonding curve in lamports
+    ///
+    /// # Returns
+    /// Fees of the matching tier, or the flat fees if no tiers are configured
+    pub fn get_fees(&self, market_cap_lamports: u128) -> Fees {
+        let Some(first_tier) = self.fee_tiers.first() else {
+            return self.flat_fees;
+        };
+
+        if market_cap_lamports < first_tier.market_cap_lamports_threshold {
+            return first_tier.fees;
+        }
+
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|tier| market_cap_lamports >= tier.market_cap_lamports_threshold)
+            .map_or(first_tier.fees, |tier| tier.fees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fees(protocol_fee_bps: u64, creator_fee_bps: u64) -> Fees {
+        Fees {
+            lp_fee_bps: 0,
+            protocol_fee_bps,
+            creator_fee_bps,
+        }
+    }
+
+    fn get_fee_config() -> FeeConfig {
+        FeeConfig::new(
+            1,
+            255,
+            Pubkey::new_unique(),
+            fees(100, 0),
+            vec![
+                FeeTier {
+                    market_cap_lamports_threshold: 100,
+                    fees: fees(95, 30),
+                },
+                FeeTier {
+                    market_cap_lamports_threshold: 1_000,
+                    fees: fees(90, 20),
+                },
+                FeeTier {
+                    market_cap_lamports_threshold: 10_000,
+                    fees: fees(80, 10),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_fee_config_layout() {
+        let fee_config = get_fee_config();
+        let data = borsh::to_vec(&fee_config).unwrap();
+        assert_eq!(data.len(), 8 + 1 + 32 + 24 + 4 + 3 * (16 + 24));
+
+        let decoded = FeeConfig::try_from_slice(&data).unwrap();
+        assert_eq!(decoded.admin, fee_config.admin);
+        assert_eq!(decoded.fee_tiers, fee_config.fee_tiers);
+    }
+
+    #[test]
+    fn test_get_fees_tiers() {
+        let fee_config = get_fee_config();
+
+        assert_eq!(fee_config.get_fees(0), fees(95, 30));
+        assert_eq!(fee_config.get_fees(100), fees(95, 30));
+        assert_eq!(fee_config.get_fees(999), fees(95, 30));
+        assert_eq!(fee_config.get_fees(1_000), fees(90, 20));
+        assert_eq!(fee_config.get_fees(10_000), fees(80, 10));
+        assert_eq!(fee_config.get_fees(u128::MAX), fees(80, 10));
+    }
+
+    #[test]
+    fn test_get_fees_flat() {
+        let mut fee_config = get_fee_config();
+        fee_config.fee_tiers.clear();
+
+        assert_eq!(fee_config.get_fees(5_000), fees(100, 0));
+    }
+
+    #[test]
+    fn test_trade_fee_basis_points() {
+        assert_eq!(fees(95, 30).get_trade_fee_basis_points(true), 125);
+        assert_eq!(fees(95, 30).get_trade_fee_basis_points(false), 95);
+    }
+}
@@ -26,11 +26,20 @@
 //! # Methods
 //!
 //! - `new`: Creates a new global account instance
+//! - `decode_versioned`: Decodes a global account written with any known layout
 //! - `get_initial_buy_price`: Calculates the initial amount of tokens received for a given SOL amount
+//! - `get_initial_market_cap_sol`: Calculates the market cap in SOL of a newly created bonding curve
+//! - `get_fees`: Resolves the protocol, creator and LP fees applying to a bonding curve
+//! - `get_initial_bonding_curve`: Builds the state of a newly created bonding curve
+//! - `get_initial_buy_quote`: Quotes a buy on a newly created bonding curve, including fees
+//! - `get_initial_buy_quote_exact_tokens`: Quotes the SOL needed to buy a given token amount on a newly created bonding curve
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
 
+use super::{BondingCurveAccount, BuyQuote, FeeConfig, Fees};
+use crate::{common::types::Decoded, error::CurveMathError};
+
 /// Represents the global configuration account for token pricing and fees
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct GlobalAccount {
@@ -67,6 +76,16 @@ pub struct GlobalAccount {
 }
 
 impl GlobalAccount {
+    /// Account discriminator used to identify this account
+    pub const DISCRIMINATOR: [u8; 8] = [167, 232, 232, 177, 200, 108, 114, 127];
+
+    /// Serialized lengths of the known account layouts, oldest first
+    ///
+    /// 1. Original layout, up to `fee_basis_points`
+    /// 2. Adds the migration settings, up to `pool_migration_fee`
+    /// 3. Adds the creator fee, the fee recipients and the set creator authority
+    const LAYOUTS: [usize; 3] = [113, 154, 418];
+
     /// Creates a new global account instance
     ///
     /// # Arguments
@@ -122,35 +141,144 @@ impl GlobalAccount {
         }
     }
 
+    /// Decodes a global account written with any known layout
+    ///
+    /// Fields missing from older layouts take their default value, so account data
+    /// fetched at old slots decodes as well as the current one.
+    ///
+    /// # Arguments
+    /// * `data` - Account data, including the discriminator
+    ///
+    /// # Returns
+    /// The decoded account and the version of the layout it was written with
+    pub fn decode_versioned(data: &[u8]) -> std::io::Result<Decoded<Self>> {
+        super::decode_with_layouts(data, &Self::LAYOUTS)
+    }
+
     /// Calculates the initial amount of tokens received for a given SOL amount
     ///
     /// # Arguments
     /// * `amount` - Amount of SOL to spend
     ///
     /// # Returns
-    /// Amount of tokens that would be received
-    pub fn get_initial_buy_price(&self, amount: u64) -> u64 {
-        if amount == 0 {
-            return 0;
-        }
+    /// * `Ok(u64)` - Amount of tokens that would be received
+    /// * `Err(CurveMathError)` - Error if the initial reserves cannot support the buy
+    pub fn get_initial_buy_price(&self, amount: u64) -> Result<u64, CurveMathError> {
+        self.get_initial_bonding_curve(&Pubkey::default())
+            .get_buy_price(amount)
+    }
 
-        let n: u128 = (self.initial_virtual_sol_reserves as u128)
-            * (self.initial_virtual_token_reserves as u128);
-        let i: u128 = (self.initial_virtual_sol_reserves as u128) + (amount as u128);
-        let r: u128 = n / i + 1;
-        let s: u128 = (self.initial_virtual_token_reserves as u128) - r;
+    /// Calculates the market cap in SOL of a newly created bonding curve
+    pub fn get_initial_market_cap_sol(&self) -> Result<u64, CurveMathError> {
+        self.get_initial_bonding_curve(&Pubkey::default())
+            .get_market_cap_sol()
+    }
 
-        if s < (self.initial_real_token_reserves as u128) {
-            s as u64
-        } else {
-            self.initial_real_token_reserves
+    /// Resolves the fees applying to trades on a bonding curve
+    ///
+    /// With a fee configuration the fees come from the tier matching the bonding curve's
+    /// market cap. Without one, the flat fees of the global account apply.
+    ///
+    /// # Arguments
+    /// * `fee_config` - Fee configuration account, if available
+    /// * `bonding_curve` - Bonding curve being traded, or None for a curve that is not created yet
+    ///
+    /// # Returns
+    /// Protocol, creator and LP fees in basis points
+    pub fn get_fees(
+        &self,
+        fee_config: Option<&FeeConfig>,
+        bonding_curve: Option<&BondingCurveAccount>,
+    ) -> Fees {
+        match fee_config {
+            Some(fee_config) => {
+                let market_cap = bonding_curve.map_or_else(
+                    || {
+                        self.get_initial_bonding_curve(&Pubkey::default())
+                            .get_market_cap_lamports()
+                    },
+                    |bonding_curve| bonding_curve.get_market_cap_lamports(),
+                );
+                fee_config.get_fees(market_cap)
+            }
+            None => Fees {
+                lp_fee_bps: 0,
+                protocol_fee_bps: self.fee_basis_points,
+                creator_fee_bps: self.creator_fee_basis_points,
+            },
         }
     }
+
+    /// Builds the state of a newly created bonding curve
+    ///
+    /// # Arguments
+    /// * `creator` - Creator of the token
+    ///
+    /// # Returns
+    /// Bonding curve account as initialized by the create instruction
+    pub fn get_initial_bonding_curve(&self, creator: &Pubkey) -> BondingCurveAccount {
+        BondingCurveAccount::new(
+            0,
+            self.initial_virtual_token_reserves,
+            self.initial_virtual_sol_reserves,
+            self.initial_real_token_reserves,
+            0,
+            self.token_total_supply,
+            false,
+            *creator,
+        )
+    }
+
+    /// Quotes a buy on a newly created bonding curve, including fees
+    ///
+    /// A new bonding curve always has a creator, so both the protocol and the creator
+    /// fee apply.
+    ///
+    /// # Arguments
+    /// * `amount_sol` - Amount of SOL to spend, including fees
+    /// * `fees` - Fees applying to a new curve, see `get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyQuote)` - Breakdown of the buy
+    /// * `Err(CurveMathError)` - Error if the initial reserves cannot support the buy
+    pub fn get_initial_buy_quote(
+        &self,
+        amount_sol: u64,
+        fees: &Fees,
+    ) -> Result<BuyQuote, CurveMathError> {
+        self.get_initial_bonding_curve(&Pubkey::default())
+            .get_buy_quote_with_fees(amount_sol, fees.protocol_fee_bps, fees.creator_fee_bps)
+    }
+
+    /// Quotes the SOL needed to buy a given amount of tokens on a newly created bonding
+    /// curve, including fees
+    ///
+    /// # Arguments
+    /// * `amount_token` - Amount of tokens to buy, capped at the initial real token reserves
+    /// * `fees` - Fees applying to a new curve, see `get_fees`
+    ///
+    /// # Returns
+    /// * `Ok(BuyQuote)` - Breakdown of the buy
+    /// * `Err(CurveMathError)` - Error if the initial reserves cannot support the buy
+    pub fn get_initial_buy_quote_exact_tokens(
+        &self,
+        amount_token: u64,
+        fees: &Fees,
+    ) -> Result<BuyQuote, CurveMathError> {
+        self.get_initial_bonding_curve(&Pubkey::default())
+            .get_buy_quote_exact_tokens_with_fees(
+                amount_token,
+                fees.protocol_fee_bps,
+                fees.creator_fee_bps,
+            )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::accounts::FeeTier;
+    use proptest::prelude::*;
 
     fn get_global() -> GlobalAccount {
         GlobalAccount::new(
@@ -197,20 +325,116 @@ mod tests {
         let global: GlobalAccount = get_global();
 
         // Test initial buy price calculation
-        assert_eq!(global.get_initial_buy_price(0), 0);
+        assert_eq!(global.get_initial_buy_price(0).unwrap(), 0);
 
-        let price: u64 = global.get_initial_buy_price(100);
+        let price: u64 = global.get_initial_buy_price(100).unwrap();
         assert!(price > 0);
         assert!(price <= global.initial_real_token_reserves);
     }
 
+    #[test]
+    fn test_global_account_decode_versioned() {
+        let global: GlobalAccount = get_global();
+        let data = borsh::to_vec(&global).unwrap();
+
+        let decoded = GlobalAccount::decode_versioned(&data).unwrap();
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.value.fee_recipients, global.fee_recipients);
+        assert_eq!(
+            decoded.value.set_creator_authority,
+            global.set_creator_authority
+        );
+
+        // Layout before the creator fee
+        let decoded = GlobalAccount::decode_versioned(&data[..154]).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.withdraw_authority, global.withdraw_authority);
+        assert_eq!(decoded.value.pool_migration_fee, 100);
+        assert_eq!(decoded.value.fee_recipients, [Pubkey::default(); 7]);
+
+        // Original layout
+        let decoded = GlobalAccount::decode_versioned(&data[..113]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.value.fee_basis_points, 250);
+        assert!(!decoded.value.enable_migrate);
+
+        assert!(GlobalAccount::decode_versioned(&data[..100]).is_err());
+    }
+
+    #[test]
+    fn test_global_account_fees() {
+        let global: GlobalAccount = get_global();
+
+        // Without a fee configuration the global fees apply
+        let fees = global.get_fees(None, None);
+        assert_eq!(fees.protocol_fee_bps, 250);
+        assert_eq!(fees.creator_fee_bps, 0);
+        assert_eq!(fees.lp_fee_bps, 0);
+
+        // With a fee configuration the tier matching the market cap applies
+        let tier = |threshold: u128, protocol_fee_bps: u64| FeeTier {
+            market_cap_lamports_threshold: threshold,
+            fees: Fees {
+                lp_fee_bps: 0,
+                protocol_fee_bps,
+                creator_fee_bps: 5,
+            },
+        };
+        let fee_config = FeeConfig::new(
+            1,
+            255,
+            Pubkey::new_unique(),
+            Fees::default(),
+            vec![tier(0, 95), tier(2000, 30)],
+        );
+        assert_eq!(global.get_initial_market_cap_sol().unwrap(), 1000);
+        assert_eq!(
+            global.get_fees(Some(&fee_config), None).protocol_fee_bps,
+            95
+        );
+
+        let bonding_curve =
+            BondingCurveAccount::new(1, 500, 1000, 250, 500, 1000, false, Pubkey::new_unique());
+        assert_eq!(bonding_curve.get_market_cap_sol().unwrap(), 2000);
+        assert_eq!(
+            global
+                .get_fees(Some(&fee_config), Some(&bonding_curve))
+                .protocol_fee_bps,
+            30
+        );
+    }
+
+    #[test]
+    fn test_global_account_initial_buy_quote() {
+        let global: GlobalAccount = get_global();
+        let fees = Fees {
+            lp_fee_bps: 0,
+            protocol_fee_bps: 100,
+            creator_fee_bps: 100,
+        };
+
+        let quote = global.get_initial_buy_quote(103, &fees).unwrap();
+        assert_eq!(quote.creator_fee_basis_points, 100);
+        assert_eq!(
+            quote.gross_sol_amount,
+            quote.net_sol_amount + quote.protocol_fee + quote.creator_fee
+        );
+        assert!(quote.token_amount > 0);
+        assert!(quote.token_amount <= global.initial_real_token_reserves);
+
+        let exact = global
+            .get_initial_buy_quote_exact_tokens(quote.token_amount, &fees)
+            .unwrap();
+        assert_eq!(exact, quote);
+    }
+
     #[test]
     fn test_global_account_max_reserves() {
         let mut global: GlobalAccount = get_global();
         global.initial_real_token_reserves = 100;
 
         // Test that returned amount is capped by real_token_reserves
-        let price: u64 = global.get_initial_buy_price(1000);
+        let price: u64 = global.get_initial_buy_price(1000).unwrap();
         assert_eq!(price, global.initial_real_token_reserves);
     }
 
@@ -219,12 +443,12 @@ mod tests {
         let global: GlobalAccount = get_large_global();
 
         // Test with maximum possible SOL amount
-        let price: u64 = global.get_initial_buy_price(u64::MAX);
+        let price: u64 = global.get_initial_buy_price(u64::MAX).unwrap();
         assert!(price > 0);
         assert!(price <= global.initial_real_token_reserves);
 
         // Test with large but not maximum SOL amount
-        let price: u64 = global.get_initial_buy_price(u64::MAX / 2);
+        let price: u64 = global.get_initial_buy_price(u64::MAX / 2).unwrap();
         assert!(price > 0);
         assert!(price <= global.initial_real_token_reserves);
     }
@@ -237,12 +461,42 @@ mod tests {
         global.initial_real_token_reserves = u64::MAX / 4;
 
         // Test with amounts near u64::MAX
-        let price: u64 = global.get_initial_buy_price(u64::MAX - 1);
+        let price: u64 = global.get_initial_buy_price(u64::MAX - 1).unwrap();
         assert!(price > 0);
         assert!(price <= global.initial_real_token_reserves);
 
-        let price: u64 = global.get_initial_buy_price(u64::MAX - 1000);
+        let price: u64 = global.get_initial_buy_price(u64::MAX - 1000).unwrap();
         assert!(price > 0);
         assert!(price <= global.initial_real_token_reserves);
     }
+
+    proptest! {
+        #[test]
+        fn prop_initial_math_never_panics(
+            initial_virtual_token_reserves in any::<u64>(),
+            initial_virtual_sol_reserves in any::<u64>(),
+            initial_real_token_reserves in any::<u64>(),
+            token_total_supply in any::<u64>(),
+            amount in any::<u64>(),
+            protocol_fee_bps in any::<u64>(),
+            creator_fee_bps in any::<u64>(),
+        ) {
+            let mut global = get_global();
+            global.initial_virtual_token_reserves = initial_virtual_token_reserves;
+            global.initial_virtual_sol_reserves = initial_virtual_sol_reserves;
+            global.initial_real_token_reserves = initial_real_token_reserves;
+            global.token_total_supply = token_total_supply;
+            let fees = Fees {
+                lp_fee_bps: 0,
+                protocol_fee_bps,
+                creator_fee_bps,
+            };
+
+            let _ = global.get_initial_buy_price(amount);
+            let _ = global.get_initial_market_cap_sol();
+            let _ = global.get_fees(None, None);
+            let _ = global.get_initial_buy_quote(amount, &fees);
+            let _ = global.get_initial_buy_quote_exact_tokens(amount, &fees);
+        }
+    }
 }
@@ -0,0 +1,141 @@
+//! Global volume accumulator account for the Pump.fun Solana Program
+//!
+//! This module contains the definition for the global volume accumulator account.
+//!
+//! # Global Volume Accumulator Account
+//!
+//! The global volume accumulator tracks the SOL volume traded per day during a token
+//! incentive period, along with the amount of incentive tokens distributed each day.
+//!
+//! # Fields
+//!
+//! - `discriminator`: Unique identifier for the global volume accumulator
+//! - `start_time`: Start of the incentive period as a Unix timestamp
+//! - `end_time`: End of the incentive period as a Unix timestamp
+//! - `seconds_in_a_day`: Length of a day in seconds
+//! - `mint`: Mint of the incentive token
+//! - `total_token_supply`: Incentive tokens distributed per day
+//! - `sol_volumes`: SOL volume traded per day
+//!
+//! # Methods
+//!
+//! - `new`: Creates a new global volume accumulator instance
+//! - `get_day_index`: Calculates the index of the incentive day for a timestamp
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Number of days tracked by the global volume accumulator
+pub const VOLUME_ACCUMULATOR_DAYS: usize = 30;
+
+/// Represents the global volume accumulator used for token incentives
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct GlobalVolumeAccumulator {
+    /// Unique identifier for the global volume accumulator
+    pub discriminator: u64,
+    /// Start of the incentive period as a Unix timestamp
+    pub start_time: i64,
+    /// End of the incentive period as a Unix timestamp
+    pub end_time: i64,
+    /// Length of a day in seconds
+    pub seconds_in_a_day: i64,
+    /// Mint of the incentive token
+    pub mint: Pubkey,
+    /// Incentive tokens distributed per day
+    pub total_token_supply: [u64; VOLUME_ACCUMULATOR_DAYS],
+    /// SOL volume traded per day
+    pub sol_volumes: [u64; VOLUME_ACCUMULATOR_DAYS],
+}
+
+impl GlobalVolumeAccumulator {
+    /// Account discriminator used to identify this account
+    pub const DISCRIMINATOR: [u8; 8] = [202, 42, 246, 43, 142, 190, 30, 255];
+
+    /// Creates a new global volume accumulator instance
+    ///
+    /// # Arguments
+    /// * `discriminator` - Unique identifier for the account
+    /// * `start_time` - Start of the incentive period as a Unix timestamp
+    /// * `end_time` - End of the incentive period as a Unix timestamp
+    /// * `seconds_in_a_day` - Length of a day in seconds
+    /// * `mint` - Mint of the incentive token
+    /// * `total_token_supply` - Incentive tokens distributed per day
+    /// * `sol_volumes` - SOL volume traded per day
+    pub fn new(
+        discriminator: u64,
+        start_time: i64,
+        end_time: i64,
+        seconds_in_a_day: i64,
+        mint: Pubkey,
+        total_token_supply: [u64; VOLUME_ACCUMULATOR_DAYS],
+        sol_volumes: [u64; VOLUME_ACCUMULATOR_DAYS],
+    ) -> Self {
+        Self {
+            discriminator,
+            start_time,
+            end_time,
+            seconds_in_a_day,
+            mint,
+            total_token_supply,
+            sol_volumes,
+        }
+    }
+
+    /// Calculates the index of the incentive day a timestamp falls into
+    ///
+    /// # Arguments
+    /// * `timestamp` - Unix timestamp to look up
+    ///
+    /// # Returns
+    /// The day index, or None if the timestamp is before the incentive period or the
+    /// accumulator has no day length configured
+    pub fn get_day_index(&self, timestamp: i64) -> Option<usize> {
+        if self.seconds_in_a_day <= 0 || timestamp < self.start_time {
+            return None;
+        }
+
+        usize::try_from((timestamp - self.start_time) / self.seconds_in_a_day).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_global_volume_accumulator() -> GlobalVolumeAccumulator {
+        GlobalVolumeAccumulator::new(
+            1,
+            1_000,
+            1_000 + 30 * 86_400,
+            86_400,
+            Pubkey::new_unique(),
+            [1_000_000; VOLUME_ACCUMULATOR_DAYS],
+            [0; VOLUME_ACCUMULATOR_DAYS],
+        )
+    }
+
+    #[test]
+    fn test_global_volume_accumulator_layout() {
+        let accumulator = get_global_volume_accumulator();
+        let data = borsh::to_vec(&accumulator).unwrap();
+        assert_eq!(data.len(), 8 + 8 * 3 + 32 + 8 * VOLUME_ACCUMULATOR_DAYS * 2);
+
+        let decoded = GlobalVolumeAccumulator::try_from_slice(&data).unwrap();
+        assert_eq!(decoded.mint, accumulator.mint);
+        assert_eq!(decoded.total_token_supply, accumulator.total_token_supply);
+    }
+
+    #[test]
+    fn test_day_index() {
+        let accumulator = get_global_volume_accumulator();
+
+        assert_eq!(accumulator.get_day_index(999), None);
+        assert_eq!(accumulator.get_day_index(1_000), Some(0));
+        assert_eq!(accumulator.get_day_index(1_000 + 86_399), Some(0));
+        assert_eq!(accumulator.get_day_index(1_000 + 86_400), Some(1));
+
+        let mut accumulator = accumulator;
+        accumulator.seconds_in_a_day = 0;
+        assert_eq!(accumulator.get_day_index(5_000), None);
+    }
+}
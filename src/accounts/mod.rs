@@ -5,10 +5,270 @@
 //! # Accounts
 //!
 //! - `BondingCurve`: Represents a bonding curve account.
+//! - `FeeConfig`: Represents the market cap tiered fee configuration of the bonding curve.
 //! - `Global`: Represents the global configuration account.
+//! - `GlobalVolumeAccumulator`: Represents the daily volume and incentive supply of the token incentive program.
+//! - `UserVolumeAccumulator`: Represents a user's traded volume and incentive token balance.
 
 mod bonding_curve;
+mod fee_config;
 mod global;
+mod global_volume_accumulator;
+mod user_volume_accumulator;
 
 pub use bonding_curve::*;
+pub use fee_config::*;
 pub use global::*;
+pub use global_volume_accumulator::*;
+pub use user_volume_accumulator::*;
+
+use crate::{common::types::Decoded, error::ClientError};
+use borsh::BorshDeserialize;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+/// Decodes a program account after checking its owner and discriminator
+///
+/// Anchor accounts start with an 8 byte discriminator identifying their type, so
+/// checking it together with the owner rejects closed accounts and accounts of another
+/// type before they are decoded.
+///
+/// # Arguments
+///
+/// * `account` - Account fetched from the RPC
+/// * `owner` - Program expected to own the account
+/// * `discriminator` - Discriminator of the expected account type
+pub(crate) fn decode_account<T: BorshDeserialize>(
+    account: &Account,
+    owner: &Pubkey,
+    discriminator: [u8; 8],
+) -> Result<T, ClientError> {
+    check_account(account, owner, discriminator)?;
+
+    solana_sdk::borsh1::try_from_slice_unchecked::<T>(&account.data)
+        .map_err(ClientError::BorshError)
+}
+
+/// Decodes a program account with several known layouts after checking its owner and
+/// discriminator
+///
+/// # Arguments
+///
+/// * `account` - Account fetched from the RPC
+/// * `owner` - Program expected to own the account
+/// * `discriminator` - Discriminator of the expected account type
+/// * `decode` - Versioned decoder of the account type, e.g. `GlobalAccount::decode_versioned`
+pub(crate) fn decode_account_versioned<T>(
+    account: &Account,
+    owner: &Pubkey,
+    discriminator: [u8; 8],
+    decode: impl FnOnce(&[u8]) -> std::io::Result<Decoded<T>>,
+) -> Result<Decoded<T>, ClientError> {
+    check_account(account, owner, discriminator)?;
+
+    decode(&account.data).map_err(ClientError::BorshError)
+}
+
+/// Checks the owner and discriminator of a program account
+fn check_account(
+    account: &Account,
+    owner: &Pubkey,
+    discriminator: [u8; 8],
+) -> Result<(), ClientError> {
+    if account.owner != *owner {
+        return Err(ClientError::InvalidAccountOwner {
+            expected: *owner,
+            found: account.owner,
+        });
+    }
+
+    let mut found = [0u8; 8];
+    let len = account.data.len().min(8);
+    found[..len].copy_from_slice(&account.data[..len]);
+    if len < 8 || found != discriminator {
+        return Err(ClientError::InvalidAccount {
+            expected: discriminator,
+            found,
+        });
+    }
+
+    Ok(())
+}
+
+/// Decodes data with the newest known layout it is long enough for
+///
+/// `layouts` holds the serialized length of every known layout, oldest first. The
+/// fields missing from an older layout are zero filled, which decodes as their default
+/// value, and bytes past the newest layout are ignored.
+///
+/// # Arguments
+///
+/// * `data` - Serialized data, including the account discriminator
+/// * `layouts` - Lengths of the known layouts, oldest first
+pub(crate) fn decode_with_layouts<T: BorshDeserialize>(
+    data: &[u8],
+    layouts: &[usize],
+) -> std::io::Result<Decoded<T>> {
+    let version = layouts
+        .iter()
+        .rposition(|len| data.len() >= *len)
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} bytes are shorter than any known layout", data.len()),
+            )
+        })?;
+
+    let full_len = layouts[layouts.len() - 1];
+    let mut padded = data[..data.len().min(full_len)].to_vec();
+    padded.resize(full_len, 0);
+
+    Ok(Decoded::new(T::try_from_slice(&padded)?, version as u8 + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+    use solana_sdk::hash::hash;
+
+    fn anchor_discriminator(name: &str) -> [u8; 8] {
+        hash(format!("account:{}", name).as_bytes()).to_bytes()[..8]
+            .try_into()
+            .unwrap()
+    }
+
+    fn get_account(data: Vec<u8>) -> Account {
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner: constants::accounts::PUMPFUN,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_account_discriminators() {
+        assert_eq!(GlobalAccount::DISCRIMINATOR, anchor_discriminator("Global"));
+        assert_eq!(
+            BondingCurveAccount::DISCRIMINATOR,
+            anchor_discriminator("BondingCurve")
+        );
+        assert_eq!(FeeConfig::DISCRIMINATOR, anchor_discriminator("FeeConfig"));
+        assert_eq!(
+            GlobalVolumeAccumulator::DISCRIMINATOR,
+            anchor_discriminator("GlobalVolumeAccumulator")
+        );
+        assert_eq!(
+            UserVolumeAccumulator::DISCRIMINATOR,
+            anchor_discriminator("UserVolumeAccumulator")
+        );
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let creator = Pubkey::new_unique();
+        let bonding_curve = BondingCurveAccount::new(
+            u64::from_le_bytes(BondingCurveAccount::DISCRIMINATOR),
+            1000,
+            1000,
+            500,
+            0,
+            1000,
+            false,
+            creator,
+        );
+        let account = get_account(borsh::to_vec(&bonding_curve).unwrap());
+
+        let decoded: BondingCurveAccount = decode_account(
+            &account,
+            &constants::accounts::PUMPFUN,
+            BondingCurveAccount::DISCRIMINATOR,
+        )
+        .unwrap();
+        assert_eq!(decoded.virtual_token_reserves, 1000);
+        assert_eq!(decoded.creator, creator);
+    }
+
+    #[test]
+    fn test_decode_account_versioned() {
+        let bonding_curve = BondingCurveAccount::new(
+            u64::from_le_bytes(BondingCurveAccount::DISCRIMINATOR),
+            1000,
+            1000,
+            500,
+            0,
+            1000,
+            false,
+            Pubkey::new_unique(),
+        );
+        let data = borsh::to_vec(&bonding_curve).unwrap();
+
+        // Layout before the creator
+        let account = get_account(data[..49].to_vec());
+        let decoded = decode_account_versioned(
+            &account,
+            &constants::accounts::PUMPFUN,
+            BondingCurveAccount::DISCRIMINATOR,
+            BondingCurveAccount::decode_versioned,
+        )
+        .unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.value.token_total_supply, 1000);
+        assert_eq!(decoded.value.creator, Pubkey::default());
+
+        let mut account = get_account(data);
+        account.owner = constants::accounts::SYSTEM_PROGRAM;
+        let result = decode_account_versioned(
+            &account,
+            &constants::accounts::PUMPFUN,
+            BondingCurveAccount::DISCRIMINATOR,
+            BondingCurveAccount::decode_versioned,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::InvalidAccountOwner { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_account_wrong_discriminator() {
+        let account = get_account(borsh::to_vec(&(0u64, [0u8; 200])).unwrap());
+        let result = decode_account::<GlobalAccount>(
+            &account,
+            &constants::accounts::PUMPFUN,
+            GlobalAccount::DISCRIMINATOR,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::InvalidAccount { expected, found })
+                if expected == GlobalAccount::DISCRIMINATOR && found == [0u8; 8]
+        ));
+
+        // Closed accounts have no data left
+        let account = get_account(vec![]);
+        let result = decode_account::<GlobalAccount>(
+            &account,
+            &constants::accounts::PUMPFUN,
+            GlobalAccount::DISCRIMINATOR,
+        );
+        assert!(matches!(result, Err(ClientError::InvalidAccount { .. })));
+    }
+
+    #[test]
+    fn test_decode_account_wrong_owner() {
+        let mut account = get_account(GlobalAccount::DISCRIMINATOR.to_vec());
+        account.owner = constants::accounts::SYSTEM_PROGRAM;
+        let result = decode_account::<GlobalAccount>(
+            &account,
+            &constants::accounts::PUMPFUN,
+            GlobalAccount::DISCRIMINATOR,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientError::InvalidAccountOwner { expected, found })
+                if expected == constants::accounts::PUMPFUN
+                    && found == constants::accounts::SYSTEM_PROGRAM
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+//! User volume accumulator account for the Pump.fun Solana Program
+//!
+//! This module contains the definition for the user volume accumulator account.
+//!
+//! # User Volume Accumulator Account
+//!
+//! The user volume accumulator tracks the SOL volume a user traded with volume tracking
+//! enabled, and the incentive tokens the user earned and claimed with it.
+//!
+//! # Fields
+//!
+//! - `discriminator`: Unique identifier for the user volume accumulator
+//! - `user`: User the accumulator belongs to
+//! - `needs_claim`: Whether the accumulator has tokens waiting to be claimed
+//! - `total_unclaimed_tokens`: Incentive tokens earned but not claimed yet
+//! - `total_claimed_tokens`: Incentive tokens claimed so far
+//! - `current_sol_volume`: SOL volume traded on the day of the last update
+//! - `last_update_timestamp`: Unix timestamp of the last update
+//! - `has_total_claimed_tokens`: Whether `total_claimed_tokens` has been initialized
+//!
+//! # Methods
+//!
+//! - `new`: Creates a new user volume accumulator instance
+//! - `get_total_unclaimed_tokens`: Calculates the incentive tokens the user can claim
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+
+use super::GlobalVolumeAccumulator;
+
+/// Represents a user's traded volume and incentive token balance
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UserVolumeAccumulator {
+    /// Unique identifier for the user volume accumulator
+    pub discriminator: u64,
+    /// User the accumulator belongs to
+    pub user: Pubkey,
+    /// Whether the accumulator has tokens waiting to be claimed
+    pub needs_claim: bool,
+    /// Incentive tokens earned but not claimed yet
+    pub total_unclaimed_tokens: u64,
+    /// Incentive tokens claimed so far
+    pub total_claimed_tokens: u64,
+    /// SOL volume traded on the day of the last update
+    pub current_sol_volume: u64,
+    /// Unix timestamp of the last update
+    pub last_update_timestamp: i64,
+    /// Whether `total_claimed_tokens` has been initialized
+    pub has_total_claimed_tokens: bool,
+}
+
+impl UserVolumeAccumulator {
+    /// Account discriminator used to identify this account
+    pub const DISCRIMINATOR: [u8; 8] = [86, 255, 112, 14, 102, 53, 154, 250];
+
+    /// Creates a new user volume accumulator instance
+    ///
+    /// # Arguments
+    /// * `discriminator` - Unique identifier for the account
+    /// * `user` - User the accumulator belongs to
+    /// * `needs_claim` - Whether the accumulator has tokens waiting to be claimed
+    /// * `total_unclaimed_tokens` - Incentive tokens earned but not claimed yet
+    /// * `total_claimed_tokens` - Incentive tokens claimed so far
+    /// * `current_sol_volume` - SOL volume traded on the day of the last update
+    /// * `last_update_timestamp` - Unix timestamp of the last update
+    /// * `has_total_claimed_tokens` - Whether `total_claimed_tokens` has been initialized
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        discriminator: u64,
+        user: Pubkey,
+        needs_claim: bool,
+        total_unclaimed_tokens: u64,
+        total_claimed_tokens: u64,
+        current_sol_volume: u64,
+        last_update_timestamp: i64,
+        has_total_claimed_tokens: bool,
+    ) -> Self {
+        Self {
+            discriminator,
+            user,
+            needs_claim,
+            total_unclaimed_tokens,
+            total_claimed_tokens,
+            current_sol_volume,
+            last_update_timestamp,
+            has_total_claimed_tokens,
+        }
+    }
+
+    /// Calculates the incentive tokens the user can claim at a given time
+    ///
+    /// The volume traded on the day of the last update only earns tokens once that day
+    /// is over. The user then receives a share of that day's token supply proportional
+    /// to their share of that day's SOL volume.
+    ///
+    /// # Arguments
+    /// * `global` - Global volume accumulator holding the daily supply and volume
+    /// * `current_timestamp` - Unix timestamp to calculate the claimable tokens at
+    ///
+    /// # Returns
+    /// Amount of incentive tokens the user can claim
+    pub fn get_total_unclaimed_tokens(
+        &self,
+        global: &GlobalVolumeAccumulator,
+        current_timestamp: i64,
+    ) -> u64 {
+        let mut total = self.total_unclaimed_tokens;
+
+        if global.start_time == global.end_time || self.current_sol_volume == 0 {
+            return total;
+        }
+
+        let (Some(current_day), Some(last_update_day)) = (
+            global.get_day_index(current_timestamp),
+            global.get_day_index(self.last_update_timestamp),
+        ) else {
+            return total;
+        };
+
+        if current_day > last_update_day && last_update_day < global.sol_volumes.len() {
+            let day_token_supply = global.total_token_supply[last_update_day] as u128;
+            let day_sol_volume = global.sol_volumes[last_update_day] as u128;
+            if let Some(earned) =
+                ((self.current_sol_volume as u128) * day_token_supply).checked_div(day_sol_volume)
+            {
+                total = total.saturating_add(u64::try_from(earned).unwrap_or(u64::MAX));
+            }
+        }
+
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::VOLUME_ACCUMULATOR_DAYS;
+
+    fn get_global_volume_accumulator() -> GlobalVolumeAccumulator {
+        let mut sol_volumes = [0; VOLUME_ACCUMULATOR_DAYS];
+        sol_volumes[0] = 1_000;
+        GlobalVolumeAccumulator::new(
+            1,
+            0,
+            30 * 86_400,
+            86_400,
+            Pubkey::new_unique(),
+            [1_000_000; VOLUME_ACCUMULATOR_DAYS],
+            sol_volumes,
+        )
+    }
+
+    fn get_user_volume_accumulator() -> UserVolumeAccumulator {
+        UserVolumeAccumulator::new(1, Pubkey::new_unique(), false, 500, 0, 250, 3_600, true)
+    }
+
+    #[test]
+    fn test_user_volume_accumulator_layout() {
+        let accumulator = get_user_volume_accumulator();
+        let data = borsh::to_vec(&accumulator).unwrap();
+        assert_eq!(data.len(), 8 + 32 + 1 + 8 * 4 + 1);
+
+        let decoded = UserVolumeAccumulator::try_from_slice(&data).unwrap();
+        assert_eq!(decoded.user, accumulator.user);
+        assert_eq!(decoded.current_sol_volume, accumulator.current_sol_volume);
+    }
+
+    #[test]
+    fn test_unclaimed_tokens_same_day() {
+        let global = get_global_volume_accumulator();
+        let user = get_user_volume_accumulator();
+
+        // Volume of the current day does not earn tokens yet
+        assert_eq!(user.get_total_unclaimed_tokens(&global, 7_200), 500);
+    }
+
+    #[test]
+    fn test_unclaimed_tokens_after_day_end() {
+        let global = get_global_volume_accumulator();
+        let user = get_user_volume_accumulator();
+
+        // 250 of 1000 lamports traded on day 0 earns a quarter of the daily supply
+        assert_eq!(
+            user.get_total_unclaimed_tokens(&global, 86_400 + 1),
+            500 + 250_000
+        );
+    }
+
+    #[test]
+    fn test_unclaimed_tokens_no_volume() {
+        let mut global = get_global_volume_accumulator();
+        global.sol_volumes = [0; VOLUME_ACCUMULATOR_DAYS];
+        let user = get_user_volume_accumulator();
+
+        assert_eq!(user.get_total_unclaimed_tokens(&global, 86_400 + 1), 500);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod price;
+#[cfg(feature = "stream")]
+pub mod settlement;
+pub mod simulation;
 #[cfg(feature = "stream")]
 pub mod stream;
 pub mod types;
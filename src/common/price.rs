@@ -0,0 +1,214 @@
+//! SOL price sources for USD valuations
+//!
+//! This module provides the `SolPriceSource` trait, which supplies the USD price of SOL
+//! used to value market caps, trades and quotes in USD, along with two implementations:
+//!
+//! - `FixedSolPrice`: Always returns the same price, for tests and offline valuations
+//! - `HttpJsonSolPrice`: Reads the price from the JSON response of an HTTP endpoint
+//!
+//! Custom sources, such as an on-chain oracle, implement the trait themselves.
+
+use async_trait::async_trait;
+use isahc::AsyncReadResponseExt;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+
+use crate::error;
+
+/// Source of the USD price of SOL
+#[async_trait]
+pub trait SolPriceSource: Send + Sync {
+    /// Gets the current price of one SOL in USD
+    async fn get_sol_price_usd(&self) -> Result<f64, error::ClientError>;
+}
+
+/// Price source returning a fixed SOL price
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedSolPrice {
+    /// Price of one SOL in USD
+    pub price_usd: f64,
+}
+
+impl FixedSolPrice {
+    /// Creates a new fixed price source
+    ///
+    /// # Arguments
+    ///
+    /// * `price_usd` - Price of one SOL in USD
+    pub fn new(price_usd: f64) -> Self {
+        Self { price_usd }
+    }
+}
+
+#[async_trait]
+impl SolPriceSource for FixedSolPrice {
+    async fn get_sol_price_usd(&self) -> Result<f64, error::ClientError> {
+        Ok(self.price_usd)
+    }
+}
+
+/// Price source reading the SOL price from an HTTP endpoint returning JSON
+///
+/// The price is located in the response with a JSON pointer (RFC 6901), and can be
+/// either a number or a string holding a number. For example, a response of
+/// `{"solana":{"usd":150.5}}` is read with the pointer `/solana/usd`.
+#[derive(Debug, Clone)]
+pub struct HttpJsonSolPrice {
+    /// URL queried for the price
+    pub url: String,
+    /// JSON pointer to the price in the response
+    pub pointer: String,
+    client: isahc::HttpClient,
+}
+
+impl HttpJsonSolPrice {
+    /// Creates a new HTTP price source
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL queried for the price
+    /// * `pointer` - JSON pointer to the price in the response, such as `/solana/usd`
+    ///
+    /// # Returns
+    ///
+    /// Returns the price source, or a ClientError if the HTTP client cannot be created
+    pub fn new(
+        url: impl Into<String>,
+        pointer: impl Into<String>,
+    ) -> Result<Self, error::ClientError> {
+        let client = isahc::HttpClient::new()
+            .map_err(|err| error::ClientError::PriceSourceError(err.to_string()))?;
+
+        Ok(Self {
+            url: url.into(),
+            pointer: pointer.into(),
+            client,
+        })
+    }
+
+    /// Creates a price source reading the SOL price from the CoinGecko simple price API
+    pub fn coingecko() -> Result<Self, error::ClientError> {
+        Self::new(
+            "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd",
+            "/solana/usd",
+        )
+    }
+}
+
+#[async_trait]
+impl SolPriceSource for HttpJsonSolPrice {
+    async fn get_sol_price_usd(&self) -> Result<f64, error::ClientError> {
+        let mut response = self
+            .client
+            .get_async(self.url.as_str())
+            .await
+            .map_err(|err| error::ClientError::PriceSourceError(err.to_string()))?;
+        if !response.status().is_success() {
+            return Err(error::ClientError::PriceSourceError(format!(
+                "{} returned status {}",
+                self.url,
+                response.status()
+            )));
+        }
+
+        let text = response
+            .text()
+            .await
+            .map_err(|err| error::ClientError::PriceSourceError(err.to_string()))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|err| error::ClientError::PriceSourceError(err.to_string()))?;
+        let price = match json.pointer(&self.pointer) {
+            Some(serde_json::Value::Number(price)) => price.as_f64(),
+            Some(serde_json::Value::String(price)) => price.parse().ok(),
+            _ => None,
+        };
+
+        price
+            .filter(|price| price.is_finite() && *price > 0.0)
+            .ok_or_else(|| {
+                error::ClientError::PriceSourceError(format!(
+                    "No valid price at {} in response",
+                    self.pointer
+                ))
+            })
+    }
+}
+
+/// Converts an amount of lamports to USD
+///
+/// # Arguments
+///
+/// * `lamports` - Amount in lamports
+/// * `sol_price_usd` - Price of one SOL in USD
+pub fn lamports_to_usd(lamports: u128, sol_price_usd: f64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64 * sol_price_usd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    /// Serves a single HTTP response on a local port and returns its URL
+    fn serve_once(status: &str, body: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/price", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_fixed_sol_price() {
+        let source = FixedSolPrice::new(150.0);
+        assert_eq!(source.get_sol_price_usd().await.unwrap(), 150.0);
+    }
+
+    #[tokio::test]
+    async fn test_http_json_sol_price() {
+        let url = serve_once("200 OK", r#"{"solana":{"usd":151.25}}"#);
+        let source = HttpJsonSolPrice::new(url, "/solana/usd").unwrap();
+        assert_eq!(source.get_sol_price_usd().await.unwrap(), 151.25);
+
+        // Prices given as strings
+        let url = serve_once("200 OK", r#"{"data":[{"price":"149.5"}]}"#);
+        let source: Box<dyn SolPriceSource> =
+            Box::new(HttpJsonSolPrice::new(url, "/data/0/price").unwrap());
+        assert_eq!(source.get_sol_price_usd().await.unwrap(), 149.5);
+    }
+
+    #[tokio::test]
+    async fn test_http_json_sol_price_errors() {
+        let url = serve_once("200 OK", r#"{"solana":{}}"#);
+        let source = HttpJsonSolPrice::new(url, "/solana/usd").unwrap();
+        assert!(matches!(
+            source.get_sol_price_usd().await,
+            Err(error::ClientError::PriceSourceError(_))
+        ));
+
+        let url = serve_once("500 Internal Server Error", "{}");
+        let source = HttpJsonSolPrice::new(url, "/solana/usd").unwrap();
+        assert!(source.get_sol_price_usd().await.is_err());
+    }
+
+    #[test]
+    fn test_lamports_to_usd() {
+        assert_eq!(lamports_to_usd(LAMPORTS_PER_SOL as u128, 150.0), 150.0);
+        assert_eq!(lamports_to_usd(500_000_000, 100.0), 50.0);
+        assert_eq!(lamports_to_usd(0, 100.0), 0.0);
+    }
+}
@@ -0,0 +1,388 @@
+//! Settlement reports of confirmed transactions
+//!
+//! This module provides `Settlement`, the outcome of a confirmed transaction as recorded
+//! by the cluster. It holds the slot, block time and fee of the transaction, and the
+//! Pump.fun events it emitted, so that the amounts actually exchanged and the fees
+//! actually paid by a trade can be read once it landed.
+
+use std::str::FromStr;
+
+use base64::Engine;
+use solana_sdk::{bs58, pubkey::Pubkey, signature::Signature, transaction::TransactionError};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInnerInstructions,
+    UiInstruction, UiLoadedAddresses,
+};
+
+use super::{
+    simulation::{get_events, get_program_error, ProgramLog},
+    stream::{self, CreateEvent, PumpFunEvent, TradeEvent},
+};
+use crate::{
+    constants,
+    error::{ClientError, ProgramError},
+};
+
+/// Tag prefixing the data of the instructions Anchor uses to emit events through a CPI
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Settlement report of a confirmed transaction
+#[derive(Debug)]
+pub struct Settlement {
+    /// Signature of the transaction
+    pub signature: Signature,
+    /// Slot in which the transaction was processed
+    pub slot: u64,
+    /// Estimated production time of the block, as a Unix timestamp, if known
+    pub block_time: Option<i64>,
+    /// Fee paid for the transaction, including the priority fee, in lamports
+    pub transaction_fee: u64,
+    /// Compute units consumed by the transaction, if reported by the RPC node
+    pub units_consumed: Option<u64>,
+    /// Token created by the transaction, if any
+    pub create: Option<CreateEvent>,
+    /// Trades executed by the transaction, in order
+    pub trades: Vec<TradeEvent>,
+    /// All the events emitted by the Pump.fun program, in order
+    pub events: Vec<PumpFunEvent>,
+    /// Error of the transaction, or None if it succeeded
+    pub error: Option<TransactionError>,
+    /// Pump.fun program error causing the failure, if the Pump.fun program failed
+    pub program_error: Option<ProgramError>,
+}
+
+impl Settlement {
+    /// Creates a settlement report from a transaction returned by the RPC node
+    ///
+    /// The events are read from the instructions the Pump.fun program invokes on itself
+    /// to emit them, and from the program logs when there are none. Logs can be
+    /// truncated by the cluster, while the instructions are always recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the transaction
+    /// * `transaction` - Transaction fetched with a binary encoding, such as base64
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report, or a ClientError if the transaction has no status
+    /// metadata
+    pub fn new(
+        signature: Signature,
+        transaction: EncodedConfirmedTransactionWithStatusMeta,
+    ) -> Result<Self, ClientError> {
+        let meta = transaction.transaction.meta.ok_or_else(|| {
+            ClientError::OtherError(format!("Transaction {} has no status metadata", signature))
+        })?;
+
+        let logs: Vec<String> = Option::from(meta.log_messages).unwrap_or_default();
+        let program_logs: Vec<ProgramLog> =
+            logs.iter().map(|line| ProgramLog::parse(line)).collect();
+
+        let inner_instructions: Vec<UiInnerInstructions> =
+            Option::from(meta.inner_instructions).unwrap_or_default();
+        let account_keys = get_account_keys(
+            &transaction.transaction.transaction,
+            Option::from(meta.loaded_addresses),
+        );
+        let mut events = get_cpi_events(&signature.to_string(), &account_keys, &inner_instructions);
+        if events.is_empty() {
+            events = get_events(&signature.to_string(), &program_logs);
+        }
+
+        let create = events.iter().find_map(|event| match event {
+            PumpFunEvent::Create(event) => Some(event.clone()),
+            _ => None,
+        });
+        let trades = events
+            .iter()
+            .filter_map(|event| match event {
+                PumpFunEvent::Trade(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect();
+        let program_error = meta
+            .err
+            .as_ref()
+            .and_then(|err| get_program_error(err, &program_logs));
+
+        Ok(Self {
+            signature,
+            slot: transaction.slot,
+            block_time: transaction.block_time,
+            transaction_fee: meta.fee,
+            units_consumed: Option::from(meta.compute_units_consumed),
+            create,
+            trades,
+            events,
+            error: meta.err,
+            program_error,
+        })
+    }
+
+    /// Returns true if the transaction succeeded
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the first trade of the transaction, the only one for single trades
+    pub fn trade(&self) -> Option<&TradeEvent> {
+        self.trades.first()
+    }
+
+    /// Returns the protocol and creator fees paid by the trades of the transaction
+    ///
+    /// The fees are in lamports and exclude the transaction fee.
+    pub fn get_trade_fees(&self) -> u64 {
+        self.trades
+            .iter()
+            .map(|trade| trade.fee + trade.creator_fee)
+            .sum()
+    }
+}
+
+/// Returns the account keys of a transaction, followed by the addresses it loaded from
+/// lookup tables
+///
+/// Returns no keys if the transaction was not fetched with a binary encoding.
+fn get_account_keys(
+    transaction: &EncodedTransaction,
+    loaded_addresses: Option<UiLoadedAddresses>,
+) -> Vec<Pubkey> {
+    let Some(transaction) = transaction.decode() else {
+        return Vec::new();
+    };
+
+    let mut account_keys = transaction.message.static_account_keys().to_vec();
+    if let Some(loaded_addresses) = loaded_addresses {
+        account_keys.extend(
+            loaded_addresses
+                .writable
+                .iter()
+                .chain(&loaded_addresses.readonly)
+                .filter_map(|address| Pubkey::from_str(address).ok()),
+        );
+    }
+    account_keys
+}
+
+/// Parses the events emitted by the Pump.fun program through instructions invoked on
+/// itself
+///
+/// Instructions of other programs and the entries that cannot be parsed are skipped.
+fn get_cpi_events(
+    signature: &str,
+    account_keys: &[Pubkey],
+    inner_instructions: &[UiInnerInstructions],
+) -> Vec<PumpFunEvent> {
+    inner_instructions
+        .iter()
+        .flat_map(|inner| &inner.instructions)
+        .filter_map(|instruction| match instruction {
+            UiInstruction::Compiled(instruction) => Some(instruction),
+            UiInstruction::Parsed(_) => None,
+        })
+        .filter(|instruction| {
+            account_keys.get(instruction.program_id_index as usize)
+                == Some(&constants::accounts::PUMPFUN)
+        })
+        .filter_map(|instruction| {
+            let data = bs58::decode(&instruction.data).into_vec().ok()?;
+            let event = data.strip_prefix(&EVENT_IX_TAG)?;
+            let event = base64::engine::general_purpose::STANDARD.encode(event);
+            stream::parse_event(signature, &event).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash, message::Message, signature::Keypair, signer::Signer, transaction::Transaction,
+    };
+    use solana_transaction_status_client_types::{
+        option_serializer::OptionSerializer, EncodedTransactionWithStatusMeta,
+        TransactionBinaryEncoding, UiCompiledInstruction, UiTransactionStatusMeta,
+    };
+
+    fn get_trade_event(sol_amount: u64) -> TradeEvent {
+        TradeEvent {
+            mint: Pubkey::new_unique(),
+            sol_amount,
+            token_amount: 2_000_000,
+            is_buy: true,
+            user: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            virtual_token_reserves: 1_073_000_000_000_000,
+            real_sol_reserves: 0,
+            real_token_reserves: 793_100_000_000_000,
+            fee_recipient: Pubkey::new_unique(),
+            fee_basis_points: 95,
+            fee: sol_amount * 95 / 10_000,
+            creator: Pubkey::new_unique(),
+            creator_fee_basis_points: 5,
+            creator_fee: sol_amount * 5 / 10_000,
+            track_volume: false,
+            total_unclaimed_tokens: 0,
+            total_claimed_tokens: 0,
+            current_sol_volume: 0,
+            last_update_timestamp: 0,
+            ix_name: Some("buy".to_string()),
+        }
+    }
+
+    fn get_event_data(event: &TradeEvent) -> Vec<u8> {
+        let mut data = vec![189, 219, 127, 211, 78, 230, 97, 238];
+        data.extend(borsh::to_vec(event).unwrap());
+        data
+    }
+
+    fn get_transaction(
+        logs: Vec<String>,
+        inner_instructions: Vec<UiInnerInstructions>,
+        err: Option<TransactionError>,
+    ) -> EncodedConfirmedTransactionWithStatusMeta {
+        let payer = Keypair::new();
+        let message = Message::new_with_blockhash(
+            &[solana_sdk::instruction::Instruction::new_with_bytes(
+                constants::accounts::PUMPFUN,
+                &[],
+                vec![],
+            )],
+            Some(&payer.pubkey()),
+            &Hash::new_unique(),
+        );
+        let transaction = Transaction::new(&[&payer], message, Hash::default());
+        let data = base64::engine::general_purpose::STANDARD
+            .encode(bincode::serialize(&transaction).unwrap());
+
+        EncodedConfirmedTransactionWithStatusMeta {
+            slot: 42,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: EncodedTransaction::Binary(data, TransactionBinaryEncoding::Base64),
+                meta: Some(UiTransactionStatusMeta {
+                    status: err.clone().map_or(Ok(()), Err),
+                    err,
+                    fee: 15_000,
+                    pre_balances: Vec::new(),
+                    post_balances: Vec::new(),
+                    inner_instructions: OptionSerializer::Some(inner_instructions),
+                    log_messages: OptionSerializer::Some(logs),
+                    pre_token_balances: OptionSerializer::None,
+                    post_token_balances: OptionSerializer::None,
+                    rewards: OptionSerializer::None,
+                    loaded_addresses: OptionSerializer::Skip,
+                    return_data: OptionSerializer::Skip,
+                    compute_units_consumed: OptionSerializer::Some(41_207),
+                    cost_units: OptionSerializer::Skip,
+                }),
+                version: None,
+            },
+            block_time: Some(1_700_000_001),
+        }
+    }
+
+    #[test]
+    fn test_settlement_from_logs() {
+        let trade = get_trade_event(100_000_000);
+        let data = base64::engine::general_purpose::STANDARD.encode(get_event_data(&trade));
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            "Program log: Instruction: Buy".to_string(),
+            format!("Program data: {}", data),
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success".to_string(),
+        ];
+        let settlement = Settlement::new(
+            Signature::default(),
+            get_transaction(logs, Vec::new(), None),
+        )
+        .unwrap();
+
+        assert!(settlement.is_success());
+        assert_eq!(settlement.slot, 42);
+        assert_eq!(settlement.block_time, Some(1_700_000_001));
+        assert_eq!(settlement.transaction_fee, 15_000);
+        assert_eq!(settlement.units_consumed, Some(41_207));
+        assert!(settlement.create.is_none());
+        assert_eq!(settlement.trades.len(), 1);
+        assert_eq!(settlement.trade().unwrap().mint, trade.mint);
+        assert_eq!(settlement.trade().unwrap().token_amount, 2_000_000);
+        assert_eq!(settlement.get_trade_fees(), 1_000_000);
+    }
+
+    #[test]
+    fn test_settlement_from_inner_instructions() {
+        // Two trades emitted through self-invocations, with truncated logs
+        let trades = [get_trade_event(100_000_000), get_trade_event(200_000_000)];
+        let mut instructions: Vec<UiInstruction> = trades
+            .iter()
+            .map(|trade| {
+                let mut data = EVENT_IX_TAG.to_vec();
+                data.extend(get_event_data(trade));
+                UiInstruction::Compiled(UiCompiledInstruction {
+                    program_id_index: 1,
+                    accounts: vec![],
+                    data: bs58::encode(data).into_string(),
+                    stack_height: Some(2),
+                })
+            })
+            .collect();
+        // Instruction of another program carrying the same data
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend(get_event_data(&trades[0]));
+        instructions.push(UiInstruction::Compiled(UiCompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: bs58::encode(data).into_string(),
+            stack_height: Some(2),
+        }));
+
+        let inner_instructions = vec![UiInnerInstructions {
+            index: 0,
+            instructions,
+        }];
+        let logs = vec!["Log truncated".to_string()];
+        let settlement = Settlement::new(
+            Signature::default(),
+            get_transaction(logs, inner_instructions, None),
+        )
+        .unwrap();
+
+        assert_eq!(settlement.events.len(), 2);
+        assert_eq!(settlement.trades[1].mint, trades[1].mint);
+        assert_eq!(settlement.trades[1].sol_amount, 200_000_000);
+        assert_eq!(settlement.get_trade_fees(), 3_000_000);
+    }
+
+    #[test]
+    fn test_settlement_failed() {
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P failed: custom program error: 0x1772"
+                .to_string(),
+        ];
+        let err = TransactionError::InstructionError(
+            0,
+            solana_sdk::instruction::InstructionError::Custom(6002),
+        );
+        let settlement = Settlement::new(
+            Signature::default(),
+            get_transaction(logs, Vec::new(), Some(err)),
+        )
+        .unwrap();
+
+        assert!(!settlement.is_success());
+        assert_eq!(settlement.transaction_fee, 15_000);
+        assert!(settlement.trade().is_none());
+        assert_eq!(
+            settlement.program_error,
+            Some(ProgramError::TooMuchSolRequired)
+        );
+
+        let mut transaction = get_transaction(Vec::new(), Vec::new(), None);
+        transaction.transaction.meta = None;
+        assert!(Settlement::new(Signature::default(), transaction).is_err());
+    }
+}
@@ -0,0 +1,351 @@
+//! Results of simulated transactions
+//!
+//! This module provides `SimulationResult`, the structured result of a transaction
+//! simulated against the current state of the cluster without being sent. It holds the
+//! compute units consumed, the decoded program logs, the Pump.fun events emitted by the
+//! transaction, and the reason of a failure mapped to a typed `ProgramError`.
+
+use std::str::FromStr;
+
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signature::Signature,
+    transaction::TransactionError,
+};
+
+#[cfg(feature = "stream")]
+use super::stream::{self, PumpFunEvent};
+use crate::{constants, error::ProgramError};
+
+/// Decoded line of the program logs of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgramLog {
+    /// A program was invoked at a depth of the call stack, starting at 1
+    Invoke { program_id: Pubkey, depth: u32 },
+    /// A program returned successfully
+    Success { program_id: Pubkey },
+    /// A program failed with an error
+    Failure { program_id: Pubkey, error: String },
+    /// A program consumed compute units out of the remaining limit
+    Consumed {
+        program_id: Pubkey,
+        units: u64,
+        limit: u64,
+    },
+    /// Message logged by a program
+    Log(String),
+    /// Base64-encoded data emitted by a program, such as an Anchor event
+    Data(String),
+    /// Base64-encoded data returned by a program
+    Return { program_id: Pubkey, data: String },
+    /// Line that does not match a known log format
+    Other(String),
+}
+
+impl ProgramLog {
+    /// Decodes a line of the program logs
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line of the logs returned for a transaction
+    pub fn parse(line: &str) -> Self {
+        Self::try_parse(line).unwrap_or_else(|| Self::Other(line.to_string()))
+    }
+
+    fn try_parse(line: &str) -> Option<Self> {
+        if let Some(message) = line.strip_prefix("Program log: ") {
+            return Some(Self::Log(message.to_string()));
+        }
+        if let Some(data) = line.strip_prefix("Program data: ") {
+            return Some(Self::Data(data.to_string()));
+        }
+        if let Some(rest) = line.strip_prefix("Program return: ") {
+            let (program_id, data) = rest.split_once(' ')?;
+            return Some(Self::Return {
+                program_id: Pubkey::from_str(program_id).ok()?,
+                data: data.to_string(),
+            });
+        }
+
+        let (program_id, rest) = line.strip_prefix("Program ")?.split_once(' ')?;
+        let program_id = Pubkey::from_str(program_id).ok()?;
+        if rest == "success" {
+            return Some(Self::Success { program_id });
+        }
+        if let Some(error) = rest.strip_prefix("failed: ") {
+            return Some(Self::Failure {
+                program_id,
+                error: error.to_string(),
+            });
+        }
+        if let Some(depth) = rest
+            .strip_prefix("invoke [")
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            return Some(Self::Invoke {
+                program_id,
+                depth: depth.parse().ok()?,
+            });
+        }
+        let (units, limit) = rest
+            .strip_prefix("consumed ")?
+            .strip_suffix(" compute units")?
+            .split_once(" of ")?;
+        Some(Self::Consumed {
+            program_id,
+            units: units.parse().ok()?,
+            limit: limit.parse().ok()?,
+        })
+    }
+}
+
+/// Result of a simulated transaction
+#[derive(Debug)]
+pub struct SimulationResult {
+    /// Signature of the simulated transaction
+    pub signature: Signature,
+    /// Compute units consumed by the transaction, if reported by the RPC node
+    pub units_consumed: Option<u64>,
+    /// Raw program logs of the transaction
+    pub logs: Vec<String>,
+    /// Decoded program logs of the transaction
+    pub program_logs: Vec<ProgramLog>,
+    /// Events emitted by the Pump.fun program (only available with "stream" feature)
+    #[cfg(feature = "stream")]
+    pub events: Vec<PumpFunEvent>,
+    /// Error of the transaction, or None if the simulation succeeded
+    pub error: Option<TransactionError>,
+    /// Pump.fun program error causing the failure, if the Pump.fun program failed
+    pub program_error: Option<ProgramError>,
+}
+
+impl SimulationResult {
+    /// Creates a simulation result from the response of the RPC node
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the simulated transaction
+    /// * `result` - Simulation result returned by the RPC node
+    pub fn new(signature: Signature, result: RpcSimulateTransactionResult) -> Self {
+        let logs = result.logs.unwrap_or_default();
+        let program_logs: Vec<ProgramLog> =
+            logs.iter().map(|line| ProgramLog::parse(line)).collect();
+        let program_error = result
+            .err
+            .as_ref()
+            .and_then(|err| get_program_error(err, &program_logs));
+
+        Self {
+            signature,
+            units_consumed: result.units_consumed,
+            #[cfg(feature = "stream")]
+            events: get_events(&signature.to_string(), &program_logs),
+            logs,
+            program_logs,
+            error: result.err,
+            program_error,
+        }
+    }
+
+    /// Returns true if the simulated transaction succeeded
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Maps the custom error code of a failed instruction to a Pump.fun program error
+///
+/// Custom codes are only mapped when the innermost failing program is the Pump.fun
+/// program, or when the logs do not say which program failed.
+pub(crate) fn get_program_error(
+    err: &TransactionError,
+    program_logs: &[ProgramLog],
+) -> Option<ProgramError> {
+    let TransactionError::InstructionError(_, InstructionError::Custom(code)) = err else {
+        return None;
+    };
+
+    let failed_program = program_logs.iter().find_map(|log| match log {
+        ProgramLog::Failure { program_id, .. } => Some(program_id),
+        _ => None,
+    });
+    failed_program
+        .is_none_or(|program_id| *program_id == constants::accounts::PUMPFUN)
+        .then(|| ProgramError::from_code(*code))
+}
+
+/// Parses the events emitted by the Pump.fun program from the decoded logs
+///
+/// Data logged by other programs, including programs invoked by Pump.fun, is skipped,
+/// as are the entries that cannot be parsed.
+#[cfg(feature = "stream")]
+pub(crate) fn get_events(signature: &str, program_logs: &[ProgramLog]) -> Vec<PumpFunEvent> {
+    let mut call_stack = Vec::new();
+    let mut events = Vec::new();
+
+    for log in program_logs {
+        match log {
+            ProgramLog::Invoke { program_id, .. } => call_stack.push(*program_id),
+            ProgramLog::Success { .. } | ProgramLog::Failure { .. } => {
+                call_stack.pop();
+            }
+            ProgramLog::Data(data) if call_stack.last() == Some(&constants::accounts::PUMPFUN) => {
+                if let Ok(event) = stream::parse_event(signature, data) {
+                    events.push(event);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    fn get_result(
+        err: Option<TransactionError>,
+        logs: Vec<String>,
+        units_consumed: Option<u64>,
+    ) -> RpcSimulateTransactionResult {
+        RpcSimulateTransactionResult {
+            err,
+            logs: Some(logs),
+            accounts: None,
+            units_consumed,
+            loaded_accounts_data_size: None,
+            return_data: None,
+            inner_instructions: None,
+            replacement_blockhash: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_program_log() {
+        let pumpfun = constants::accounts::PUMPFUN;
+        let logs = get_logs(&[
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]",
+            "Program log: Instruction: Buy",
+            "Program data: vdt/007mYe4=",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P consumed 41207 of 200000 compute units",
+            "Program return: 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P AQ==",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P failed: custom program error: 0x1772",
+            "Log truncated",
+        ]);
+        let parsed: Vec<ProgramLog> = logs.iter().map(|line| ProgramLog::parse(line)).collect();
+
+        assert_eq!(
+            parsed,
+            vec![
+                ProgramLog::Invoke {
+                    program_id: pumpfun,
+                    depth: 1
+                },
+                ProgramLog::Log("Instruction: Buy".to_string()),
+                ProgramLog::Data("vdt/007mYe4=".to_string()),
+                ProgramLog::Consumed {
+                    program_id: pumpfun,
+                    units: 41207,
+                    limit: 200000
+                },
+                ProgramLog::Return {
+                    program_id: pumpfun,
+                    data: "AQ==".to_string()
+                },
+                ProgramLog::Success {
+                    program_id: pumpfun
+                },
+                ProgramLog::Failure {
+                    program_id: pumpfun,
+                    error: "custom program error: 0x1772".to_string()
+                },
+                ProgramLog::Other("Log truncated".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulation_result_program_error() {
+        let logs = get_logs(&[
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]",
+            "Program log: Instruction: Buy",
+            "Program log: AnchorError thrown in programs/pump/src/lib.rs:1. Error Code: TooMuchSolRequired. Error Number: 6002.",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P consumed 30000 of 200000 compute units",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P failed: custom program error: 0x1772",
+        ]);
+        let err = TransactionError::InstructionError(2, InstructionError::Custom(6002));
+        let result = SimulationResult::new(
+            Signature::default(),
+            get_result(Some(err.clone()), logs, Some(30000)),
+        );
+
+        assert!(!result.is_success());
+        assert_eq!(result.units_consumed, Some(30000));
+        assert_eq!(result.program_logs.len(), 5);
+        assert_eq!(result.error, Some(err));
+        assert_eq!(result.program_error, Some(ProgramError::TooMuchSolRequired));
+    }
+
+    #[test]
+    fn test_simulation_result_other_program_error() {
+        // Insufficient funds raised by the token program during a CPI
+        let logs = get_logs(&[
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]",
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]",
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA failed: custom program error: 0x1",
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P failed: custom program error: 0x1",
+        ]);
+        let err = TransactionError::InstructionError(0, InstructionError::Custom(1));
+        let result = SimulationResult::new(Signature::default(), get_result(Some(err), logs, None));
+        assert!(!result.is_success());
+        assert_eq!(result.program_error, None);
+
+        // Failures that are not custom program errors
+        let err = TransactionError::InsufficientFundsForFee;
+        let result = SimulationResult::new(
+            Signature::default(),
+            get_result(Some(err), Vec::new(), None),
+        );
+        assert_eq!(result.program_error, None);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn test_simulation_result_events() {
+        use base64::Engine;
+
+        // CompleteEvent emitted by Pump.fun, followed by data logged by another program
+        let mut event = vec![95, 114, 97, 156, 212, 46, 152, 8];
+        event.extend(
+            borsh::to_vec(&stream::CompleteEvent {
+                user: Pubkey::new_unique(),
+                mint: Pubkey::new_unique(),
+                bonding_curve: Pubkey::new_unique(),
+                timestamp: 1_700_000_000,
+            })
+            .unwrap(),
+        );
+        let data = base64::engine::general_purpose::STANDARD.encode(event);
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            format!("Program data: {}", data),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]".to_string(),
+            format!("Program data: {}", data),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success".to_string(),
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success".to_string(),
+        ];
+        let result =
+            SimulationResult::new(Signature::default(), get_result(None, logs, Some(50000)));
+
+        assert!(result.is_success());
+        assert_eq!(result.events.len(), 1);
+        assert!(matches!(result.events[0], PumpFunEvent::Complete(_)));
+    }
+}
@@ -13,7 +13,10 @@ use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use super::types::Cluster;
+use super::{
+    price::lamports_to_usd,
+    types::{Cluster, Decoded},
+};
 use crate::{constants, error};
 
 /// Event emitted when a new token is created
@@ -36,6 +39,62 @@ pub struct CreateEvent {
     pub token_total_supply: u64,
 }
 
+impl CreateEvent {
+    /// Decodes a create event written with any known layout
+    ///
+    /// The known layouts, oldest first, are:
+    ///
+    /// 1. Original layout, up to `user`
+    /// 2. Adds the creator, the timestamp and the initial reserves of the curve
+    ///
+    /// Fields missing from older layouts take their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Event data following the event discriminator
+    ///
+    /// # Returns
+    ///
+    /// The decoded event and the version of the layout it was written with
+    pub fn decode_versioned(mut data: &[u8]) -> std::io::Result<Decoded<Self>> {
+        let (name, symbol, uri, mint, bonding_curve, user): (
+            String,
+            String,
+            String,
+            Pubkey,
+            Pubkey,
+            Pubkey,
+        ) = BorshDeserialize::deserialize(&mut data)?;
+        let mut event = Self {
+            name,
+            symbol,
+            uri,
+            mint,
+            bonding_curve,
+            user,
+            creator: Pubkey::default(),
+            timestamp: 0,
+            virtual_token_reserves: 0,
+            virtual_sol_reserves: 0,
+            real_token_reserves: 0,
+            token_total_supply: 0,
+        };
+        if data.is_empty() {
+            return Ok(Decoded::new(event, 1));
+        }
+
+        (
+            event.creator,
+            event.timestamp,
+            event.virtual_token_reserves,
+            event.virtual_sol_reserves,
+            event.real_token_reserves,
+            event.token_total_supply,
+        ) = BorshDeserialize::deserialize(&mut data)?;
+        Ok(Decoded::new(event, 2))
+    }
+}
+
 /// Event emitted when a token is bought or sold
 ///
 /// This event contains details about a trade transaction, including the amounts
@@ -66,6 +125,74 @@ pub struct TradeEvent {
     pub ix_name: Option<String>,
 }
 
+impl TradeEvent {
+    /// Serialized lengths of the known layouts without the instruction name, oldest first
+    ///
+    /// 1. Original layout, up to `virtual_token_reserves`
+    /// 2. Adds the real reserves, the fee recipient and the protocol and creator fees
+    /// 3. Adds the volume tracking fields
+    ///
+    /// Version 4, the current layout, appends the instruction name to version 3.
+    const LAYOUTS: [usize; 3] = [105, 217, 250];
+
+    /// Values the SOL amount of the trade in USD
+    ///
+    /// The SOL amount excludes fees: it is the SOL entering the curve on buys and
+    /// leaving it on sells.
+    ///
+    /// # Arguments
+    ///
+    /// * `sol_price_usd` - Price of one SOL in USD, see `SolPriceSource`
+    pub fn get_sol_amount_usd(&self, sol_price_usd: f64) -> f64 {
+        lamports_to_usd(self.sol_amount as u128, sol_price_usd)
+    }
+
+    /// Decodes a trade event written with any known layout
+    ///
+    /// Fields missing from older layouts take their default value: older trades have
+    /// no fees, no volume tracking and no instruction name.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Event data following the event discriminator
+    ///
+    /// # Returns
+    ///
+    /// The decoded event and the version of the layout it was written with
+    pub fn decode_versioned(data: &[u8]) -> std::io::Result<Decoded<Self>> {
+        let fixed_len = Self::LAYOUTS[Self::LAYOUTS.len() - 1];
+        let version = match Self::LAYOUTS.iter().position(|len| *len == data.len()) {
+            Some(index) => index as u8 + 1,
+            None if data.len() > fixed_len => 4,
+            None => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("{} bytes match no known TradeEvent layout", data.len()),
+                ))
+            }
+        };
+
+        // The zero padding decodes as the default of every missing field, and the
+        // extra byte as a missing instruction name
+        let mut padded = data[..data.len().min(fixed_len)].to_vec();
+        padded.resize(fixed_len + 1, 0);
+        let mut event = Self::try_from_slice(&padded)?;
+
+        if version == 4 {
+            // The program logs the instruction name as a string, while events serialized
+            // with this struct encode it as an optional string
+            let ix_name: std::io::Result<String> =
+                BorshDeserialize::deserialize(&mut &data[fixed_len..]);
+            event.ix_name = match ix_name {
+                Ok(name) => Some(name),
+                Err(_) => BorshDeserialize::deserialize(&mut &data[fixed_len..])?,
+            };
+        }
+
+        Ok(Decoded::new(event, version))
+    }
+}
+
 /// Event emitted when a bonding curve operation completes
 ///
 /// This event signals the completion of a bonding curve operation,
@@ -100,6 +227,221 @@ pub struct SetParamsEvent {
     pub admin_set_creator_authority: Pubkey,
 }
 
+impl SetParamsEvent {
+    /// Decodes a set params event written with any known layout
+    ///
+    /// The known layouts, oldest first, are:
+    ///
+    /// 1. Original layout, up to `fee_basis_points`
+    /// 2. Adds the migration settings, up to `pool_migration_fee`
+    /// 3. Adds the creator fee, seven fee recipients, the timestamp and the set creator
+    ///    authority
+    /// 4. Adds an eighth fee recipient and the admin set creator authority
+    ///
+    /// Fields missing from older layouts take their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Event data following the event discriminator
+    ///
+    /// # Returns
+    ///
+    /// The decoded event and the version of the layout it was written with
+    pub fn decode_versioned(mut data: &[u8]) -> std::io::Result<Decoded<Self>> {
+        let (
+            initial_virtual_token_reserves,
+            initial_virtual_sol_reserves,
+            initial_real_token_reserves,
+            final_real_sol_reserves,
+            token_total_supply,
+            fee_basis_points,
+        ): (u64, u64, u64, u64, u64, u64) = BorshDeserialize::deserialize(&mut data)?;
+        let mut event = Self {
+            initial_virtual_token_reserves,
+            initial_virtual_sol_reserves,
+            initial_real_token_reserves,
+            final_real_sol_reserves,
+            token_total_supply,
+            fee_basis_points,
+            withdraw_authority: Pubkey::default(),
+            enable_migrate: false,
+            pool_migration_fee: 0,
+            creator_fee_basis_points: 0,
+            fee_recipients: [Pubkey::default(); 8],
+            timestamp: 0,
+            set_creator_authority: Pubkey::default(),
+            admin_set_creator_authority: Pubkey::default(),
+        };
+        if data.is_empty() {
+            return Ok(Decoded::new(event, 1));
+        }
+
+        (
+            event.withdraw_authority,
+            event.enable_migrate,
+            event.pool_migration_fee,
+        ) = BorshDeserialize::deserialize(&mut data)?;
+        if data.is_empty() {
+            return Ok(Decoded::new(event, 2));
+        }
+
+        event.creator_fee_basis_points = BorshDeserialize::deserialize(&mut data)?;
+        if data.len() == 7 * 32 + 8 + 32 {
+            let fee_recipients: [Pubkey; 7];
+            (fee_recipients, event.timestamp, event.set_creator_authority) =
+                BorshDeserialize::deserialize(&mut data)?;
+            event.fee_recipients[..7].copy_from_slice(&fee_recipients);
+            return Ok(Decoded::new(event, 3));
+        }
+
+        (
+            event.fee_recipients,
+            event.timestamp,
+            event.set_creator_authority,
+            event.admin_set_creator_authority,
+        ) = BorshDeserialize::deserialize(&mut data)?;
+        Ok(Decoded::new(event, 4))
+    }
+}
+
+/// Event emitted when the admin overrides the creator of a bonding curve
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct AdminSetCreatorEvent {
+    pub timestamp: i64,
+    pub admin_set_creator_authority: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+/// Event emitted when the admin changes the authority of the program IDL
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct AdminSetIdlAuthorityEvent {
+    pub idl_authority: Pubkey,
+}
+
+/// Event emitted when the admin sets the incentive token supply of a day
+///
+/// This event describes the token incentive period and the amount of incentive
+/// tokens distributed on the given day.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct AdminUpdateTokenIncentivesEvent {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+    pub mint: Pubkey,
+    pub seconds_in_a_day: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a user claims their token incentives
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct ClaimTokenIncentivesEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+}
+
+/// Event emitted when a user volume accumulator is closed
+///
+/// This event contains the final state of the accumulator at the time it was closed.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct CloseUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+}
+
+/// Event emitted when a creator collects the fees accumulated in their vault
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct CollectCreatorFeeEvent {
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub creator_fee: u64,
+}
+
+/// Event emitted when a completed bonding curve is migrated to a Pump AMM pool
+///
+/// This event contains the token and SOL amounts moved into the pool, along with
+/// the fee charged for the migration.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct CompletePumpAmmMigrationEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub mint_amount: u64,
+    pub sol_amount: u64,
+    pub pool_migration_fee: u64,
+    pub bonding_curve: Pubkey,
+    pub timestamp: i64,
+    pub pool: Pubkey,
+}
+
+/// Event emitted when a program account is resized
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct ExtendAccountEvent {
+    pub account: Pubkey,
+    pub user: Pubkey,
+    pub current_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a user volume accumulator is created
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct InitUserVolumeAccumulatorEvent {
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the creator of a bonding curve is set
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct SetCreatorEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+}
+
+/// Event emitted when the creator is written to a token's Metaplex metadata
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct SetMetaplexCreatorEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub metadata: Pubkey,
+    pub creator: Pubkey,
+}
+
+/// Event emitted when a user volume accumulator is synced
+///
+/// This event reports the user's claimed token total before and after crediting
+/// the incentives earned by completed days.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct SyncUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the global authority is transferred
+#[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize, Clone)]
+pub struct UpdateGlobalAuthorityEvent {
+    pub global: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Enum representing all possible event types emitted by the Pump.fun program
 ///
 /// This enum acts as a container for the different event types that can be
@@ -110,8 +452,20 @@ pub enum PumpFunEvent {
     Trade(TradeEvent),
     Complete(CompleteEvent),
     SetParams(SetParamsEvent),
-    Unhandled(String, Vec<u8>), // For unhandled events
-    Unknown(String, Vec<u8>),   // For unknown events
+    AdminSetCreator(AdminSetCreatorEvent),
+    AdminSetIdlAuthority(AdminSetIdlAuthorityEvent),
+    AdminUpdateTokenIncentives(AdminUpdateTokenIncentivesEvent),
+    ClaimTokenIncentives(ClaimTokenIncentivesEvent),
+    CloseUserVolumeAccumulator(CloseUserVolumeAccumulatorEvent),
+    CollectCreatorFee(CollectCreatorFeeEvent),
+    CompletePumpAmmMigration(CompletePumpAmmMigrationEvent),
+    ExtendAccount(ExtendAccountEvent),
+    InitUserVolumeAccumulator(InitUserVolumeAccumulatorEvent),
+    SetCreator(SetCreatorEvent),
+    SetMetaplexCreator(SetMetaplexCreatorEvent),
+    SyncUserVolumeAccumulator(SyncUserVolumeAccumulatorEvent),
+    UpdateGlobalAuthority(UpdateGlobalAuthorityEvent),
+    Unknown(String, Vec<u8>), // For unknown events
 }
 
 /// Represents an active WebSocket subscription to Pump.fun events
@@ -154,6 +508,29 @@ pub fn parse_event(
     signature: &str,
     data: &str,
 ) -> Result<PumpFunEvent, Box<dyn Error + Send + Sync>> {
+    parse_event_versioned(signature, data).map(|decoded| decoded.value)
+}
+
+/// Parses base64-encoded program log data into a PumpFunEvent and its layout version
+///
+/// Events that gained fields over time, such as `TradeEvent` and `CreateEvent`, are
+/// decoded with whichever known layout matches the data, and the fields missing from
+/// older layouts take their default value. This makes it possible to parse the logs of
+/// old slots. Events with a single known layout report version 1.
+///
+/// # Arguments
+///
+/// * `signature` - Transaction signature associated with the event
+/// * `data` - Base64-encoded event data from program logs
+///
+/// # Returns
+///
+/// Returns the parsed PumpFunEvent and the version of its layout if successful, or an
+/// error if parsing fails
+pub fn parse_event_versioned(
+    signature: &str,
+    data: &str,
+) -> Result<Decoded<PumpFunEvent>, Box<dyn Error + Send + Sync>> {
     // Decode base64
     let decoded = base64::engine::general_purpose::STANDARD.decode(data)?;
 
@@ -162,47 +539,107 @@ pub fn parse_event(
         return Err(format!("Data too short to contain discriminator: {}", data).into());
     }
 
-    let discriminator = &decoded[..8];
-    match discriminator {
+    let (discriminator, event_data) = decoded.split_at(8);
+    let event = match discriminator {
         // CreateEvent
-        [27, 114, 169, 77, 222, 235, 99, 118] => Ok(PumpFunEvent::Create(
-            CreateEvent::try_from_slice(&decoded[8..])
-                .map_err(|e| format!("Failed to decode CreateEvent: {}", e))?,
-        )),
+        [27, 114, 169, 77, 222, 235, 99, 118] => CreateEvent::decode_versioned(event_data)
+            .map_err(|err| decode_error("CreateEvent", err))?
+            .map(PumpFunEvent::Create),
         // TradeEvent
-        [189, 219, 127, 211, 78, 230, 97, 238] => Ok(PumpFunEvent::Trade(
-            TradeEvent::try_from_slice(&decoded[8..])
-                .map_err(|e| format!("Failed to decode TradeEvent: {}", e))?,
-        )),
+        [189, 219, 127, 211, 78, 230, 97, 238] => TradeEvent::decode_versioned(event_data)
+            .map_err(|err| decode_error("TradeEvent", err))?
+            .map(PumpFunEvent::Trade),
         // CompleteEvent
-        [95, 114, 97, 156, 212, 46, 152, 8] => Ok(PumpFunEvent::Complete(
-            CompleteEvent::try_from_slice(&decoded[8..])
-                .map_err(|e| format!("Failed to decode CompleteEvent: {}", e))?,
-        )),
+        [95, 114, 97, 156, 212, 46, 152, 8] => {
+            decode_event("CompleteEvent", event_data)?.map(PumpFunEvent::Complete)
+        }
         // SetParamsEvent
-        [223, 195, 159, 246, 62, 48, 143, 131] => Ok(PumpFunEvent::SetParams(
-            SetParamsEvent::try_from_slice(&decoded[8..])
-                .map_err(|e| format!("Failed to decode SetParamsEvent: {}", e))?,
-        )),
-        // Other unhandled Pump.fun events
-        [64, 69, 192, 104, 29, 30, 25, 107]
-        | [245, 59, 70, 34, 75, 185, 109, 92]
-        | [147, 250, 108, 120, 247, 29, 67, 222]
-        | [79, 172, 246, 49, 205, 91, 206, 232]
-        | [146, 159, 189, 172, 146, 88, 56, 244]
-        | [122, 2, 127, 1, 14, 191, 12, 175]
-        | [189, 233, 93, 185, 92, 148, 234, 148]
-        | [97, 97, 215, 144, 93, 146, 22, 124]
-        | [134, 36, 13, 72, 232, 101, 130, 216]
-        | [237, 52, 123, 37, 245, 251, 72, 210]
-        | [142, 203, 6, 32, 127, 105, 191, 162]
-        | [197, 122, 167, 124, 116, 81, 91, 255]
-        | [182, 195, 137, 42, 35, 206, 207, 247] => {
-            Ok(PumpFunEvent::Unhandled(signature.to_string(), decoded))
+        [223, 195, 159, 246, 62, 48, 143, 131] => SetParamsEvent::decode_versioned(event_data)
+            .map_err(|err| decode_error("SetParamsEvent", err))?
+            .map(PumpFunEvent::SetParams),
+        // AdminSetCreatorEvent
+        [64, 69, 192, 104, 29, 30, 25, 107] => {
+            decode_event("AdminSetCreatorEvent", event_data)?.map(PumpFunEvent::AdminSetCreator)
+        }
+        // AdminSetIdlAuthorityEvent
+        [245, 59, 70, 34, 75, 185, 109, 92] => {
+            decode_event("AdminSetIdlAuthorityEvent", event_data)?
+                .map(PumpFunEvent::AdminSetIdlAuthority)
+        }
+        // AdminUpdateTokenIncentivesEvent
+        [147, 250, 108, 120, 247, 29, 67, 222] => {
+            decode_event("AdminUpdateTokenIncentivesEvent", event_data)?
+                .map(PumpFunEvent::AdminUpdateTokenIncentives)
+        }
+        // ClaimTokenIncentivesEvent
+        [79, 172, 246, 49, 205, 91, 206, 232] => {
+            decode_event("ClaimTokenIncentivesEvent", event_data)?
+                .map(PumpFunEvent::ClaimTokenIncentives)
+        }
+        // CloseUserVolumeAccumulatorEvent
+        [146, 159, 189, 172, 146, 88, 56, 244] => {
+            decode_event("CloseUserVolumeAccumulatorEvent", event_data)?
+                .map(PumpFunEvent::CloseUserVolumeAccumulator)
+        }
+        // CollectCreatorFeeEvent
+        [122, 2, 127, 1, 14, 191, 12, 175] => {
+            decode_event("CollectCreatorFeeEvent", event_data)?.map(PumpFunEvent::CollectCreatorFee)
+        }
+        // CompletePumpAmmMigrationEvent
+        [189, 233, 93, 185, 92, 148, 234, 148] => {
+            decode_event("CompletePumpAmmMigrationEvent", event_data)?
+                .map(PumpFunEvent::CompletePumpAmmMigration)
+        }
+        // ExtendAccountEvent
+        [97, 97, 215, 144, 93, 146, 22, 124] => {
+            decode_event("ExtendAccountEvent", event_data)?.map(PumpFunEvent::ExtendAccount)
+        }
+        // InitUserVolumeAccumulatorEvent
+        [134, 36, 13, 72, 232, 101, 130, 216] => {
+            decode_event("InitUserVolumeAccumulatorEvent", event_data)?
+                .map(PumpFunEvent::InitUserVolumeAccumulator)
+        }
+        // SetCreatorEvent
+        [237, 52, 123, 37, 245, 251, 72, 210] => {
+            decode_event("SetCreatorEvent", event_data)?.map(PumpFunEvent::SetCreator)
+        }
+        // SetMetaplexCreatorEvent
+        [142, 203, 6, 32, 127, 105, 191, 162] => {
+            decode_event("SetMetaplexCreatorEvent", event_data)?
+                .map(PumpFunEvent::SetMetaplexCreator)
+        }
+        // SyncUserVolumeAccumulatorEvent
+        [197, 122, 167, 124, 116, 81, 91, 255] => {
+            decode_event("SyncUserVolumeAccumulatorEvent", event_data)?
+                .map(PumpFunEvent::SyncUserVolumeAccumulator)
+        }
+        // UpdateGlobalAuthorityEvent
+        [182, 195, 137, 42, 35, 206, 207, 247] => {
+            decode_event("UpdateGlobalAuthorityEvent", event_data)?
+                .map(PumpFunEvent::UpdateGlobalAuthority)
         }
         // Unknown event type
-        _ => Ok(PumpFunEvent::Unknown(signature.to_string(), decoded)),
-    }
+        _ => Decoded::new(PumpFunEvent::Unknown(signature.to_string(), decoded), 1),
+    };
+
+    Ok(event)
+}
+
+/// Deserializes the data of an event with a single known layout
+///
+/// Bytes after the known fields are ignored, so fields appended by newer versions of
+/// the program do not break decoding.
+fn decode_event<T: BorshDeserialize>(
+    name: &str,
+    mut data: &[u8],
+) -> Result<Decoded<T>, Box<dyn Error + Send + Sync>> {
+    let event = T::deserialize(&mut data).map_err(|err| decode_error(name, err))?;
+    Ok(Decoded::new(event, 1))
+}
+
+/// Describes a failure to decode an event
+fn decode_error(name: &str, err: std::io::Error) -> Box<dyn Error + Send + Sync> {
+    format!("Failed to decode {}: {}", name, err).into()
 }
 
 /// Subscribes to Pump.fun program events emitted on-chain
@@ -286,11 +723,20 @@ where
         + Sync
         + 'static,
 {
-    // Initialize PubsubClient
-    let ws_url = &cluster.rpc.ws;
-    let pubsub_client = PubsubClient::new(ws_url)
-        .await
-        .map_err(error::ClientError::PubsubClientError)?;
+    // Initialize PubsubClient with the first endpoint accepting the connection
+    let mut pubsub_client = Err(None);
+    for endpoint in cluster.get_endpoints() {
+        match PubsubClient::new(&endpoint.ws).await {
+            Ok(client) => {
+                pubsub_client = Ok(client);
+                break;
+            }
+            Err(err) => pubsub_client = Err(Some(err)),
+        }
+    }
+    let pubsub_client = pubsub_client.map_err(|err| {
+        error::ClientError::PubsubClientError(err.expect("Cluster has at least one endpoint"))
+    })?;
 
     let (tx, _) = mpsc::channel(1);
     let (cb_tx, mut cb_rx) = mpsc::channel(1000);
@@ -357,6 +803,437 @@ mod tests {
     use tokio::sync::Mutex;
     use tokio::time::{timeout, Duration};
 
+    fn encode_event<T: BorshSerialize>(discriminator: [u8; 8], event: &T) -> String {
+        let mut data = discriminator.to_vec();
+        event.serialize(&mut data).unwrap();
+        base64::engine::general_purpose::STANDARD.encode(data)
+    }
+
+    #[test]
+    fn test_parse_collect_creator_fee_event() {
+        let event = CollectCreatorFeeEvent {
+            timestamp: 1_700_000_000,
+            creator: Pubkey::new_unique(),
+            creator_fee: 42_000,
+        };
+        let data = encode_event([122, 2, 127, 1, 14, 191, 12, 175], &event);
+
+        match parse_event("sig", &data).unwrap() {
+            PumpFunEvent::CollectCreatorFee(parsed) => {
+                assert_eq!(parsed.creator, event.creator);
+                assert_eq!(parsed.creator_fee, 42_000);
+                assert_eq!(parsed.timestamp, 1_700_000_000);
+            }
+            other => panic!("Expected CollectCreatorFee, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_volume_accumulator_events() {
+        let user = Pubkey::new_unique();
+
+        let data = encode_event(
+            [79, 172, 246, 49, 205, 91, 206, 232],
+            &ClaimTokenIncentivesEvent {
+                user,
+                mint: Pubkey::new_unique(),
+                amount: 1_000,
+                timestamp: 1,
+                total_claimed_tokens: 5_000,
+                current_sol_volume: 0,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::ClaimTokenIncentives(e) if e.user == user && e.amount == 1_000
+        ));
+
+        let data = encode_event(
+            [197, 122, 167, 124, 116, 81, 91, 255],
+            &SyncUserVolumeAccumulatorEvent {
+                user,
+                total_claimed_tokens_before: 10,
+                total_claimed_tokens_after: 20,
+                timestamp: 2,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::SyncUserVolumeAccumulator(e) if e.total_claimed_tokens_after == 20
+        ));
+
+        let data = encode_event(
+            [134, 36, 13, 72, 232, 101, 130, 216],
+            &InitUserVolumeAccumulatorEvent {
+                payer: user,
+                user,
+                timestamp: 3,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::InitUserVolumeAccumulator(e) if e.payer == user
+        ));
+
+        let data = encode_event(
+            [146, 159, 189, 172, 146, 88, 56, 244],
+            &CloseUserVolumeAccumulatorEvent {
+                user,
+                timestamp: 4,
+                total_unclaimed_tokens: 0,
+                total_claimed_tokens: 20,
+                current_sol_volume: 0,
+                last_update_timestamp: 3,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::CloseUserVolumeAccumulator(e) if e.total_claimed_tokens == 20
+        ));
+    }
+
+    #[test]
+    fn test_parse_creator_events() {
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let data = encode_event(
+            [237, 52, 123, 37, 245, 251, 72, 210],
+            &SetCreatorEvent {
+                timestamp: 1,
+                mint,
+                bonding_curve: Pubkey::new_unique(),
+                creator,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::SetCreator(e) if e.mint == mint && e.creator == creator
+        ));
+
+        let data = encode_event(
+            [64, 69, 192, 104, 29, 30, 25, 107],
+            &AdminSetCreatorEvent {
+                timestamp: 1,
+                admin_set_creator_authority: Pubkey::new_unique(),
+                mint,
+                bonding_curve: Pubkey::new_unique(),
+                old_creator: Pubkey::default(),
+                new_creator: creator,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::AdminSetCreator(e) if e.new_creator == creator
+        ));
+
+        let data = encode_event(
+            [142, 203, 6, 32, 127, 105, 191, 162],
+            &SetMetaplexCreatorEvent {
+                timestamp: 1,
+                mint,
+                bonding_curve: Pubkey::new_unique(),
+                metadata: Pubkey::new_unique(),
+                creator,
+            },
+        );
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::SetMetaplexCreator(e) if e.creator == creator
+        ));
+    }
+
+    #[test]
+    fn test_parse_admin_and_migration_events() {
+        let account = Pubkey::new_unique();
+
+        let cases = [
+            encode_event(
+                [245, 59, 70, 34, 75, 185, 109, 92],
+                &AdminSetIdlAuthorityEvent {
+                    idl_authority: account,
+                },
+            ),
+            encode_event(
+                [147, 250, 108, 120, 247, 29, 67, 222],
+                &AdminUpdateTokenIncentivesEvent {
+                    start_time: 0,
+                    end_time: 86_400,
+                    day_number: 0,
+                    token_supply_per_day: 1_000_000,
+                    mint: account,
+                    seconds_in_a_day: 86_400,
+                    timestamp: 1,
+                },
+            ),
+            encode_event(
+                [189, 233, 93, 185, 92, 148, 234, 148],
+                &CompletePumpAmmMigrationEvent {
+                    user: account,
+                    mint: Pubkey::new_unique(),
+                    mint_amount: 206_900_000_000_000,
+                    sol_amount: 84_990_359_120,
+                    pool_migration_fee: 15_000_001,
+                    bonding_curve: Pubkey::new_unique(),
+                    timestamp: 1,
+                    pool: Pubkey::new_unique(),
+                },
+            ),
+            encode_event(
+                [97, 97, 215, 144, 93, 146, 22, 124],
+                &ExtendAccountEvent {
+                    account,
+                    user: Pubkey::new_unique(),
+                    current_size: 81,
+                    new_size: 150,
+                    timestamp: 1,
+                },
+            ),
+            encode_event(
+                [182, 195, 137, 42, 35, 206, 207, 247],
+                &UpdateGlobalAuthorityEvent {
+                    global: Pubkey::new_unique(),
+                    authority: Pubkey::new_unique(),
+                    new_authority: account,
+                    timestamp: 1,
+                },
+            ),
+        ];
+
+        let events: Vec<PumpFunEvent> = cases
+            .iter()
+            .map(|data| parse_event("sig", data).unwrap())
+            .collect();
+        assert!(
+            matches!(&events[0], PumpFunEvent::AdminSetIdlAuthority(e) if e.idl_authority == account)
+        );
+        assert!(
+            matches!(&events[1], PumpFunEvent::AdminUpdateTokenIncentives(e) if e.mint == account)
+        );
+        assert!(
+            matches!(&events[2], PumpFunEvent::CompletePumpAmmMigration(e) if e.user == account)
+        );
+        assert!(matches!(&events[3], PumpFunEvent::ExtendAccount(e) if e.new_size == 150));
+        assert!(
+            matches!(&events[4], PumpFunEvent::UpdateGlobalAuthority(e) if e.new_authority == account)
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_event() {
+        let data = encode_event([122, 2, 127, 1, 14, 191, 12, 175], &42u64);
+        let err = parse_event("sig", &data).unwrap_err();
+        assert!(err.to_string().contains("CollectCreatorFeeEvent"));
+
+        let data = encode_event([0; 8], &42u64);
+        assert!(matches!(
+            parse_event("sig", &data).unwrap(),
+            PumpFunEvent::Unknown(..)
+        ));
+    }
+
+    fn get_trade_event() -> TradeEvent {
+        TradeEvent {
+            mint: Pubkey::new_unique(),
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            is_buy: true,
+            user: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+            virtual_sol_reserves: 30_000,
+            virtual_token_reserves: 40_000,
+            real_sol_reserves: 3_000,
+            real_token_reserves: 4_000,
+            fee_recipient: Pubkey::new_unique(),
+            fee_basis_points: 95,
+            fee: 10,
+            creator: Pubkey::new_unique(),
+            creator_fee_basis_points: 30,
+            creator_fee: 3,
+            track_volume: true,
+            total_unclaimed_tokens: 5,
+            total_claimed_tokens: 6,
+            current_sol_volume: 7,
+            last_update_timestamp: 8,
+            ix_name: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_trade_event_versions() {
+        let event = get_trade_event();
+        let data = borsh::to_vec(&event).unwrap();
+
+        // Original layout without fees or volume tracking
+        let decoded = TradeEvent::decode_versioned(&data[..105]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.value.virtual_token_reserves, 40_000);
+        assert_eq!(decoded.value.real_sol_reserves, 0);
+        assert_eq!(decoded.value.creator, Pubkey::default());
+        assert!(!decoded.value.track_volume);
+
+        let decoded = TradeEvent::decode_versioned(&data[..217]).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.creator, event.creator);
+        assert_eq!(decoded.value.creator_fee, 3);
+        assert_eq!(decoded.value.total_claimed_tokens, 0);
+
+        let decoded = TradeEvent::decode_versioned(&data[..250]).unwrap();
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.value.last_update_timestamp, 8);
+        assert_eq!(decoded.value.ix_name, None);
+
+        // The program logs the instruction name as a plain string
+        let mut logged = data[..250].to_vec();
+        logged.extend_from_slice(&borsh::to_vec("buy").unwrap());
+        let decoded = TradeEvent::decode_versioned(&logged).unwrap();
+        assert_eq!(decoded.version, 4);
+        assert_eq!(decoded.value.ix_name.as_deref(), Some("buy"));
+
+        let decoded = TradeEvent::decode_versioned(&data).unwrap();
+        assert_eq!(decoded.version, 4);
+        assert_eq!(decoded.value.ix_name, None);
+
+        assert!(TradeEvent::decode_versioned(&data[..150]).is_err());
+    }
+
+    #[test]
+    fn test_trade_event_usd_value() {
+        let mut event = get_trade_event();
+        event.sol_amount = 2_500_000_000;
+        assert_eq!(event.get_sol_amount_usd(150.0), 375.0);
+    }
+
+    #[test]
+    fn test_decode_create_event_versions() {
+        let event = CreateEvent {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com".to_string(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+            virtual_token_reserves: 1,
+            virtual_sol_reserves: 2,
+            real_token_reserves: 3,
+            token_total_supply: 4,
+        };
+        let data = borsh::to_vec(&event).unwrap();
+
+        let decoded = CreateEvent::decode_versioned(&data).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.creator, event.creator);
+        assert_eq!(decoded.value.token_total_supply, 4);
+
+        // Original layout ending with the user
+        let decoded = CreateEvent::decode_versioned(&data[..data.len() - 72]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.value.symbol, "TKN");
+        assert_eq!(decoded.value.user, event.user);
+        assert_eq!(decoded.value.creator, Pubkey::default());
+        assert_eq!(decoded.value.timestamp, 0);
+
+        assert!(CreateEvent::decode_versioned(&data[..data.len() - 10]).is_err());
+    }
+
+    #[test]
+    fn test_decode_set_params_event_versions() {
+        let event = SetParamsEvent {
+            initial_virtual_token_reserves: 1,
+            initial_virtual_sol_reserves: 2,
+            initial_real_token_reserves: 3,
+            final_real_sol_reserves: 4,
+            token_total_supply: 5,
+            fee_basis_points: 95,
+            withdraw_authority: Pubkey::new_unique(),
+            enable_migrate: true,
+            pool_migration_fee: 15_000_001,
+            creator_fee_basis_points: 5,
+            fee_recipients: std::array::from_fn(|_| Pubkey::new_unique()),
+            timestamp: 1_700_000_000,
+            set_creator_authority: Pubkey::new_unique(),
+            admin_set_creator_authority: Pubkey::new_unique(),
+        };
+        let data = borsh::to_vec(&event).unwrap();
+
+        let decoded = SetParamsEvent::decode_versioned(&data).unwrap();
+        assert_eq!(decoded.version, 4);
+        assert_eq!(decoded.value.fee_recipients, event.fee_recipients);
+        assert_eq!(
+            decoded.value.admin_set_creator_authority,
+            event.admin_set_creator_authority
+        );
+
+        // Layout with seven fee recipients and no admin set creator authority
+        let mut older = data[..97 + 7 * 32].to_vec();
+        older.extend_from_slice(
+            &borsh::to_vec(&(event.timestamp, event.set_creator_authority)).unwrap(),
+        );
+        let decoded = SetParamsEvent::decode_versioned(&older).unwrap();
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.value.fee_recipients[..7], event.fee_recipients[..7]);
+        assert_eq!(decoded.value.fee_recipients[7], Pubkey::default());
+        assert_eq!(decoded.value.timestamp, event.timestamp);
+        assert_eq!(
+            decoded.value.set_creator_authority,
+            event.set_creator_authority
+        );
+        assert_eq!(decoded.value.admin_set_creator_authority, Pubkey::default());
+
+        // Layout before the creator fee
+        let decoded = SetParamsEvent::decode_versioned(&data[..89]).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.value.withdraw_authority, event.withdraw_authority);
+        assert_eq!(decoded.value.pool_migration_fee, 15_000_001);
+        assert_eq!(decoded.value.creator_fee_basis_points, 0);
+
+        // Original layout
+        let decoded = SetParamsEvent::decode_versioned(&data[..48]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.value.fee_basis_points, 95);
+        assert!(!decoded.value.enable_migrate);
+
+        assert!(SetParamsEvent::decode_versioned(&data[..200]).is_err());
+    }
+
+    #[test]
+    fn test_parse_event_versioned() {
+        let data = encode_event([189, 219, 127, 211, 78, 230, 97, 238], &get_trade_event());
+        let mut decoded = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .unwrap();
+        decoded.truncate(8 + 105);
+        let data = base64::engine::general_purpose::STANDARD.encode(decoded);
+
+        let parsed = parse_event_versioned("sig", &data).unwrap();
+        assert_eq!(parsed.version, 1);
+        assert!(matches!(
+            parsed.value,
+            PumpFunEvent::Trade(e) if e.token_amount == 2_000 && e.fee == 0
+        ));
+
+        // Fields appended by newer program versions are ignored
+        let mut data = [95, 114, 97, 156, 212, 46, 152, 8].to_vec();
+        let event = CompleteEvent {
+            user: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            timestamp: 1,
+        };
+        data.extend_from_slice(&borsh::to_vec(&event).unwrap());
+        data.extend_from_slice(&[1, 2, 3]);
+        let data = base64::engine::general_purpose::STANDARD.encode(data);
+
+        let parsed = parse_event_versioned("sig", &data).unwrap();
+        assert_eq!(parsed.version, 1);
+        assert!(matches!(
+            parsed.value,
+            PumpFunEvent::Complete(e) if e.mint == event.mint
+        ));
+    }
+
     #[cfg(not(skip_expensive_tests))]
     #[tokio::test]
     async fn test_subscribe() {
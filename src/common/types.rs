@@ -3,8 +3,11 @@
 //! This module provides common types and utilities that are used throughout the SDK, including:
 //!
 //! - Configuration structures for Solana clusters
-//! - Priority fee settings for transactions
+//! - Priority fee settings for transactions, including strategies resolving the
+//!   compute unit price when a transaction is sent
 //! - Helper methods for connecting to different Solana networks
+//! - Format of the transactions built by the client
+//! - Results of decoding data that has several known layouts
 //!
 //! These utilities help with configuring the connection to the Solana blockchain
 //! and managing transaction parameters.
@@ -15,16 +18,30 @@ use solana_sdk::commitment_config::CommitmentConfig;
 /// Configuration for priority fee compute unit parameters
 ///
 /// Priority fees allow transactions to be prioritized by validators based on
-/// the fee paid per compute unit.
+/// the fee paid per compute unit. The price per compute unit is given by a
+/// `PriorityFeeStrategy`, which is resolved when the transaction is sent.
+///
+/// The compute unit limit can either be fixed with `unit_limit`, or estimated
+/// automatically by simulating the transaction before it is sent and adding a margin
+/// to the compute units it consumed, with `unit_limit_margin_basis_points`.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PriorityFee {
-    /// Maximum compute units that can be consumed by the transaction
+    /// Maximum compute units that can be consumed by the transaction. When the limit is
+    /// estimated automatically, this is the limit used to simulate the transaction
     pub unit_limit: Option<u32>,
-    /// Price in micro-lamports per compute unit
-    pub unit_price: Option<u64>,
+    /// Strategy resolving the price in micro-lamports per compute unit
+    pub unit_price: Option<PriorityFeeStrategy>,
+    /// Margin in basis points (1 bp = 0.01%) added to the compute units consumed by a
+    /// simulation of the transaction to set its unit limit. If None, the unit limit is
+    /// not estimated
+    #[serde(default)]
+    pub unit_limit_margin_basis_points: Option<u64>,
 }
 
 impl PriorityFee {
+    /// Maximum compute unit limit of a transaction
+    pub const MAX_UNIT_LIMIT: u32 = 1_400_000;
+
     /// Creates a new priority fee configuration
     ///
     /// # Arguments
@@ -38,11 +55,172 @@ impl PriorityFee {
     pub fn new(unit_limit: Option<u32>, unit_price: Option<u64>) -> Self {
         PriorityFee {
             unit_limit,
-            unit_price,
+            unit_price: unit_price.map(PriorityFeeStrategy::Fixed),
+            unit_limit_margin_basis_points: None,
+        }
+    }
+
+    /// Sets the strategy resolving the price per compute unit
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - Strategy resolving the price in micro-lamports per compute unit
+    ///
+    /// # Returns
+    ///
+    /// The priority fee configuration with the given price strategy
+    pub fn with_unit_price(mut self, strategy: PriorityFeeStrategy) -> Self {
+        self.unit_price = Some(strategy);
+        self
+    }
+
+    /// Estimates the compute unit limit automatically with a margin
+    ///
+    /// The transaction is simulated before it is sent, and its unit limit is set to the
+    /// compute units consumed in the simulation plus the margin.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin_basis_points` - Margin in basis points (1 bp = 0.01%) added to the consumed compute units
+    ///
+    /// # Returns
+    ///
+    /// The priority fee configuration with automatic unit limit estimation
+    pub fn with_auto_unit_limit(mut self, margin_basis_points: u64) -> Self {
+        self.unit_limit_margin_basis_points = Some(margin_basis_points);
+        self
+    }
+
+    /// Gets the compute unit limit for the compute units consumed by a simulation
+    ///
+    /// # Arguments
+    ///
+    /// * `units_consumed` - Compute units consumed by a simulation of the transaction
+    ///
+    /// # Returns
+    ///
+    /// The consumed compute units plus the margin, capped at `MAX_UNIT_LIMIT`, or None if
+    /// the unit limit is not estimated automatically
+    pub fn get_estimated_unit_limit(&self, units_consumed: u64) -> Option<u32> {
+        let margin_basis_points = self.unit_limit_margin_basis_points?;
+        let margin = (units_consumed as u128 * margin_basis_points as u128).div_ceil(10_000);
+        let unit_limit = (units_consumed as u128 + margin).min(Self::MAX_UNIT_LIMIT as u128);
+        Some(unit_limit as u32)
+    }
+}
+
+/// Strategy resolving the price per compute unit of a transaction
+///
+/// Strategies other than `Fixed` are resolved when the transaction is sent, so that the
+/// price follows the network conditions at that time. Fixed prices are serialized as a
+/// plain number, matching the former `unit_price` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PriorityFeeStrategy {
+    /// Fixed price in micro-lamports per compute unit
+    Fixed(u64),
+    /// Percentile of the prioritization fees paid in recent slots by transactions
+    /// writing to the accounts of the Pump.fun instructions, such as the bonding curve
+    /// and the fee recipient
+    Percentile {
+        /// Percentile of the recent fees, from 0 to 100
+        percentile: u8,
+        /// Optional maximum price in micro-lamports per compute unit
+        max_price: Option<u64>,
+    },
+    /// Price increasing each time the transaction fails to confirm, up to a maximum
+    Escalate {
+        /// Price in micro-lamports per compute unit of the first attempt
+        initial_price: u64,
+        /// Increase of the price between attempts, in basis points (1 bp = 0.01%)
+        increase_basis_points: u64,
+        /// Maximum price in micro-lamports per compute unit
+        max_price: u64,
+        /// Maximum number of attempts to send the transaction
+        max_attempts: u32,
+    },
+}
+
+impl PriorityFeeStrategy {
+    /// Gets the price known without accessing the network
+    ///
+    /// # Returns
+    ///
+    /// The fixed price, the price of the first attempt of an escalation, or None for
+    /// strategies resolved from the network
+    pub fn get_static_price(&self) -> Option<u64> {
+        match self {
+            Self::Fixed(price) => Some(*price),
+            Self::Percentile { .. } => None,
+            Self::Escalate { initial_price, .. } => Some(*initial_price),
+        }
+    }
+
+    /// Gets the maximum number of attempts to send a transaction
+    pub fn get_max_attempts(&self) -> u32 {
+        match self {
+            Self::Escalate { max_attempts, .. } => (*max_attempts).max(1),
+            _ => 1,
+        }
+    }
+
+    /// Gets the price of an attempt to send a transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Index of the attempt, starting at 0
+    /// * `recent_fees` - Prioritization fees paid in recent slots, used by `Percentile`
+    ///
+    /// # Returns
+    ///
+    /// The price in micro-lamports per compute unit of the attempt
+    pub fn get_price(&self, attempt: u32, recent_fees: &[u64]) -> u64 {
+        match *self {
+            Self::Fixed(price) => price,
+            Self::Percentile {
+                percentile,
+                max_price,
+            } => {
+                let price = get_percentile(recent_fees, percentile);
+                max_price.map_or(price, |max_price| price.min(max_price))
+            }
+            Self::Escalate {
+                initial_price,
+                increase_basis_points,
+                max_price,
+                ..
+            } => {
+                let mut price = initial_price as u128;
+                for _ in 0..attempt {
+                    price += (price * increase_basis_points as u128).div_ceil(10_000);
+                    if price >= max_price as u128 {
+                        break;
+                    }
+                }
+                price.min(max_price as u128) as u64
+            }
         }
     }
 }
 
+impl From<u64> for PriorityFeeStrategy {
+    fn from(price: u64) -> Self {
+        Self::Fixed(price)
+    }
+}
+
+/// Gets a percentile of a set of values with the nearest-rank method, or 0 if it is empty
+fn get_percentile(values: &[u64], percentile: u8) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+
+    let mut values = values.to_vec();
+    values.sort_unstable();
+    let rank = (values.len() * percentile.min(100) as usize).div_ceil(100);
+    values[rank.saturating_sub(1)]
+}
+
 /// RPC connection endpoints for a Solana cluster
 ///
 /// # Fields
@@ -79,11 +257,13 @@ impl RpcEndpoint {
 /// # Fields
 ///
 /// * `rpc` - RPC endpoints for the cluster
+/// * `fallback_rpcs` - Additional RPC endpoints used when the primary endpoints fail
 /// * `commitment` - Commitment level for confirmations
 /// * `priority_fee` - Priority fee configuration for transactions
 #[derive(Debug, Clone)]
 pub struct Cluster {
     pub rpc: RpcEndpoint,
+    pub fallback_rpcs: Vec<RpcEndpoint>,
     pub commitment: CommitmentConfig,
     pub priority_fee: PriorityFee,
 }
@@ -109,11 +289,52 @@ impl Cluster {
     ) -> Self {
         Self {
             rpc: RpcEndpoint { http, ws },
+            fallback_rpcs: Vec::new(),
             commitment,
             priority_fee,
         }
     }
 
+    /// Adds a fallback RPC endpoint to the cluster configuration
+    ///
+    /// With fallback endpoints, reads are sent to the healthiest endpoint and fail over
+    /// to the others, transactions are sent to every endpoint concurrently, and event
+    /// subscriptions connect to the first endpoint accepting the connection. See
+    /// `FailoverSender` for the details.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - RPC endpoint of another provider for the same cluster
+    ///
+    /// # Returns
+    ///
+    /// The cluster configuration with the fallback endpoint
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::common::types::{Cluster, PriorityFee, RpcEndpoint};
+    /// use solana_sdk::commitment_config::CommitmentConfig;
+    ///
+    /// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default())
+    ///     .with_fallback(RpcEndpoint::new(
+    ///         "https://solana-rpc.publicnode.com".to_string(),
+    ///         "wss://solana-rpc.publicnode.com".to_string(),
+    ///     ));
+    /// assert_eq!(cluster.get_endpoints().len(), 2);
+    /// ```
+    pub fn with_fallback(mut self, endpoint: RpcEndpoint) -> Self {
+        self.fallback_rpcs.push(endpoint);
+        self
+    }
+
+    /// Returns the RPC endpoints of the cluster, the primary endpoints first
+    pub fn get_endpoints(&self) -> Vec<&RpcEndpoint> {
+        std::iter::once(&self.rpc)
+            .chain(&self.fallback_rpcs)
+            .collect()
+    }
+
     /// Creates a configuration for the Solana mainnet-beta cluster
     ///
     /// # Arguments
@@ -190,3 +411,53 @@ impl Cluster {
         )
     }
 }
+
+/// Format of the transactions built by the client
+///
+/// Legacy transactions are supported by every wallet and RPC provider, while v0
+/// transactions can reference accounts through address lookup tables, so that more
+/// instructions fit in a transaction. The default format is legacy, or v0 when the
+/// "versioned-tx" feature is enabled.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionFormat {
+    /// Legacy transaction, without address lookup tables
+    #[cfg_attr(not(feature = "versioned-tx"), default)]
+    Legacy,
+    /// Versioned transaction with a v0 message, using the address lookup tables given
+    #[cfg_attr(feature = "versioned-tx", default)]
+    V0,
+}
+
+/// Value decoded from data with several known layouts
+///
+/// Accounts and events gained fields over time. When decoding data written with an
+/// older layout, the missing fields take their default value and `version` tells which
+/// layout matched, starting at 1 for the oldest known layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoded<T> {
+    /// Decoded value
+    pub value: T,
+    /// Version of the layout the data was decoded with
+    pub version: u8,
+}
+
+impl<T> Decoded<T> {
+    /// Creates a new decoded value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Decoded value
+    /// * `version` - Version of the layout the data was decoded with
+    pub fn new(value: T, version: u8) -> Self {
+        Self { value, version }
+    }
+
+    /// Maps the decoded value, keeping its layout version
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Decoded<U> {
+        Decoded {
+            value: f(self.value),
+            version: self.version,
+        }
+    }
+}
@@ -8,12 +8,25 @@
 //! # Error Types
 //!
 //! - `BondingCurveNotFound`: The bonding curve account was not found.
-//! - `BondingCurveError`: An error occurred while interacting with the bonding curve.
+//! - `BondingCurveError`: A bonding curve calculation failed, see `CurveMathError`.
 //! - `BorshError`: An error occurred while serializing or deserializing data using Borsh.
 //! - `SolanaClientError`: An error occurred while interacting with the Solana RPC client.
 //! - `PubsubClientError`: An error occurred while interacting with the Solana Pubsub client.
 //! - `UploadMetadataError`: An error occurred while uploading metadata to IPFS.
+//! - `PriceSourceError`: The USD price of SOL could not be fetched from a price source.
+//! - `InvalidInstruction`: An instruction could not be decoded as a Pump.fun instruction.
+//! - `InvalidAccount`: An account does not start with the expected account discriminator.
+//! - `InvalidAccountOwner`: An account is not owned by the expected program.
 //! - `OtherError`: An error occurred that is not covered by the other error types.
+//!
+//! Bonding curve calculations return a `CurveMathError` describing why the calculation
+//! failed, which converts into `ClientError::BondingCurveError`.
+//!
+//! Custom error codes of failed Pump.fun instructions are mapped to `ProgramError`.
+
+pub mod program;
+
+pub use program::ProgramError;
 
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -21,7 +34,7 @@ pub enum ClientError {
     /// Bonding curve account was not found
     BondingCurveNotFound,
     /// Error related to bonding curve operations
-    BondingCurveError(&'static str),
+    BondingCurveError(CurveMathError),
     /// Error deserializing data using Borsh
     BorshError(std::io::Error),
     /// Error from Solana RPC client
@@ -31,6 +44,24 @@ pub enum ClientError {
     PubsubClientError(solana_client::pubsub_client::PubsubClientError),
     /// Error uploading metadata
     UploadMetadataError(Box<dyn std::error::Error>),
+    /// Error fetching the USD price of SOL
+    PriceSourceError(String),
+    /// Instruction could not be decoded as a Pump.fun instruction
+    InvalidInstruction(String),
+    /// Account data does not start with the expected account discriminator
+    InvalidAccount {
+        /// Discriminator of the requested account type
+        expected: [u8; 8],
+        /// First bytes of the account data, zero padded if shorter
+        found: [u8; 8],
+    },
+    /// Account is not owned by the expected program
+    InvalidAccountOwner {
+        /// Program expected to own the account
+        expected: solana_sdk::pubkey::Pubkey,
+        /// Actual owner of the account
+        found: solana_sdk::pubkey::Pubkey,
+    },
     /// Other error
     OtherError(String),
 }
@@ -39,12 +70,24 @@ impl std::fmt::Display for ClientError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::BondingCurveNotFound => write!(f, "Bonding curve not found"),
-            Self::BondingCurveError(msg) => write!(f, "Bonding curve error: {}", msg),
+            Self::BondingCurveError(err) => write!(f, "Bonding curve error: {}", err),
             Self::BorshError(err) => write!(f, "Borsh serialization error: {}", err),
             Self::SolanaClientError(err) => write!(f, "Solana client error: {}", err),
             #[cfg(feature = "stream")]
             Self::PubsubClientError(err) => write!(f, "Solana pubsub client error: {}", err),
             Self::UploadMetadataError(err) => write!(f, "Metadata upload error: {}", err),
+            Self::PriceSourceError(msg) => write!(f, "Price source error: {}", msg),
+            Self::InvalidInstruction(msg) => write!(f, "Invalid instruction: {}", msg),
+            Self::InvalidAccount { expected, found } => write!(
+                f,
+                "Invalid account discriminator: expected {:?}, found {:?}",
+                expected, found
+            ),
+            Self::InvalidAccountOwner { expected, found } => write!(
+                f,
+                "Invalid account owner: expected {}, found {}",
+                expected, found
+            ),
             Self::OtherError(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -53,6 +96,7 @@ impl std::fmt::Display for ClientError {
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::BondingCurveError(err) => Some(err),
             Self::BorshError(err) => Some(err),
             Self::SolanaClientError(err) => Some(err),
             #[cfg(feature = "stream")]
@@ -63,6 +107,12 @@ impl std::error::Error for ClientError {
     }
 }
 
+impl From<CurveMathError> for ClientError {
+    fn from(err: CurveMathError) -> Self {
+        Self::BondingCurveError(err)
+    }
+}
+
 impl From<solana_client::client_error::ClientError> for ClientError {
     fn from(err: solana_client::client_error::ClientError) -> Self {
         Self::SolanaClientError(err)
@@ -75,3 +125,38 @@ impl From<solana_client::pubsub_client::PubsubClientError> for ClientError {
         Self::PubsubClientError(err)
     }
 }
+
+/// Reasons a bonding curve calculation can fail
+///
+/// All bonding curve math uses checked arithmetic, so reserves and amounts that the
+/// program would reject produce one of these errors instead of a panic or a wrapped value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveMathError {
+    /// The bonding curve is complete and no longer trades
+    CurveComplete,
+    /// A result does not fit in its integer type
+    Overflow,
+    /// A subtraction went below zero
+    Underflow,
+    /// A division had a zero divisor
+    DivisionByZero,
+    /// The token reserves cannot cover the requested amount
+    InsufficientTokenReserves,
+    /// The SOL reserves cannot cover the requested amount
+    InsufficientSolReserves,
+}
+
+impl std::fmt::Display for CurveMathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CurveComplete => write!(f, "Curve is complete"),
+            Self::Overflow => write!(f, "Arithmetic overflow"),
+            Self::Underflow => write!(f, "Arithmetic underflow"),
+            Self::DivisionByZero => write!(f, "Division by zero"),
+            Self::InsufficientTokenReserves => write!(f, "Insufficient token reserves"),
+            Self::InsufficientSolReserves => write!(f, "Insufficient SOL reserves"),
+        }
+    }
+}
+
+impl std::error::Error for CurveMathError {}
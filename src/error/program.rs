@@ -0,0 +1,277 @@
+//! Errors raised by the Pump.fun program
+//!
+//! Transactions rejected by the Pump.fun program fail with a custom program error code.
+//! This module maps those codes to `ProgramError`, so that failed simulations and
+//! transactions report a typed reason instead of a raw code.
+
+/// Error raised by the Pump.fun program
+///
+/// Codes from 6000 are the errors of the Pump.fun program itself. Lower codes are
+/// raised by the Anchor framework, for example when an account constraint is violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramError {
+    /// The given account is not authorized to execute this instruction
+    NotAuthorized,
+    /// The program is already initialized
+    AlreadyInitialized,
+    /// Slippage: too much SOL required to buy the given amount of tokens
+    TooMuchSolRequired,
+    /// Slippage: too little SOL received to sell the given amount of tokens
+    TooLittleSolReceived,
+    /// The mint does not match the bonding curve
+    MintDoesNotMatchBondingCurve,
+    /// The bonding curve has completed and liquidity migrated to the AMM
+    BondingCurveComplete,
+    /// The bonding curve has not completed
+    BondingCurveNotComplete,
+    /// The program is not initialized
+    NotInitialized,
+    /// Withdraw too frequent
+    WithdrawTooFrequent,
+    /// The new account size should be greater than the current size
+    NewSizeShouldBeGreaterThanCurrentSize,
+    /// The account type is not supported
+    AccountTypeNotSupported,
+    /// The initial real token reserves should be less than the token total supply
+    InitialRealTokenReservesShouldBeLessThanTokenTotalSupply,
+    /// The initial virtual token reserves should be greater than the real token reserves
+    InitialVirtualTokenReservesShouldBeGreaterThanRealTokenReserves,
+    /// The fee basis points are greater than the maximum
+    FeeBasisPointsGreaterThanMaximum,
+    /// The withdraw authority cannot be set to the zero address
+    AllZerosWithdrawAuthority,
+    /// The pool migration fee should be less than the final real SOL reserves
+    PoolMigrationFeeShouldBeLessThanFinalRealSolReserves,
+    /// The pool migration fee should be greater than the creator fee plus the maximum migration fees
+    PoolMigrationFeeShouldBeGreaterThanCreatorFeePlusMaxMigrateFees,
+    /// Withdraw is disabled
+    DisabledWithdraw,
+    /// Migration is disabled
+    DisabledMigrate,
+    /// The creator is invalid
+    InvalidCreator,
+    /// Buy amount must be greater than zero
+    BuyZeroAmount,
+    /// Not enough tokens to buy
+    NotEnoughTokensToBuy,
+    /// Sell amount must be greater than zero
+    SellZeroAmount,
+    /// Not enough tokens to sell
+    NotEnoughTokensToSell,
+    /// Arithmetic overflow
+    Overflow,
+    /// Arithmetic truncation
+    Truncation,
+    /// Division by zero
+    DivisionByZero,
+    /// Not enough remaining accounts
+    NotEnoughRemainingAccounts,
+    /// All fee recipients should be non-zero
+    AllFeeRecipientsShouldBeNonZero,
+    /// Fee recipients should be sorted and unique
+    UnsortedNotUniqueFeeRecipients,
+    /// The creator should not be zero
+    CreatorShouldNotBeZero,
+    /// The start time is in the past
+    StartTimeInThePast,
+    /// The end time is in the past
+    EndTimeInThePast,
+    /// The end time is before the start time
+    EndTimeBeforeStartTime,
+    /// The time range is too large
+    TimeRangeTooLarge,
+    /// The end time is before the current day
+    EndTimeBeforeCurrentDay,
+    /// The token supply cannot be updated for a finished range
+    SupplyUpdateForFinishedRange,
+    /// The day index is after the end index
+    DayIndexAfterEndIndex,
+    /// The day is in an active range
+    DayInActiveRange,
+    /// The incentive mint is invalid
+    InvalidIncentiveMint,
+    /// Error raised by the Anchor framework, with its error code
+    Anchor(u32),
+    /// Error code unknown to this version of the SDK
+    Unknown(u32),
+}
+
+impl ProgramError {
+    /// First error code of the Pump.fun program, lower codes belong to the Anchor framework
+    pub const FIRST_CODE: u32 = 6000;
+
+    /// Maps a custom program error code to a program error
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Custom program error code of a failed instruction
+    pub fn from_code(code: u32) -> Self {
+        match code {
+            6000 => Self::NotAuthorized,
+            6001 => Self::AlreadyInitialized,
+            6002 => Self::TooMuchSolRequired,
+            6003 => Self::TooLittleSolReceived,
+            6004 => Self::MintDoesNotMatchBondingCurve,
+            6005 => Self::BondingCurveComplete,
+            6006 => Self::BondingCurveNotComplete,
+            6007 => Self::NotInitialized,
+            6008 => Self::WithdrawTooFrequent,
+            6009 => Self::NewSizeShouldBeGreaterThanCurrentSize,
+            6010 => Self::AccountTypeNotSupported,
+            6011 => Self::InitialRealTokenReservesShouldBeLessThanTokenTotalSupply,
+            6012 => Self::InitialVirtualTokenReservesShouldBeGreaterThanRealTokenReserves,
+            6013 => Self::FeeBasisPointsGreaterThanMaximum,
+            6014 => Self::AllZerosWithdrawAuthority,
+            6015 => Self::PoolMigrationFeeShouldBeLessThanFinalRealSolReserves,
+            6016 => Self::PoolMigrationFeeShouldBeGreaterThanCreatorFeePlusMaxMigrateFees,
+            6017 => Self::DisabledWithdraw,
+            6018 => Self::DisabledMigrate,
+            6019 => Self::InvalidCreator,
+            6020 => Self::BuyZeroAmount,
+            6021 => Self::NotEnoughTokensToBuy,
+            6022 => Self::SellZeroAmount,
+            6023 => Self::NotEnoughTokensToSell,
+            6024 => Self::Overflow,
+            6025 => Self::Truncation,
+            6026 => Self::DivisionByZero,
+            6027 => Self::NotEnoughRemainingAccounts,
+            6028 => Self::AllFeeRecipientsShouldBeNonZero,
+            6029 => Self::UnsortedNotUniqueFeeRecipients,
+            6030 => Self::CreatorShouldNotBeZero,
+            6031 => Self::StartTimeInThePast,
+            6032 => Self::EndTimeInThePast,
+            6033 => Self::EndTimeBeforeStartTime,
+            6034 => Self::TimeRangeTooLarge,
+            6035 => Self::EndTimeBeforeCurrentDay,
+            6036 => Self::SupplyUpdateForFinishedRange,
+            6037 => Self::DayIndexAfterEndIndex,
+            6038 => Self::DayInActiveRange,
+            6039 => Self::InvalidIncentiveMint,
+            code if code < Self::FIRST_CODE => Self::Anchor(code),
+            code => Self::Unknown(code),
+        }
+    }
+
+    /// Gets the custom program error code of the error
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::NotAuthorized => 6000,
+            Self::AlreadyInitialized => 6001,
+            Self::TooMuchSolRequired => 6002,
+            Self::TooLittleSolReceived => 6003,
+            Self::MintDoesNotMatchBondingCurve => 6004,
+            Self::BondingCurveComplete => 6005,
+            Self::BondingCurveNotComplete => 6006,
+            Self::NotInitialized => 6007,
+            Self::WithdrawTooFrequent => 6008,
+            Self::NewSizeShouldBeGreaterThanCurrentSize => 6009,
+            Self::AccountTypeNotSupported => 6010,
+            Self::InitialRealTokenReservesShouldBeLessThanTokenTotalSupply => 6011,
+            Self::InitialVirtualTokenReservesShouldBeGreaterThanRealTokenReserves => 6012,
+            Self::FeeBasisPointsGreaterThanMaximum => 6013,
+            Self::AllZerosWithdrawAuthority => 6014,
+            Self::PoolMigrationFeeShouldBeLessThanFinalRealSolReserves => 6015,
+            Self::PoolMigrationFeeShouldBeGreaterThanCreatorFeePlusMaxMigrateFees => 6016,
+            Self::DisabledWithdraw => 6017,
+            Self::DisabledMigrate => 6018,
+            Self::InvalidCreator => 6019,
+            Self::BuyZeroAmount => 6020,
+            Self::NotEnoughTokensToBuy => 6021,
+            Self::SellZeroAmount => 6022,
+            Self::NotEnoughTokensToSell => 6023,
+            Self::Overflow => 6024,
+            Self::Truncation => 6025,
+            Self::DivisionByZero => 6026,
+            Self::NotEnoughRemainingAccounts => 6027,
+            Self::AllFeeRecipientsShouldBeNonZero => 6028,
+            Self::UnsortedNotUniqueFeeRecipients => 6029,
+            Self::CreatorShouldNotBeZero => 6030,
+            Self::StartTimeInThePast => 6031,
+            Self::EndTimeInThePast => 6032,
+            Self::EndTimeBeforeStartTime => 6033,
+            Self::TimeRangeTooLarge => 6034,
+            Self::EndTimeBeforeCurrentDay => 6035,
+            Self::SupplyUpdateForFinishedRange => 6036,
+            Self::DayIndexAfterEndIndex => 6037,
+            Self::DayInActiveRange => 6038,
+            Self::InvalidIncentiveMint => 6039,
+            Self::Anchor(code) | Self::Unknown(code) => *code,
+        }
+    }
+}
+
+impl std::fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAuthorized => write!(f, "The given account is not authorized to execute this instruction"),
+            Self::AlreadyInitialized => write!(f, "The program is already initialized"),
+            Self::TooMuchSolRequired => write!(f, "Slippage: too much SOL required to buy the given amount of tokens"),
+            Self::TooLittleSolReceived => write!(f, "Slippage: too little SOL received to sell the given amount of tokens"),
+            Self::MintDoesNotMatchBondingCurve => write!(f, "The mint does not match the bonding curve"),
+            Self::BondingCurveComplete => write!(f, "The bonding curve has completed and liquidity migrated to the AMM"),
+            Self::BondingCurveNotComplete => write!(f, "The bonding curve has not completed"),
+            Self::NotInitialized => write!(f, "The program is not initialized"),
+            Self::WithdrawTooFrequent => write!(f, "Withdraw too frequent"),
+            Self::NewSizeShouldBeGreaterThanCurrentSize => write!(f, "The new account size should be greater than the current size"),
+            Self::AccountTypeNotSupported => write!(f, "The account type is not supported"),
+            Self::InitialRealTokenReservesShouldBeLessThanTokenTotalSupply => write!(f, "The initial real token reserves should be less than the token total supply"),
+            Self::InitialVirtualTokenReservesShouldBeGreaterThanRealTokenReserves => write!(f, "The initial virtual token reserves should be greater than the real token reserves"),
+            Self::FeeBasisPointsGreaterThanMaximum => write!(f, "The fee basis points are greater than the maximum"),
+            Self::AllZerosWithdrawAuthority => write!(f, "The withdraw authority cannot be set to the zero address"),
+            Self::PoolMigrationFeeShouldBeLessThanFinalRealSolReserves => write!(f, "The pool migration fee should be less than the final real SOL reserves"),
+            Self::PoolMigrationFeeShouldBeGreaterThanCreatorFeePlusMaxMigrateFees => write!(f, "The pool migration fee should be greater than the creator fee plus the maximum migration fees"),
+            Self::DisabledWithdraw => write!(f, "Withdraw is disabled"),
+            Self::DisabledMigrate => write!(f, "Migration is disabled"),
+            Self::InvalidCreator => write!(f, "The creator is invalid"),
+            Self::BuyZeroAmount => write!(f, "Buy amount must be greater than zero"),
+            Self::NotEnoughTokensToBuy => write!(f, "Not enough tokens to buy"),
+            Self::SellZeroAmount => write!(f, "Sell amount must be greater than zero"),
+            Self::NotEnoughTokensToSell => write!(f, "Not enough tokens to sell"),
+            Self::Overflow => write!(f, "Arithmetic overflow"),
+            Self::Truncation => write!(f, "Arithmetic truncation"),
+            Self::DivisionByZero => write!(f, "Division by zero"),
+            Self::NotEnoughRemainingAccounts => write!(f, "Not enough remaining accounts"),
+            Self::AllFeeRecipientsShouldBeNonZero => write!(f, "All fee recipients should be non-zero"),
+            Self::UnsortedNotUniqueFeeRecipients => write!(f, "Fee recipients should be sorted and unique"),
+            Self::CreatorShouldNotBeZero => write!(f, "The creator should not be zero"),
+            Self::StartTimeInThePast => write!(f, "The start time is in the past"),
+            Self::EndTimeInThePast => write!(f, "The end time is in the past"),
+            Self::EndTimeBeforeStartTime => write!(f, "The end time is before the start time"),
+            Self::TimeRangeTooLarge => write!(f, "The time range is too large"),
+            Self::EndTimeBeforeCurrentDay => write!(f, "The end time is before the current day"),
+            Self::SupplyUpdateForFinishedRange => write!(f, "The token supply cannot be updated for a finished range"),
+            Self::DayIndexAfterEndIndex => write!(f, "The day index is after the end index"),
+            Self::DayInActiveRange => write!(f, "The day is in an active range"),
+            Self::InvalidIncentiveMint => write!(f, "The incentive mint is invalid"),
+            Self::Anchor(code) => write!(f, "Anchor framework error {}", code),
+            Self::Unknown(code) => write!(f, "Unknown program error {}", code),
+        }
+    }
+}
+
+impl std::error::Error for ProgramError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_error_codes() {
+        assert_eq!(ProgramError::from_code(6000), ProgramError::NotAuthorized);
+        assert_eq!(
+            ProgramError::from_code(6002),
+            ProgramError::TooMuchSolRequired
+        );
+        assert_eq!(
+            ProgramError::from_code(6005),
+            ProgramError::BondingCurveComplete
+        );
+        assert_eq!(ProgramError::from_code(2006), ProgramError::Anchor(2006));
+        assert_eq!(ProgramError::from_code(9999), ProgramError::Unknown(9999));
+
+        // Codes round trip through every known error
+        for code in 0..7000 {
+            assert_eq!(ProgramError::from_code(code).code(), code);
+        }
+    }
+}
@@ -3,13 +3,14 @@
 //! This module provides the functionality to buy tokens from bonding curves.
 //! It includes the instruction data structure and helper function to build the Solana instruction.
 
-use crate::{constants::{self}, PumpFun};
+use crate::{
+    constants::{self},
+    PumpFun,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::Keypair,
-    signer::Signer,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -20,7 +21,7 @@ use spl_associated_token_account::get_associated_token_address;
 /// * `amount` - Amount of tokens to buy (in token smallest units)
 /// * `max_sol_cost` - Maximum acceptable SOL cost for the purchase (slippage protection)
 /// * `track_volume` - Whether to track this purchase in volume accumulators
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
 pub struct Buy {
     pub amount: u64,
     pub max_sol_cost: u64,
@@ -53,7 +54,7 @@ impl Buy {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
 /// * `mint` - Public key of the token mint to buy
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `creator` - Public key of the token's creator
@@ -83,7 +84,7 @@ impl Buy {
 /// 15. Fee configuration account (readonly)
 /// 16. Fee configuration program ID (readonly)
 pub fn buy(
-    payer: &Keypair,
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     creator: &Pubkey,
@@ -100,18 +101,15 @@ pub fn buy(
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new(creator_vault, false),
             AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
             AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
             AccountMeta::new(constants::accounts::GLOBAL_VOLUME_ACCUMULATOR, false),
-            AccountMeta::new(
-                PumpFun::get_user_volume_accumulator_pda(&payer.pubkey()),
-                false,
-            ),
+            AccountMeta::new(PumpFun::get_user_volume_accumulator_pda(payer), false),
             AccountMeta::new_readonly(constants::accounts::FEE_CONFIG, false),
             AccountMeta::new_readonly(constants::accounts::FEE_CONFIG_PROGRAM, false),
         ],
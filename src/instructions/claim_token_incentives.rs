@@ -0,0 +1,104 @@
+//! Instruction for claiming token incentives
+//!
+//! This module provides the functionality to transfer the incentive tokens a user earned
+//! by trading into the user's token account.
+//! It includes the instruction data structure and helper function to build the Solana instruction.
+
+use crate::{constants, PumpFun};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Instruction data for claiming token incentives
+///
+/// The instruction takes no arguments; all unclaimed tokens are transferred.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct ClaimTokenIncentives {}
+
+impl ClaimTokenIncentives {
+    /// Instruction discriminator used to identify this instruction
+    pub const DISCRIMINATOR: [u8; 8] = [16, 4, 71, 28, 204, 1, 40, 27];
+
+    /// Serializes the instruction data with the appropriate discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut data).unwrap();
+        data
+    }
+}
+
+/// Creates an instruction to claim the incentive tokens earned by a user
+///
+/// The tokens are transferred from the global volume accumulator's token account to the
+/// user's associated token account, which is created if it does not exist yet.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that pays for the transaction and token account creation
+/// * `user` - Public key of the user receiving the tokens
+/// * `mint` - Mint of the incentive token, as stored in the global volume accumulator
+/// * `token_program` - Token program owning the incentive mint
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will claim the user's incentive tokens
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. User account (readonly)
+/// 2. User's associated token account (writable)
+/// 3. Global volume accumulator (readonly)
+/// 4. Global volume accumulator's associated token account (writable)
+/// 5. User volume accumulator PDA (writable)
+/// 6. Incentive token mint (readonly)
+/// 7. Token program (readonly)
+/// 8. System program (readonly)
+/// 9. Associated token program (readonly)
+/// 10. Event authority (readonly)
+/// 11. Pump.fun program ID (readonly)
+/// 12. Payer account (signer, writable)
+pub fn claim_token_incentives(
+    payer: &Pubkey,
+    user: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let global_volume_accumulator = constants::accounts::GLOBAL_VOLUME_ACCUMULATOR;
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &ClaimTokenIncentives {}.data(),
+        vec![
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(user, mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(global_volume_accumulator, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &global_volume_accumulator,
+                    mint,
+                    token_program,
+                ),
+                false,
+            ),
+            AccountMeta::new(PumpFun::get_user_volume_accumulator_pda(user), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+            AccountMeta::new(*payer, true),
+        ],
+    )
+}
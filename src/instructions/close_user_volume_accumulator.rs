@@ -0,0 +1,68 @@
+//! Instruction for closing a user volume accumulator
+//!
+//! This module provides the functionality to close a user's volume accumulator and
+//! reclaim its rent.
+//! It includes the instruction data structure and helper function to build the Solana instruction.
+
+use crate::{constants, PumpFun};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// Instruction data for closing a user volume accumulator
+///
+/// The instruction takes no arguments; the accumulator is derived from the user.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct CloseUserVolumeAccumulator {}
+
+impl CloseUserVolumeAccumulator {
+    /// Instruction discriminator used to identify this instruction
+    pub const DISCRIMINATOR: [u8; 8] = [249, 69, 164, 218, 150, 103, 84, 138];
+
+    /// Serializes the instruction data with the appropriate discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut data).unwrap();
+        data
+    }
+}
+
+/// Creates an instruction to close the volume accumulator of a user
+///
+/// The rent of the accumulator is returned to the user. The program rejects the
+/// instruction while the accumulator still holds unclaimed tokens.
+///
+/// # Arguments
+///
+/// * `user` - Public key of the user the accumulator belongs to
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will close the user volume accumulator
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. User account (signer, writable)
+/// 2. User volume accumulator PDA (writable)
+/// 3. Event authority (readonly)
+/// 4. Pump.fun program ID (readonly)
+pub fn close_user_volume_accumulator(user: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &CloseUserVolumeAccumulator {}.data(),
+        vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(PumpFun::get_user_volume_accumulator_pda(user), false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+        ],
+    )
+}
@@ -0,0 +1,72 @@
+//! Instruction for collecting creator fees
+//!
+//! This module provides the functionality to withdraw the creator fees that accumulate
+//! in a creator's vault as their tokens are traded.
+//! It includes the instruction data structure and helper function to build the Solana instruction.
+
+use crate::{constants, PumpFun};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// Instruction data for collecting creator fees
+///
+/// The instruction takes no arguments; the whole collectable balance of the
+/// creator vault is transferred to the creator.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct CollectCreatorFee {}
+
+impl CollectCreatorFee {
+    /// Instruction discriminator used to identify this instruction
+    pub const DISCRIMINATOR: [u8; 8] = [20, 22, 86, 123, 198, 28, 219, 132];
+
+    /// Serializes the instruction data with the appropriate discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut data).unwrap();
+        data
+    }
+}
+
+/// Creates an instruction to collect the creator fees of a creator vault
+///
+/// Transfers the lamports accumulated in the creator's vault, minus the amount needed
+/// to keep the vault rent exempt, to the creator.
+///
+/// # Arguments
+///
+/// * `creator` - Public key of the token creator that receives the fees
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will collect the creator fees
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. Creator account (signer, writable)
+/// 2. Creator vault PDA (writable)
+/// 3. System program (readonly)
+/// 4. Event authority (readonly)
+/// 5. Pump.fun program ID (readonly)
+pub fn collect_creator_fee(creator: &Pubkey) -> Instruction {
+    let creator_vault: Pubkey = PumpFun::get_creator_vault_pda(creator).unwrap();
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &CollectCreatorFee {}.data(),
+        vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(creator_vault, false),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+        ],
+    )
+}
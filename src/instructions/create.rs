@@ -8,8 +8,6 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::Keypair,
-    signer::Signer,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -21,7 +19,7 @@ use spl_associated_token_account::get_associated_token_address;
 /// * `symbol` - Symbol/ticker of the token to be created
 /// * `uri` - Metadata URI containing token information (image, description, etc.)
 /// * `creator` - Public key of the token creator
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
 pub struct Create {
     pub name: String,
     pub symbol: String,
@@ -53,8 +51,8 @@ impl Create {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will pay for account creation and transaction fees
-/// * `mint` - Keypair for the new token mint account that will be created
+/// * `payer` - Public key of the account that will pay for account creation and transaction fees
+/// * `mint` - Public key of the new token mint account that will be created, which must sign the transaction
 /// * `args` - Create instruction data containing token name, symbol, metadata URI, and creator
 ///
 /// # Returns
@@ -78,23 +76,20 @@ impl Create {
 /// 12. Rent sysvar (readonly)
 /// 13. Event authority (readonly)
 /// 14. Pump.fun program ID (readonly)
-pub fn create(payer: &Keypair, mint: &Keypair, args: Create) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(&mint.pubkey()).unwrap();
+pub fn create(payer: &Pubkey, mint: &Pubkey, args: Create) -> Instruction {
+    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
     Instruction::new_with_bytes(
         constants::accounts::PUMPFUN,
         &args.data(),
         vec![
-            AccountMeta::new(mint.pubkey(), true),
+            AccountMeta::new(*mint, true),
             AccountMeta::new(PumpFun::get_mint_authority_pda(), false),
             AccountMeta::new(bonding_curve, false),
-            AccountMeta::new(
-                get_associated_token_address(&bonding_curve, &mint.pubkey()),
-                false,
-            ),
+            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
             AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
             AccountMeta::new_readonly(constants::accounts::MPL_TOKEN_METADATA, false),
-            AccountMeta::new(PumpFun::get_metadata_pda(&mint.pubkey()), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(PumpFun::get_metadata_pda(mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
@@ -0,0 +1,850 @@
+//! Decoder for Pump.fun program instructions
+//!
+//! This module turns raw Pump.fun instructions back into typed values. It matches the
+//! instruction discriminator, deserializes the Borsh arguments and maps the account list
+//! to named roles, so indexers and audit tools can explain any transaction that touches
+//! the Pump.fun program.
+//!
+//! Instructions built by older versions of the program are accepted as well: arguments
+//! that were added later (such as `track_volume` or the `creator` of a token) fall back
+//! to their defaults, and trailing accounts that were added later are reported as `None`.
+
+use crate::{constants, error::ClientError};
+use borsh::BorshDeserialize;
+use solana_sdk::{
+    instruction::{CompiledInstruction, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::{
+    Buy, ClaimTokenIncentives, CloseUserVolumeAccumulator, CollectCreatorFee, Create,
+    InitUserVolumeAccumulator, Sell, SyncUserVolumeAccumulator,
+};
+
+/// Named accounts of a `Create` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateAccounts {
+    /// Mint account of the new token
+    pub mint: Pubkey,
+    /// Mint authority PDA
+    pub mint_authority: Pubkey,
+    /// Bonding curve PDA
+    pub bonding_curve: Pubkey,
+    /// Token account owned by the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Global configuration PDA
+    pub global: Pubkey,
+    /// MPL Token Metadata program
+    pub mpl_token_metadata: Pubkey,
+    /// Metadata PDA of the new token
+    pub metadata: Pubkey,
+    /// User paying for the token creation
+    pub user: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Token program
+    pub token_program: Pubkey,
+    /// Associated token program
+    pub associated_token_program: Pubkey,
+    /// Rent sysvar
+    pub rent: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+}
+
+/// Named accounts of a `Buy` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuyAccounts {
+    /// Global configuration PDA
+    pub global: Pubkey,
+    /// Account receiving the protocol fee
+    pub fee_recipient: Pubkey,
+    /// Mint of the token being bought
+    pub mint: Pubkey,
+    /// Bonding curve PDA
+    pub bonding_curve: Pubkey,
+    /// Token account owned by the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Buyer's token account
+    pub associated_user: Pubkey,
+    /// Buyer paying the SOL
+    pub user: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Token program
+    pub token_program: Pubkey,
+    /// Vault receiving the creator fee
+    pub creator_vault: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+    /// Global volume accumulator, absent on older instructions
+    pub global_volume_accumulator: Option<Pubkey>,
+    /// Buyer's volume accumulator, absent on older instructions
+    pub user_volume_accumulator: Option<Pubkey>,
+    /// Fee configuration account, absent on older instructions
+    pub fee_config: Option<Pubkey>,
+    /// Fee configuration program, absent on older instructions
+    pub fee_program: Option<Pubkey>,
+}
+
+/// Named accounts of a `Sell` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SellAccounts {
+    /// Global configuration PDA
+    pub global: Pubkey,
+    /// Account receiving the protocol fee
+    pub fee_recipient: Pubkey,
+    /// Mint of the token being sold
+    pub mint: Pubkey,
+    /// Bonding curve PDA
+    pub bonding_curve: Pubkey,
+    /// Token account owned by the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Seller's token account
+    pub associated_user: Pubkey,
+    /// Seller receiving the SOL
+    pub user: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Vault receiving the creator fee
+    pub creator_vault: Pubkey,
+    /// Token program
+    pub token_program: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+    /// Fee configuration account, absent on older instructions
+    pub fee_config: Option<Pubkey>,
+    /// Fee configuration program, absent on older instructions
+    pub fee_program: Option<Pubkey>,
+}
+
+/// Named accounts of a `CollectCreatorFee` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectCreatorFeeAccounts {
+    /// Creator receiving the fees
+    pub creator: Pubkey,
+    /// Vault holding the accumulated creator fees
+    pub creator_vault: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+}
+
+/// Named accounts of an `InitUserVolumeAccumulator` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitUserVolumeAccumulatorAccounts {
+    /// Payer of the account creation
+    pub payer: Pubkey,
+    /// User the accumulator belongs to
+    pub user: Pubkey,
+    /// User volume accumulator PDA
+    pub user_volume_accumulator: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+}
+
+/// Named accounts of a `SyncUserVolumeAccumulator` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncUserVolumeAccumulatorAccounts {
+    /// User the accumulator belongs to
+    pub user: Pubkey,
+    /// Global volume accumulator
+    pub global_volume_accumulator: Pubkey,
+    /// User volume accumulator PDA
+    pub user_volume_accumulator: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+}
+
+/// Named accounts of a `ClaimTokenIncentives` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimTokenIncentivesAccounts {
+    /// User receiving the incentive tokens
+    pub user: Pubkey,
+    /// User's token account for the incentive mint
+    pub user_token_account: Pubkey,
+    /// Global volume accumulator
+    pub global_volume_accumulator: Pubkey,
+    /// Global volume accumulator's token account holding the incentives
+    pub global_incentive_token_account: Pubkey,
+    /// User volume accumulator PDA
+    pub user_volume_accumulator: Pubkey,
+    /// Incentive token mint
+    pub mint: Pubkey,
+    /// Token program of the incentive mint
+    pub token_program: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Associated token program
+    pub associated_token_program: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+    /// Payer of the transaction
+    pub payer: Pubkey,
+}
+
+/// Named accounts of a `CloseUserVolumeAccumulator` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseUserVolumeAccumulatorAccounts {
+    /// User the accumulator belongs to, receiving its rent
+    pub user: Pubkey,
+    /// User volume accumulator PDA
+    pub user_volume_accumulator: Pubkey,
+    /// Event authority
+    pub event_authority: Pubkey,
+    /// Pump.fun program
+    pub program: Pubkey,
+}
+
+/// A decoded Pump.fun program instruction
+///
+/// Instructions with a discriminator that is not recognized are returned as
+/// `Unknown` with their raw data, so callers can still account for them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PumpFunInstruction {
+    Create {
+        args: Create,
+        accounts: CreateAccounts,
+    },
+    Buy {
+        args: Buy,
+        accounts: BuyAccounts,
+    },
+    Sell {
+        args: Sell,
+        accounts: SellAccounts,
+    },
+    CollectCreatorFee {
+        accounts: CollectCreatorFeeAccounts,
+    },
+    InitUserVolumeAccumulator {
+        accounts: InitUserVolumeAccumulatorAccounts,
+    },
+    SyncUserVolumeAccumulator {
+        accounts: SyncUserVolumeAccumulatorAccounts,
+    },
+    ClaimTokenIncentives {
+        accounts: ClaimTokenIncentivesAccounts,
+    },
+    CloseUserVolumeAccumulator {
+        accounts: CloseUserVolumeAccumulatorAccounts,
+    },
+    Unknown(Vec<u8>),
+}
+
+impl PumpFunInstruction {
+    /// Decodes a Pump.fun instruction
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - Instruction to decode
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded instruction, or a ClientError if the instruction does not
+    /// target the Pump.fun program or its data or accounts are malformed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::instructions::{self, PumpFunInstruction};
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let instruction = instructions::sell(
+    ///     &Pubkey::new_unique(),
+    ///     &Pubkey::new_unique(),
+    ///     &Pubkey::new_unique(),
+    ///     &Pubkey::new_unique(),
+    ///     instructions::Sell { amount: 1_000, min_sol_output: 10 },
+    /// );
+    ///
+    /// match PumpFunInstruction::decode(&instruction).unwrap() {
+    ///     PumpFunInstruction::Sell { args, accounts } => {
+    ///         println!("{} sold {} tokens of {}", accounts.user, args.amount, accounts.mint);
+    ///     }
+    ///     other => println!("Other instruction: {:?}", other),
+    /// }
+    /// ```
+    pub fn decode(instruction: &Instruction) -> Result<Self, ClientError> {
+        let accounts: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect();
+        Self::decode_raw(&instruction.program_id, &accounts, &instruction.data)
+    }
+
+    /// Decodes a compiled Pump.fun instruction from a transaction message
+    ///
+    /// # Arguments
+    ///
+    /// * `account_keys` - Account keys of the message the instruction belongs to, including
+    ///   any keys loaded from address lookup tables
+    /// * `instruction` - Compiled instruction referencing `account_keys` by index
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded instruction, or a ClientError if an account index is out of range,
+    /// the instruction does not target the Pump.fun program, or its data or accounts are malformed
+    pub fn decode_compiled(
+        account_keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Result<Self, ClientError> {
+        let key = |index: u8| {
+            account_keys.get(index as usize).copied().ok_or_else(|| {
+                ClientError::InvalidInstruction(format!("Account index {} out of range", index))
+            })
+        };
+
+        let program_id = key(instruction.program_id_index)?;
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|index| key(*index))
+            .collect::<Result<Vec<Pubkey>, ClientError>>()?;
+        Self::decode_raw(&program_id, &accounts, &instruction.data)
+    }
+
+    /// Decodes a Pump.fun instruction from its raw parts
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - Program the instruction is addressed to
+    /// * `accounts` - Account keys passed to the instruction, in order
+    /// * `data` - Instruction data, starting with the 8-byte discriminator
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded instruction, or a ClientError if the instruction does not
+    /// target the Pump.fun program or its data or accounts are malformed
+    pub fn decode_raw(
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        data: &[u8],
+    ) -> Result<Self, ClientError> {
+        if *program_id != constants::accounts::PUMPFUN {
+            return Err(ClientError::InvalidInstruction(format!(
+                "Instruction targets {} instead of the Pump.fun program",
+                program_id
+            )));
+        }
+
+        if data.len() < 8 {
+            return Err(ClientError::InvalidInstruction(
+                "Data too short to contain discriminator".to_string(),
+            ));
+        }
+
+        let (discriminator, mut args) = data.split_at(8);
+        match discriminator {
+            d if d == Create::DISCRIMINATOR => Ok(Self::Create {
+                args: decode_create_args(&mut args)?,
+                accounts: CreateAccounts::from_keys(accounts)?,
+            }),
+            d if d == Buy::DISCRIMINATOR => Ok(Self::Buy {
+                args: decode_buy_args(&mut args)?,
+                accounts: BuyAccounts::from_keys(accounts)?,
+            }),
+            d if d == Sell::DISCRIMINATOR => Ok(Self::Sell {
+                args: Sell::deserialize(&mut args).map_err(ClientError::BorshError)?,
+                accounts: SellAccounts::from_keys(accounts)?,
+            }),
+            d if d == CollectCreatorFee::DISCRIMINATOR => Ok(Self::CollectCreatorFee {
+                accounts: CollectCreatorFeeAccounts::from_keys(accounts)?,
+            }),
+            d if d == InitUserVolumeAccumulator::DISCRIMINATOR => {
+                Ok(Self::InitUserVolumeAccumulator {
+                    accounts: InitUserVolumeAccumulatorAccounts::from_keys(accounts)?,
+                })
+            }
+            d if d == SyncUserVolumeAccumulator::DISCRIMINATOR => {
+                Ok(Self::SyncUserVolumeAccumulator {
+                    accounts: SyncUserVolumeAccumulatorAccounts::from_keys(accounts)?,
+                })
+            }
+            d if d == ClaimTokenIncentives::DISCRIMINATOR => Ok(Self::ClaimTokenIncentives {
+                accounts: ClaimTokenIncentivesAccounts::from_keys(accounts)?,
+            }),
+            d if d == CloseUserVolumeAccumulator::DISCRIMINATOR => {
+                Ok(Self::CloseUserVolumeAccumulator {
+                    accounts: CloseUserVolumeAccumulatorAccounts::from_keys(accounts)?,
+                })
+            }
+            _ => Ok(Self::Unknown(data.to_vec())),
+        }
+    }
+}
+
+impl CreateAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("Create", keys, 14)?;
+        Ok(Self {
+            mint: keys[0],
+            mint_authority: keys[1],
+            bonding_curve: keys[2],
+            associated_bonding_curve: keys[3],
+            global: keys[4],
+            mpl_token_metadata: keys[5],
+            metadata: keys[6],
+            user: keys[7],
+            system_program: keys[8],
+            token_program: keys[9],
+            associated_token_program: keys[10],
+            rent: keys[11],
+            event_authority: keys[12],
+            program: keys[13],
+        })
+    }
+}
+
+impl BuyAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("Buy", keys, 12)?;
+        Ok(Self {
+            global: keys[0],
+            fee_recipient: keys[1],
+            mint: keys[2],
+            bonding_curve: keys[3],
+            associated_bonding_curve: keys[4],
+            associated_user: keys[5],
+            user: keys[6],
+            system_program: keys[7],
+            token_program: keys[8],
+            creator_vault: keys[9],
+            event_authority: keys[10],
+            program: keys[11],
+            global_volume_accumulator: keys.get(12).copied(),
+            user_volume_accumulator: keys.get(13).copied(),
+            fee_config: keys.get(14).copied(),
+            fee_program: keys.get(15).copied(),
+        })
+    }
+}
+
+impl SellAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("Sell", keys, 12)?;
+        Ok(Self {
+            global: keys[0],
+            fee_recipient: keys[1],
+            mint: keys[2],
+            bonding_curve: keys[3],
+            associated_bonding_curve: keys[4],
+            associated_user: keys[5],
+            user: keys[6],
+            system_program: keys[7],
+            creator_vault: keys[8],
+            token_program: keys[9],
+            event_authority: keys[10],
+            program: keys[11],
+            fee_config: keys.get(12).copied(),
+            fee_program: keys.get(13).copied(),
+        })
+    }
+}
+
+impl CollectCreatorFeeAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("CollectCreatorFee", keys, 5)?;
+        Ok(Self {
+            creator: keys[0],
+            creator_vault: keys[1],
+            system_program: keys[2],
+            event_authority: keys[3],
+            program: keys[4],
+        })
+    }
+}
+
+impl InitUserVolumeAccumulatorAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("InitUserVolumeAccumulator", keys, 6)?;
+        Ok(Self {
+            payer: keys[0],
+            user: keys[1],
+            user_volume_accumulator: keys[2],
+            system_program: keys[3],
+            event_authority: keys[4],
+            program: keys[5],
+        })
+    }
+}
+
+impl SyncUserVolumeAccumulatorAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("SyncUserVolumeAccumulator", keys, 5)?;
+        Ok(Self {
+            user: keys[0],
+            global_volume_accumulator: keys[1],
+            user_volume_accumulator: keys[2],
+            event_authority: keys[3],
+            program: keys[4],
+        })
+    }
+}
+
+impl ClaimTokenIncentivesAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("ClaimTokenIncentives", keys, 12)?;
+        Ok(Self {
+            user: keys[0],
+            user_token_account: keys[1],
+            global_volume_accumulator: keys[2],
+            global_incentive_token_account: keys[3],
+            user_volume_accumulator: keys[4],
+            mint: keys[5],
+            token_program: keys[6],
+            system_program: keys[7],
+            associated_token_program: keys[8],
+            event_authority: keys[9],
+            program: keys[10],
+            payer: keys[11],
+        })
+    }
+}
+
+impl CloseUserVolumeAccumulatorAccounts {
+    fn from_keys(keys: &[Pubkey]) -> Result<Self, ClientError> {
+        require_accounts("CloseUserVolumeAccumulator", keys, 4)?;
+        Ok(Self {
+            user: keys[0],
+            user_volume_accumulator: keys[1],
+            event_authority: keys[2],
+            program: keys[3],
+        })
+    }
+}
+
+/// Ensures an instruction carries at least `required` accounts
+fn require_accounts(name: &str, keys: &[Pubkey], required: usize) -> Result<(), ClientError> {
+    if keys.len() < required {
+        return Err(ClientError::InvalidInstruction(format!(
+            "{} instruction requires at least {} accounts, found {}",
+            name,
+            required,
+            keys.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Decodes `Create` arguments, defaulting the `creator` missing from older instructions
+fn decode_create_args(data: &mut &[u8]) -> Result<Create, ClientError> {
+    let name = String::deserialize(data).map_err(ClientError::BorshError)?;
+    let symbol = String::deserialize(data).map_err(ClientError::BorshError)?;
+    let uri = String::deserialize(data).map_err(ClientError::BorshError)?;
+    let creator = if data.is_empty() {
+        Pubkey::default()
+    } else {
+        Pubkey::deserialize(data).map_err(ClientError::BorshError)?
+    };
+
+    Ok(Create {
+        name,
+        symbol,
+        uri,
+        creator,
+    })
+}
+
+/// Decodes `Buy` arguments, defaulting the `track_volume` missing from older instructions
+fn decode_buy_args(data: &mut &[u8]) -> Result<Buy, ClientError> {
+    let amount = u64::deserialize(data).map_err(ClientError::BorshError)?;
+    let max_sol_cost = u64::deserialize(data).map_err(ClientError::BorshError)?;
+    let track_volume = if data.is_empty() {
+        None
+    } else {
+        Option::<bool>::deserialize(data).map_err(ClientError::BorshError)?
+    };
+
+    Ok(Buy {
+        amount,
+        max_sol_cost,
+        track_volume,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{instructions, PumpFun};
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[test]
+    fn test_decode_create() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let args = Create {
+            name: "Cat On Horse".to_string(),
+            symbol: "COH".to_string(),
+            uri: "https://example.com/coh.json".to_string(),
+            creator: payer,
+        };
+        let instruction = instructions::create(&payer, &mint, args.clone());
+
+        match PumpFunInstruction::decode(&instruction).unwrap() {
+            PumpFunInstruction::Create {
+                args: decoded,
+                accounts,
+            } => {
+                assert_eq!(decoded, args);
+                assert_eq!(accounts.mint, mint);
+                assert_eq!(accounts.user, payer);
+                assert_eq!(
+                    accounts.bonding_curve,
+                    PumpFun::get_bonding_curve_pda(&mint).unwrap()
+                );
+                assert_eq!(accounts.metadata, PumpFun::get_metadata_pda(&mint));
+            }
+            other => panic!("Expected Create, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_buy() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let args = Buy {
+            amount: 1_000_000,
+            max_sol_cost: 10_000,
+            track_volume: Some(true),
+        };
+        let instruction = instructions::buy(&payer, &mint, &fee_recipient, &creator, args.clone());
+
+        match PumpFunInstruction::decode(&instruction).unwrap() {
+            PumpFunInstruction::Buy {
+                args: decoded,
+                accounts,
+            } => {
+                assert_eq!(decoded, args);
+                assert_eq!(accounts.mint, mint);
+                assert_eq!(accounts.user, payer);
+                assert_eq!(accounts.fee_recipient, fee_recipient);
+                assert_eq!(
+                    accounts.creator_vault,
+                    PumpFun::get_creator_vault_pda(&creator).unwrap()
+                );
+                assert_eq!(
+                    accounts.user_volume_accumulator,
+                    Some(PumpFun::get_user_volume_accumulator_pda(&payer))
+                );
+                assert_eq!(
+                    accounts.fee_program,
+                    Some(constants::accounts::FEE_CONFIG_PROGRAM)
+                );
+            }
+            other => panic!("Expected Buy, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_sell() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let args = Sell {
+            amount: 5_000,
+            min_sol_output: 42,
+        };
+        let instruction = instructions::sell(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            args.clone(),
+        );
+
+        match PumpFunInstruction::decode(&instruction).unwrap() {
+            PumpFunInstruction::Sell {
+                args: decoded,
+                accounts,
+            } => {
+                assert_eq!(decoded, args);
+                assert_eq!(accounts.mint, mint);
+                assert_eq!(accounts.user, payer);
+                assert_eq!(accounts.fee_config, Some(constants::accounts::FEE_CONFIG));
+            }
+            other => panic!("Expected Sell, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_collect_creator_fee() {
+        let creator = Pubkey::new_unique();
+        let instruction = instructions::collect_creator_fee(&creator);
+
+        match PumpFunInstruction::decode(&instruction).unwrap() {
+            PumpFunInstruction::CollectCreatorFee { accounts } => {
+                assert_eq!(accounts.creator, creator);
+                assert_eq!(
+                    accounts.creator_vault,
+                    PumpFun::get_creator_vault_pda(&creator).unwrap()
+                );
+            }
+            other => panic!("Expected CollectCreatorFee, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_volume_accumulator_instructions() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let user_volume_accumulator = PumpFun::get_user_volume_accumulator_pda(&user);
+
+        match PumpFunInstruction::decode(&instructions::init_user_volume_accumulator(&user, &user))
+            .unwrap()
+        {
+            PumpFunInstruction::InitUserVolumeAccumulator { accounts } => {
+                assert_eq!(accounts.payer, user);
+                assert_eq!(accounts.user_volume_accumulator, user_volume_accumulator);
+            }
+            other => panic!("Expected InitUserVolumeAccumulator, got {:?}", other),
+        }
+
+        match PumpFunInstruction::decode(&instructions::sync_user_volume_accumulator(&user))
+            .unwrap()
+        {
+            PumpFunInstruction::SyncUserVolumeAccumulator { accounts } => {
+                assert_eq!(
+                    accounts.global_volume_accumulator,
+                    constants::accounts::GLOBAL_VOLUME_ACCUMULATOR
+                );
+                assert_eq!(accounts.user_volume_accumulator, user_volume_accumulator);
+            }
+            other => panic!("Expected SyncUserVolumeAccumulator, got {:?}", other),
+        }
+
+        match PumpFunInstruction::decode(&instructions::claim_token_incentives(
+            &user,
+            &user,
+            &mint,
+            &constants::accounts::TOKEN_PROGRAM,
+        ))
+        .unwrap()
+        {
+            PumpFunInstruction::ClaimTokenIncentives { accounts } => {
+                assert_eq!(accounts.user, user);
+                assert_eq!(
+                    accounts.user_token_account,
+                    get_associated_token_address(&user, &mint)
+                );
+                assert_eq!(accounts.mint, mint);
+                assert_eq!(accounts.payer, user);
+            }
+            other => panic!("Expected ClaimTokenIncentives, got {:?}", other),
+        }
+
+        match PumpFunInstruction::decode(&instructions::close_user_volume_accumulator(&user))
+            .unwrap()
+        {
+            PumpFunInstruction::CloseUserVolumeAccumulator { accounts } => {
+                assert_eq!(accounts.user, user);
+                assert_eq!(accounts.user_volume_accumulator, user_volume_accumulator);
+            }
+            other => panic!("Expected CloseUserVolumeAccumulator, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_legacy_buy() {
+        // Older buy instructions carry neither `track_volume` nor the volume and fee accounts
+        let mut data = Buy::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&200u64.to_le_bytes());
+        let accounts: Vec<Pubkey> = (0..12).map(|_| Pubkey::new_unique()).collect();
+
+        match PumpFunInstruction::decode_raw(&constants::accounts::PUMPFUN, &accounts, &data)
+            .unwrap()
+        {
+            PumpFunInstruction::Buy { args, accounts } => {
+                assert_eq!(args.amount, 100);
+                assert_eq!(args.max_sol_cost, 200);
+                assert_eq!(args.track_volume, None);
+                assert_eq!(accounts.global_volume_accumulator, None);
+                assert_eq!(accounts.fee_program, None);
+            }
+            other => panic!("Expected Buy, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_compiled() {
+        let payer = Pubkey::new_unique();
+        let instruction = instructions::sell(
+            &payer,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            Sell {
+                amount: 1,
+                min_sol_output: 0,
+            },
+        );
+        let message = solana_sdk::message::Message::new(&[instruction], Some(&payer));
+
+        let decoded =
+            PumpFunInstruction::decode_compiled(&message.account_keys, &message.instructions[0])
+                .unwrap();
+        assert!(matches!(decoded, PumpFunInstruction::Sell { .. }));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+
+        // Wrong program
+        assert!(PumpFunInstruction::decode_raw(
+            &Pubkey::new_unique(),
+            &accounts,
+            &Sell::DISCRIMINATOR
+        )
+        .is_err());
+
+        // Missing discriminator
+        assert!(PumpFunInstruction::decode_raw(
+            &constants::accounts::PUMPFUN,
+            &accounts,
+            &[1, 2, 3]
+        )
+        .is_err());
+
+        // Too few accounts
+        let data = Sell {
+            amount: 1,
+            min_sol_output: 1,
+        }
+        .data();
+        assert!(PumpFunInstruction::decode_raw(
+            &constants::accounts::PUMPFUN,
+            &accounts[..4],
+            &data
+        )
+        .is_err());
+
+        // Unknown discriminator
+        let data = [0u8; 8];
+        assert_eq!(
+            PumpFunInstruction::decode_raw(&constants::accounts::PUMPFUN, &accounts, &data)
+                .unwrap(),
+            PumpFunInstruction::Unknown(data.to_vec())
+        );
+    }
+}
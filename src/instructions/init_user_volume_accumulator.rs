@@ -0,0 +1,70 @@
+//! Instruction for initializing a user volume accumulator
+//!
+//! This module provides the functionality to create the account that tracks a user's
+//! traded volume for token incentives.
+//! It includes the instruction data structure and helper function to build the Solana instruction.
+
+use crate::{constants, PumpFun};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// Instruction data for initializing a user volume accumulator
+///
+/// The instruction takes no arguments; the accumulator is derived from the user.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct InitUserVolumeAccumulator {}
+
+impl InitUserVolumeAccumulator {
+    /// Instruction discriminator used to identify this instruction
+    pub const DISCRIMINATOR: [u8; 8] = [94, 6, 202, 115, 255, 96, 232, 183];
+
+    /// Serializes the instruction data with the appropriate discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut data).unwrap();
+        data
+    }
+}
+
+/// Creates an instruction to initialize the volume accumulator of a user
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that pays for the account creation
+/// * `user` - Public key of the user the accumulator belongs to
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will create the user volume accumulator
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. Payer account (signer, writable)
+/// 2. User account (readonly)
+/// 3. User volume accumulator PDA (writable)
+/// 4. System program (readonly)
+/// 5. Event authority (readonly)
+/// 6. Pump.fun program ID (readonly)
+pub fn init_user_volume_accumulator(payer: &Pubkey, user: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &InitUserVolumeAccumulator {}.data(),
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new(PumpFun::get_user_volume_accumulator_pda(user), false),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+        ],
+    )
+}
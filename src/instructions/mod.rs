@@ -7,11 +7,32 @@
 //! - `Create`: Creates a new token with an associated bonding curve.
 //! - `Buy`: Buys tokens from a bonding curve by providing SOL.
 //! - `Sell`: Sells tokens back to the bonding curve in exchange for SOL.
+//! - `CollectCreatorFee`: Collects the creator fees accumulated in a creator vault.
+//! - `InitUserVolumeAccumulator`: Creates the account tracking a user's volume for token incentives.
+//! - `SyncUserVolumeAccumulator`: Credits the incentive tokens earned by past volume to a user.
+//! - `ClaimTokenIncentives`: Transfers a user's earned incentive tokens to their token account.
+//! - `CloseUserVolumeAccumulator`: Closes a user volume accumulator and reclaims its rent.
+//!
+//! # Decoding
+//!
+//! - `PumpFunInstruction`: Decodes raw Pump.fun instructions into typed arguments and named accounts.
 
 mod buy;
+mod claim_token_incentives;
+mod close_user_volume_accumulator;
+mod collect_creator_fee;
 mod create;
+mod decode;
+mod init_user_volume_accumulator;
 mod sell;
+mod sync_user_volume_accumulator;
 
 pub use buy::*;
+pub use claim_token_incentives::*;
+pub use close_user_volume_accumulator::*;
+pub use collect_creator_fee::*;
 pub use create::*;
+pub use decode::*;
+pub use init_user_volume_accumulator::*;
 pub use sell::*;
+pub use sync_user_volume_accumulator::*;
@@ -8,8 +8,6 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::Keypair,
-    signer::Signer,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -19,7 +17,7 @@ use spl_associated_token_account::get_associated_token_address;
 ///
 /// * `amount` - Amount of tokens to sell (in token smallest units)
 /// * `min_sol_output` - Minimum acceptable SOL received for the sale (slippage protection)
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
 pub struct Sell {
     pub amount: u64,
     pub min_sol_output: u64,
@@ -51,7 +49,7 @@ impl Sell {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that owns the tokens to sell
+/// * `payer` - Public key of the account that owns the tokens to sell
 /// * `mint` - Public key of the token mint to sell
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `creator` - Public key of the token's creator
@@ -79,7 +77,7 @@ impl Sell {
 /// 13. Fee configuration account (readonly)
 /// 14. Fee configuration program ID (readonly)
 pub fn sell(
-    payer: &Keypair,
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     creator: &Pubkey,
@@ -96,8 +94,8 @@ pub fn sell(
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new(creator_vault, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
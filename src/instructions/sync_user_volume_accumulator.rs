@@ -0,0 +1,70 @@
+//! Instruction for syncing a user volume accumulator
+//!
+//! This module provides the functionality to move the incentive tokens earned by a user's
+//! past trading volume into their claimable balance.
+//! It includes the instruction data structure and helper function to build the Solana instruction.
+
+use crate::{constants, PumpFun};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// Instruction data for syncing a user volume accumulator
+///
+/// The instruction takes no arguments; the accumulator is derived from the user.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct SyncUserVolumeAccumulator {}
+
+impl SyncUserVolumeAccumulator {
+    /// Instruction discriminator used to identify this instruction
+    pub const DISCRIMINATOR: [u8; 8] = [86, 31, 192, 87, 163, 87, 79, 238];
+
+    /// Serializes the instruction data with the appropriate discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&Self::DISCRIMINATOR);
+        self.serialize(&mut data).unwrap();
+        data
+    }
+}
+
+/// Creates an instruction to sync the volume accumulator of a user
+///
+/// Syncing credits the tokens earned by the volume of completed incentive days to the
+/// user's unclaimed balance. The instruction does not require the user's signature.
+///
+/// # Arguments
+///
+/// * `user` - Public key of the user the accumulator belongs to
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will sync the user volume accumulator
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. User account (readonly)
+/// 2. Global volume accumulator (readonly)
+/// 3. User volume accumulator PDA (writable)
+/// 4. Event authority (readonly)
+/// 5. Pump.fun program ID (readonly)
+pub fn sync_user_volume_accumulator(user: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &SyncUserVolumeAccumulator {}.data(),
+        vec![
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new_readonly(constants::accounts::GLOBAL_VOLUME_ACCUMULATOR, false),
+            AccountMeta::new(PumpFun::get_user_volume_accumulator_pda(user), false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+        ],
+    )
+}
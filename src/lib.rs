@@ -1,4 +1,6 @@
 #![doc = include_str!("../RUSTDOC.md")]
+// `ClientError` wraps the Solana RPC client error, which makes it large by design
+#![allow(clippy::result_large_err)]
 
 pub mod accounts;
 pub mod common;
@@ -7,20 +9,34 @@ pub mod error;
 pub mod instructions;
 pub mod utils;
 
-use common::types::{Cluster, PriorityFee};
-use solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use common::{
+    simulation::SimulationResult,
+    types::{Cluster, Decoded, PriorityFee, TransactionFormat},
+};
+use solana_address_lookup_table_interface::instruction as alt_instruction;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, nonce_utils::nonblocking as nonce_utils,
+    rpc_client::RpcClientConfig,
+};
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, hash::Hash, instruction::Instruction, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer
+    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction, hash::Hash,
+    instruction::Instruction, message::AddressLookupTableAccount, pubkey::Pubkey,
+    signature::Signature, signer::Signer,
 };
+use solana_system_interface::instruction::{advance_nonce_account, create_nonce_account};
 use spl_associated_token_account::get_associated_token_address;
 #[cfg(feature = "create-ata")]
 use spl_associated_token_account::instruction::create_associated_token_account;
 #[cfg(feature = "close-ata")]
 use spl_token::instruction::close_account;
 use std::sync::Arc;
-use utils::transaction::get_transaction;
 
-use crate::{accounts::GlobalAccount, utils::transaction::get_transaction_offline_prepared};
+use crate::{
+    accounts::GlobalAccount,
+    utils::transaction::{
+        add_signatures, decode_transaction, get_transaction_offline_prepared, PumpFunTransaction,
+    },
+};
 
 /// Main client for interacting with the Pump.fun program
 ///
@@ -43,12 +59,17 @@ use crate::{accounts::GlobalAccount, utils::transaction::get_transaction_offline
 /// let client = PumpFun::new(payer, cluster);
 /// ```
 pub struct PumpFun {
-    /// Keypair used to sign transactions
-    pub payer: Arc<Keypair>,
+    /// Signer paying for and signing transactions
+    pub payer: Arc<dyn Signer + Send + Sync>,
     /// RPC client for Solana network requests
     pub rpc: Arc<RpcClient>,
     /// Cluster configuration
     pub cluster: Cluster,
+    /// Format of the transactions built by the client, unless another one is set on a
+    /// transaction builder
+    pub transaction_format: TransactionFormat,
+    /// Address lookup tables used to compile the v0 transactions of the client
+    pub lookup_tables: Vec<AddressLookupTableAccount>,
 }
 
 impl PumpFun {
@@ -60,7 +81,8 @@ impl PumpFun {
     ///
     /// # Arguments
     ///
-    /// * `payer` - Keypair used to sign and pay for transactions
+    /// * `payer` - Signer used to sign and pay for transactions, such as a keypair, a
+    ///   presigner or a remote signer
     /// * `cluster` - Solana cluster configuration including RPC endpoints and transaction parameters
     ///
     /// # Returns
@@ -80,18 +102,85 @@ impl PumpFun {
     /// let cluster = Cluster::devnet(commitment, priority_fee);
     /// let client = PumpFun::new(payer, cluster);
     /// ```
-    pub fn new(payer: Arc<Keypair>, cluster: Cluster) -> Self {
-        // Create Solana RPC Client with HTTP endpoint
-        let rpc = Arc::new(RpcClient::new_with_commitment(
-            cluster.rpc.http.clone(),
-            cluster.commitment,
-        ));
+    pub fn new(payer: Arc<dyn Signer + Send + Sync>, cluster: Cluster) -> Self {
+        // Create Solana RPC Client with HTTP endpoint, failing over to the fallback
+        // endpoints if any
+        let rpc = if cluster.fallback_rpcs.is_empty() {
+            Arc::new(RpcClient::new_with_commitment(
+                cluster.rpc.http.clone(),
+                cluster.commitment,
+            ))
+        } else {
+            let urls = cluster
+                .get_endpoints()
+                .into_iter()
+                .map(|endpoint| endpoint.http.clone());
+            Arc::new(RpcClient::new_sender(
+                utils::failover::FailoverSender::new(urls),
+                RpcClientConfig::with_commitment(cluster.commitment),
+            ))
+        };
 
         // Return configured PumpFun client
         Self {
             payer,
             rpc,
             cluster,
+            transaction_format: TransactionFormat::default(),
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    /// Starts a transaction chaining several operations
+    ///
+    /// Returns a `TxBuilder` that adds token operations, instructions, memos, signers and
+    /// lookup tables to a single transaction, and then builds, signs, simulates or sends it.
+    /// The transaction uses the priority fee of the cluster configuration unless another
+    /// one is set on the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let sold = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let bought = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Rotate from one token into another in a single transaction
+    /// let signature = client
+    ///     .tx()
+    ///     .sell(sold, None, Some(300))
+    ///     .await?
+    ///     .buy(bought, sol_to_lamports(0.5), None, Some(300))
+    ///     .await?
+    ///     .memo("rotate")
+    ///     .send()
+    ///     .await?;
+    /// println!("Rotated! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tx(&self) -> utils::builder::TxBuilder<'_> {
+        utils::builder::TxBuilder::new(self)
+    }
+
+    /// Starts a transaction to export, using a durable nonce when one is given
+    fn export_tx(
+        &self,
+        nonce: Option<Pubkey>,
+        priority_fee: Option<PriorityFee>,
+    ) -> utils::builder::TxBuilder<'_> {
+        let builder = self.tx().priority_fee(priority_fee);
+        match nonce {
+            Some(nonce) => builder.durable_nonce(nonce),
+            None => builder,
         }
     }
 
@@ -105,7 +194,7 @@ impl PumpFun {
     ///
     /// # Arguments
     ///
-    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
     /// * `metadata` - Token metadata including name, symbol, description and image file
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
@@ -151,42 +240,116 @@ impl PumpFun {
     /// ```
     pub async fn create(
         &self,
-        mint: Keypair,
+        mint: impl Signer + Send + Sync + 'static,
         metadata: utils::CreateTokenMetadata,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // First upload metadata and image to IPFS
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
+        // Add create token instruction, uploading metadata and image to IPFS first,
+        // then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .send()
             .await
-            .map_err(error::ClientError::UploadMetadataError)?;
-
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add create token instruction
-        let create_ix = self.get_create_instruction(&mint, ipfs);
-        instructions.push(create_ix);
+    }
 
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            Some(&[&mint]),
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )
-        .await?;
+    /// Creates a new token and reports the settlement of the creation
+    ///
+    /// Sends the same transaction as `create`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the created token and the fees actually
+    /// paid.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report of the creation if successful, or a ClientError if the
+    /// operation fails
+    #[cfg(feature = "stream")]
+    pub async fn create_and_settle(
+        &self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: utils::CreateTokenMetadata,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .send_and_settle()
+            .await
+    }
 
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
+    /// Simulates the creation of a new token
+    ///
+    /// Builds the same transaction as `create` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent. The metadata
+    /// is still uploaded to IPFS.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
+    pub async fn simulate_create(
+        &self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: utils::CreateTokenMetadata,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .simulate()
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
+    }
 
-        Ok(signature)
+    /// Exports the creation of a new token for external signing
+    ///
+    /// Builds the same transaction as `create` without the payer's signature, see
+    /// `TxBuilder::export`. The mint signs the transaction partially, and the metadata is
+    /// uploaded to IPFS.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `nonce` - Optional durable nonce account, with the payer as authority, used instead of
+    ///   a recent blockhash. Without one, the transaction expires about a minute after the
+    ///   export, once its blockhash is no longer valid
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the base64 encoded transaction if successful, or a ClientError if the operation fails
+    pub async fn export_create(
+        &self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: utils::CreateTokenMetadata,
+        nonce: Option<Pubkey>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<String, error::ClientError> {
+        self.export_tx(nonce, priority_fee)
+            .create(mint, metadata)
+            .await?
+            .export()
+            .await
     }
 
     /// Creates a new token and immediately buys an initial amount in a single atomic transaction
@@ -201,7 +364,7 @@ impl PumpFun {
     ///
     /// # Arguments
     ///
-    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
     /// * `metadata` - Token metadata including name, symbol, description and image file
     /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
@@ -256,56 +419,112 @@ impl PumpFun {
     /// ```
     pub async fn create_and_buy(
         &self,
-        mint: Keypair,
+        mint: impl Signer + Send + Sync + 'static,
         metadata: utils::CreateTokenMetadata,
         amount_sol: u64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // Upload metadata to IPFS first
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
+        let mint_pubkey = mint.pubkey();
+
+        // Add create token instruction, uploading metadata to IPFS first, and buy
+        // instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .buy(mint_pubkey, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .send()
             .await
-            .map_err(error::ClientError::UploadMetadataError)?;
-
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add create token instruction
-        let create_ix = self.get_create_instruction(&mint, ipfs);
-        instructions.push(create_ix);
-
-        // Add buy instruction
-        let buy_ix = self
-            .get_buy_instructions(
-                mint.pubkey(),
-                amount_sol,
-                track_volume,
-                slippage_basis_points,
-            )
-            .await?;
-        instructions.extend(buy_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            Some(&[&mint]),
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )
-        .await?;
+    }
 
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
+    /// Creates a new token with an initial buy and reports the settlement of both
+    ///
+    /// Sends the same transaction as `create_and_buy`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the created token, the tokens actually
+    /// received and the fees actually paid.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report of the creation and buy if successful, or a ClientError
+    /// if the operation fails
+    #[cfg(feature = "stream")]
+    pub async fn create_and_buy_and_settle(
+        &self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        let mint_pubkey = mint.pubkey();
+
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .buy(mint_pubkey, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .send_and_settle()
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
+    }
 
-        Ok(signature)
+    /// Simulates the creation of a new token with an initial buy
+    ///
+    /// Builds the same transaction as `create_and_buy` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent. The metadata
+    /// is still uploaded to IPFS.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
+    pub async fn simulate_create_and_buy(
+        &self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        let mint_pubkey = mint.pubkey();
+
+        self.tx()
+            .priority_fee(priority_fee)
+            .create(mint, metadata)
+            .await?
+            .buy(mint_pubkey, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .simulate()
+            .await
     }
 
     /// Buys tokens from a bonding curve by spending SOL
@@ -374,127 +593,28 @@ impl PumpFun {
         slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add buy instruction
-        let buy_ix = self
-            .get_buy_instructions(mint, amount_sol, track_volume, slippage_basis_points)
-            .await?;
-        instructions.extend(buy_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )
-        .await?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
-    }
-    // ///  pub async fn get_buy_instructions_offline_prepared(
-    //     &self,
-    //     mint: Pubkey,
-    //     creator: Pubkey,
-    //     amount_sol: u64,
-    //     buy_amount: u64,
-    //     track_volume: Option<bool>,
-    //     slippage_basis_points: Option<u64>,
-    //     global_account: &GlobalAccount,
-    // ) -> Result<Vec<Instruction>, error::ClientError> {
-
-    pub fn buy_instructions_offline_prepared(
-        &self,
-        mint: &Pubkey,
-        creator: &Pubkey,
-        amount_sol: u64,
-        buy_amount: u64,
-        track_volume: Option<bool>,
-        slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
-        global_account: &GlobalAccount,
-    ) -> Vec<Instruction> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add buy instruction offline_prepared
-        let buy_ix = self.get_buy_instructions_offline_prepared(mint, creator, amount_sol, buy_amount, track_volume, slippage_basis_points, global_account);
-        instructions.extend(buy_ix);
-        instructions
-    }
-
-
-    pub async fn buy_offline_prepared(
-        &self,
-        mint: &Pubkey,
-        creator: &Pubkey,
-        amount_sol: u64,
-        buy_amount: u64,
-        track_volume: Option<bool>,
-        slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
-        global_account: &GlobalAccount,
-        recent_blockhash: &Hash,
-    ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add buy instruction offline_prepared
-        let buy_ix = self.get_buy_instructions_offline_prepared(mint, creator, amount_sol, buy_amount, track_volume, slippage_basis_points, global_account);
-        instructions.extend(buy_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction_offline_prepared(
-            recent_blockhash,
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
+        // Add buy instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy(mint, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .send()
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
     }
 
-    /// Sells tokens back to the bonding curve in exchange for SOL
-    ///
-    /// This method sells tokens back to the bonding curve, receiving SOL in return. The amount of SOL
-    /// received is determined by the bonding curve formula for the specific token. As more tokens
-    /// are sold, the price decreases according to the curve function.
+    /// Buys tokens for an amount of SOL and reports the settlement of the trade
     ///
-    /// The method:
-    /// 1. Determines how many tokens to sell (all tokens or a specific amount)
-    /// 2. Calculates how much SOL will be received for the tokens
-    /// 3. Executes the sell transaction with slippage protection
+    /// Sends the same transaction as `buy`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the tokens actually received and the fees
+    /// actually paid.
     ///
-    /// A portion of the SOL is taken as a fee according to the global configuration.
+    /// Requires the `stream` feature, which provides the event types of the report.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint to sell
-    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
@@ -502,22 +622,14 @@ impl PumpFun {
     ///
     /// # Returns
     ///
-    /// Returns the transaction signature if successful, or a ClientError if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The token account cannot be found
-    /// - The bonding curve account cannot be found
-    /// - The sell price calculation fails
-    /// - Transaction creation fails
-    /// - Transaction execution on Solana fails
+    /// Returns the settlement report of the buy if successful, or a ClientError if the
+    /// operation fails
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -527,299 +639,194 @@ impl PumpFun {
     /// # let client = PumpFun::new(payer, cluster);
     /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
     ///
-    /// // Sell 1000 tokens with 2% max slippage
-    /// let amount_tokens = Some(1000);
-    /// let slippage_bps = Some(200); // 2%
-    ///
-    /// let signature = client.sell(token_mint, amount_tokens, slippage_bps, None).await?;
-    /// println!("Tokens sold! Signature: {}", signature);
-    ///
-    /// // Or sell all tokens with default slippage (5%)
-    /// let signature = client.sell(token_mint, None, None, None).await?;
-    /// println!("All tokens sold! Signature: {}", signature);
+    /// let settlement = client
+    ///     .buy_and_settle(token_mint, sol_to_lamports(0.01), None, Some(300), None)
+    ///     .await?;
+    /// if let Some(trade) = settlement.trade() {
+    ///     println!("Received {} tokens", trade.token_amount);
+    /// }
+    /// println!("Transaction fee: {} lamports", settlement.transaction_fee);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn sell(
+    #[cfg(feature = "stream")]
+    pub async fn buy_and_settle(
         &self,
         mint: Pubkey,
-        amount_token: Option<u64>,
+        amount_sol: u64,
+        track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
-    ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add sell instruction
-        let sell_ix = self
-            .get_sell_instructions(mint, amount_token, slippage_basis_points)
-            .await?;
-        instructions.extend(sell_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )
-        .await?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
-    }
-
-    pub fn compile_instructions(&self, instructions: &Vec<Instruction>, recent_blockhash:&Hash) -> Result<impl SerializableTransaction, error::ClientError> 
-    {
-        let transaction = get_transaction_offline_prepared(
-            recent_blockhash,
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
-        );
-        transaction
-    }
-
-    pub fn sell_instructions_offline_prepared(
-        &self,
-        mint: &Pubkey,
-        creator: &Pubkey,
-        amount_sol: u64,
-        amount_token: Option<u64>,
-        slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
-        global_account: &GlobalAccount,
-        close_ata: bool,
-    ) -> Vec<Instruction>  {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add sell instruction
-        let sell_ix = self
-            .get_sell_instructions_offline_prepared(mint, creator, amount_sol, amount_token, slippage_basis_points, global_account, close_ata);
-        instructions.extend(sell_ix);
-        instructions
-    }
-
-    pub async fn sell_offline_prepared(
-        &self,
-        mint: &Pubkey,
-        creator: &Pubkey,
-        amount_sol: u64,
-        amount_token: Option<u64>,
-        slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
-        global_account: &GlobalAccount,
-        close_ata: bool,
-        recent_blockhash: &Hash
-    ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add sell instruction
-        let sell_ix = self
-            .get_sell_instructions_offline_prepared(mint, creator, amount_sol, amount_token, slippage_basis_points, global_account, close_ata);
-        instructions.extend(sell_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction_offline_prepared(
-            recent_blockhash,
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
-        )?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy(mint, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .send_and_settle()
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
     }
 
-    /// Subscribes to real-time events from the Pump.fun program
-    ///
-    /// This method establishes a WebSocket connection to the Solana cluster and subscribes
-    /// to program log events from the Pump.fun program. It parses the emitted events into
-    /// structured data types and delivers them through the provided callback function.
+    /// Simulates a buy of tokens for an amount of SOL
     ///
-    /// Event types include:
-    /// - `CreateEvent`: Emitted when a new token is created
-    /// - `TradeEvent`: Emitted when tokens are bought or sold
-    /// - `CompleteEvent`: Emitted when a bonding curve operation completes
-    /// - `SetParamsEvent`: Emitted when global parameters are updated
+    /// Builds the same transaction as `buy` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent.
     ///
     /// # Arguments
     ///
-    /// * `mentioned` - Optional public key to filter events by mentions. If None, subscribes to all Pump.fun events
-    /// * `commitment` - Optional commitment level for the subscription. If None, uses the
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
-    /// * `callback` - A function that will be called for each event with the following parameters:
-    ///   * `signature`: The transaction signature as a String
-    ///   * `event`: The parsed PumpFunEvent if successful, or None if parsing failed
-    ///   * `error`: Any error that occurred during parsing, or None if successful
-    ///   * `response`: The complete RPC logs response for additional context
     ///
     /// # Returns
     ///
-    /// Returns a `Subscription` object that manages the lifecycle of the subscription.
-    /// When this object is dropped, the subscription is automatically terminated. If
-    /// the subscription cannot be established, returns a ClientError.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The WebSocket connection cannot be established
-    /// - The subscription request fails
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
-    /// # use std::{sync::Arc, error::Error};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn Error>> {
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// // Subscribe to token events
-    /// let subscription = client.subscribe(None, None, |signature, event, error, _| {
-    ///     match event {
-    ///         Some(pumpfun::common::stream::PumpFunEvent::Create(create_event)) => {
-    ///             println!("New token created: {} ({})", create_event.name, create_event.symbol);
-    ///             println!("Mint address: {}", create_event.mint);
-    ///         },
-    ///         Some(pumpfun::common::stream::PumpFunEvent::Trade(trade_event)) => {
-    ///             let action = if trade_event.is_buy { "bought" } else { "sold" };
-    ///             println!(
-    ///                 "User {} {} {} tokens for {} SOL",
-    ///                 trade_event.user,
-    ///                 action,
-    ///                 trade_event.token_amount,
-    ///                 trade_event.sol_amount as f64 / 1_000_000_000.0
-    ///             );
-    ///         },
-    ///         Some(event) => println!("Other event received: {:#?}", event),
-    ///         None => {
-    ///             if let Some(err) = error {
-    ///                 eprintln!("Error parsing event in tx {}: {}", signature, err);
-    ///             }
-    ///         }
-    ///     }
-    /// }).await?;
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
     ///
-    /// // Keep the subscription active
-    /// // When no longer needed, drop the subscription to unsubscribe
+    /// let simulation = client
+    ///     .simulate_buy(token_mint, sol_to_lamports(0.01), None, Some(300), None)
+    ///     .await?;
+    /// match simulation.program_error {
+    ///     Some(err) => println!("Buy would fail: {}", err),
+    ///     None => println!("Buy would consume {:?} compute units", simulation.units_consumed),
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg(feature = "stream")]
-    pub async fn subscribe<F>(
+    pub async fn simulate_buy(
         &self,
-        mentioned: Option<String>,
-        commitment: Option<solana_sdk::commitment_config::CommitmentConfig>,
-        callback: F,
-    ) -> Result<common::stream::Subscription, error::ClientError>
-    where
-        F: Fn(
-                String,
-                Option<common::stream::PumpFunEvent>,
-                Option<Box<dyn std::error::Error + Send + Sync>>,
-                solana_client::rpc_response::Response<solana_client::rpc_response::RpcLogsResponse>,
-            ) + Send
-            + Sync
-            + 'static,
-    {
-        common::stream::subscribe(self.cluster.clone(), mentioned, commitment, callback).await
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy(mint, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .simulate()
+            .await
     }
 
-    /// Creates compute budget instructions for priority fees
+    /// Exports a buy of tokens for an amount of SOL for external signing
     ///
-    /// Generates Solana compute budget instructions based on the provided priority fee
-    /// configuration. These instructions are used to set the maximum compute units a
-    /// transaction can consume and the price per compute unit, which helps prioritize
-    /// transaction processing during network congestion.
+    /// Builds the same transaction as `buy` without the payer's signature, see
+    /// `TxBuilder::export`, so that it can be approved by an external wallet or multisig.
     ///
     /// # Arguments
     ///
-    /// * `priority_fee` - Priority fee configuration containing optional unit limit and unit price
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `nonce` - Optional durable nonce account, with the payer as authority, used instead of
+    ///   a recent blockhash. Without one, the transaction expires about a minute after the
+    ///   export, once its blockhash is no longer valid
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns a vector of instructions to set compute budget parameters, which can be
-    /// empty if no priority fee parameters are provided
+    /// Returns the base64 encoded transaction if successful, or a ClientError if the operation fails
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::PriorityFee};
-    /// # use solana_sdk::instruction::Instruction;
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Signature, signer::null_signer::NullSigner};
+    /// # use std::{str::FromStr, sync::Arc};
     /// #
-    /// // Set both compute unit limit and price
-    /// let priority_fee = PriorityFee {
-    ///     unit_limit: Some(200_000),
-    ///     unit_price: Some(1_000), // 1000 micro-lamports per compute unit
-    /// };
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// // The treasury only knows its address, and signs through a multisig
+    /// let treasury = pubkey!("TreasuryPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let client = PumpFun::new(Arc::new(NullSigner::new(&treasury)), cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
     ///
-    /// let compute_instructions: Vec<Instruction> = PumpFun::get_priority_fee_instructions(&priority_fee);
+    /// // Approvals take longer than a blockhash is valid, so use a nonce of the treasury
+    /// let nonce = pubkey!("TreasuryNonceAccountXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let transaction = client
+    ///     .export_buy(token_mint, sol_to_lamports(0.01), None, Some(300), Some(nonce), None)
+    ///     .await?;
+    /// println!("Transaction to approve: {}", transaction);
+    ///
+    /// // Once approved, attach the signature of the treasury and send the transaction
+    /// let signature = Signature::from_str("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW")?;
+    /// let signature = client
+    ///     .send_signed_transaction(&transaction, &[(treasury, signature)])
+    ///     .await?;
+    /// println!("Buy sent! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_priority_fee_instructions(priority_fee: &PriorityFee) -> Vec<Instruction> {
-        let mut instructions = Vec::new();
-
-        if let Some(limit) = priority_fee.unit_limit {
-            let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-            instructions.push(limit_ix);
-        }
-
-        if let Some(price) = priority_fee.unit_price {
-            let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-            instructions.push(price_ix);
-        }
-
-        instructions
+    pub async fn export_buy(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        nonce: Option<Pubkey>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<String, error::ClientError> {
+        self.export_tx(nonce, priority_fee)
+            .buy(mint, amount_sol, track_volume, slippage_basis_points)
+            .await?
+            .export()
+            .await
     }
 
-    /// Creates an instruction for initializing a new token
+    /// Buys an exact amount of tokens from a bonding curve
     ///
-    /// Generates a Solana instruction to create a new token with a bonding curve on Pump.fun.
-    /// This instruction will initialize the token mint, metadata, and bonding curve accounts.
+    /// Quotes the SOL needed to buy `amount_token` tokens, including the protocol and creator
+    /// fees, and buys them with the quote plus slippage as the maximum SOL cost.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Keypair for the new token mint account that will be created
-    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units, capped at the curve's real token reserves
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns a Solana instruction for creating a new token
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The bonding curve account cannot be found
+    /// - The buy quote calculation fails
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -827,148 +834,120 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = Keypair::new();
-    /// let metadata_response = utils::create_token_metadata(
-    ///     utils::CreateTokenMetadata {
-    ///         name: "Example Token".to_string(),
-    ///         symbol: "EXTKN".to_string(),
-    ///         description: "An example token".to_string(),
-    ///         file: "path/to/image.png".to_string(),
-    ///         twitter: None,
-    ///         telegram: None,
-    ///         website: None,
-    ///     }
-    /// ).await?;
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
     ///
-    /// let create_instruction = client.get_create_instruction(&mint, metadata_response);
+    /// // Buy exactly 10M tokens (6 decimals) with 3% max slippage
+    /// let amount_token = 10_000_000_000_000;
+    /// let signature = client
+    ///     .buy_exact_tokens(mint, amount_token, None, Some(300), None)
+    ///     .await?;
+    /// println!("Tokens bought! Signature: {}", signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_create_instruction(
+    pub async fn buy_exact_tokens(
         &self,
-        mint: &Keypair,
-        ipfs: utils::TokenMetadataResponse,
-    ) -> Instruction {
-        instructions::create(
-            &self.payer,
-            mint,
-            instructions::Create {
-                name: ipfs.metadata.name,
-                symbol: ipfs.metadata.symbol,
-                uri: ipfs.metadata.image,
-                creator: self.payer.pubkey(),
-            },
-        )
+        mint: Pubkey,
+        amount_token: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add buy instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy_exact_tokens(mint, amount_token, track_volume, slippage_basis_points)
+            .await?
+            .send()
+            .await
     }
 
-    /// Generates instructions for buying tokens from a bonding curve
+    /// Buys an exact amount of tokens and reports the settlement of the trade
     ///
-    /// Creates a set of Solana instructions needed to purchase tokens using SOL. These
-    /// instructions may include creating an associated token account if needed, and the actual
-    /// buy instruction with slippage protection.
+    /// Sends the same transaction as `buy_exact_tokens`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the SOL actually spent and the fees actually
+    /// paid.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
     ///
     /// # Arguments
     ///
     /// * `mint` - Public key of the token mint to buy
-    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `amount_token` - Amount of tokens to buy in base units, capped at the curve's real token reserves
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    /// Returns the settlement report of the buy if successful, or a ClientError if the
+    /// operation fails
+    #[cfg(feature = "stream")]
+    pub async fn buy_exact_tokens_and_settle(
+        &self,
+        mint: Pubkey,
+        amount_token: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy_exact_tokens(mint, amount_token, track_volume, slippage_basis_points)
+            .await?
+            .send_and_settle()
+            .await
+    }
+
+    /// Simulates a buy of an exact amount of tokens
     ///
-    /// # Errors
+    /// Builds the same transaction as `buy_exact_tokens` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent.
     ///
-    /// Returns an error if:
-    /// - The global account or bonding curve account cannot be fetched
-    /// - The buy price calculation fails
-    /// - Token account-related operations fail
+    /// # Arguments
     ///
-    /// # Examples
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units, capped at the curve's real token reserves
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
-    /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
-    /// # use std::sync::Arc;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let payer = Arc::new(Keypair::new());
-    /// # let commitment = CommitmentConfig::confirmed();
-    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
-    /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let amount_sol = sol_to_lamports(0.01); // 0.01 SOL
-    /// let slippage_bps = Some(300); // 3%
-    /// let track_volume = Some(true); // Track this buy in volume stats
+    /// # Returns
     ///
-    /// let buy_instructions = client.get_buy_instructions(mint, amount_sol, track_volume, slippage_bps).await?;
-    /// # Ok(())
-    /// # }
-    /// ```
-
-    pub async fn get_buy_instructions(
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
+    pub async fn simulate_buy_exact_tokens(
         &self,
         mint: Pubkey,
-        amount_sol: u64,
+        amount_token: u64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
-    ) -> Result<Vec<Instruction>, error::ClientError> {
-        // Get accounts and calculate buy amounts
-        let global_account = self.get_global_account().await?;
-        let mut bonding_curve_account: Option<accounts::BondingCurveAccount> = None;
-        let buy_amount = {
-            let bonding_curve_pda = Self::get_bonding_curve_pda(&mint)
-                .ok_or(error::ClientError::BondingCurveNotFound)?;
-            if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
-                global_account.get_initial_buy_price(amount_sol)
-            } else {
-                bonding_curve_account = self.get_bonding_curve_account(&mint).await.ok();
-                bonding_curve_account
-                    .as_ref()
-                    .unwrap()
-                    .get_buy_price(amount_sol)
-                    .map_err(error::ClientError::BondingCurveError)?
-            }
-        };
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
-
-        let mut instructions = Vec::new();
-
-        // Create Associated Token Account if needed
-        #[cfg(feature = "create-ata")]
-        {
-            let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
-            if self.rpc.get_account(&ata).await.is_err() {
-                instructions.push(create_associated_token_account(
-                    &self.payer.pubkey(),
-                    &self.payer.pubkey(),
-                    &mint,
-                    &constants::accounts::TOKEN_PROGRAM,
-                ));
-            }
-        }
-
-        // Add buy instruction
-        instructions.push(instructions::buy(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
-            instructions::Buy {
-                amount: buy_amount,
-                max_sol_cost: buy_amount_with_slippage,
-                track_volume,
-            },
-        ));
-
-        Ok(instructions)
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .buy_exact_tokens(mint, amount_token, track_volume, slippage_basis_points)
+            .await?
+            .simulate()
+            .await
     }
+    // ///  pub async fn get_buy_instructions_offline_prepared(
+    //     &self,
+    //     mint: Pubkey,
+    //     creator: Pubkey,
+    //     amount_sol: u64,
+    //     buy_amount: u64,
+    //     track_volume: Option<bool>,
+    //     slippage_basis_points: Option<u64>,
+    //     global_account: &GlobalAccount,
+    // ) -> Result<Vec<Instruction>, error::ClientError> {
 
-    pub fn get_buy_instructions_offline_prepared(
+    #[allow(clippy::too_many_arguments)]
+    pub fn buy_instructions_offline_prepared(
         &self,
         mint: &Pubkey,
         creator: &Pubkey,
@@ -976,45 +955,65 @@ impl PumpFun {
         buy_amount: u64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
         global_account: &GlobalAccount,
     ) -> Vec<Instruction> {
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
-        let mut instructions = Vec::new();
-
-        // Create Associated Token Account if needed
-        #[cfg(feature = "create-ata")]
-        {
-            instructions.push(create_associated_token_account(
-                &self.payer.pubkey(),
-                &self.payer.pubkey(),
-                &mint,
-                &constants::accounts::TOKEN_PROGRAM,
-            ));
-        }
-
         // Add buy instruction
-        instructions.push(instructions::buy(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &creator,
-            instructions::Buy {
-                amount: buy_amount,
-                max_sol_cost: buy_amount_with_slippage,
+        self.tx()
+            .priority_fee(priority_fee)
+            .instructions(self.get_buy_instructions_offline_prepared(
+                mint,
+                creator,
+                amount_sol,
+                buy_amount,
                 track_volume,
-            },
-        ));
+                slippage_basis_points,
+                global_account,
+            ))
+            .build()
+    }
 
-        instructions
+    #[allow(clippy::too_many_arguments)]
+    pub async fn buy_offline_prepared(
+        &self,
+        mint: &Pubkey,
+        creator: &Pubkey,
+        amount_sol: u64,
+        buy_amount: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        global_account: &GlobalAccount,
+        recent_blockhash: &Hash,
+    ) -> Result<Signature, error::ClientError> {
+        // Add buy instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instructions(self.get_buy_instructions_offline_prepared(
+                mint,
+                creator,
+                amount_sol,
+                buy_amount,
+                track_volume,
+                slippage_basis_points,
+                global_account,
+            ))
+            .send_with_blockhash(recent_blockhash)
+            .await
     }
 
-    /// Generates instructions for selling tokens back to a bonding curve
+    /// Sells tokens back to the bonding curve in exchange for SOL
     ///
-    /// Creates a set of Solana instructions needed to sell tokens in exchange for SOL. These
-    /// instructions include the sell instruction with slippage protection and may include
-    /// closing the associated token account if all tokens are being sold and the feature
-    /// is enabled.
+    /// This method sells tokens back to the bonding curve, receiving SOL in return. The amount of SOL
+    /// received is determined by the bonding curve formula for the specific token. As more tokens
+    /// are sold, the price decreases according to the curve function.
+    ///
+    /// The method:
+    /// 1. Determines how many tokens to sell (all tokens or a specific amount)
+    /// 2. Calculates how much SOL will be received for the tokens
+    /// 3. Executes the sell transaction with slippage protection
+    ///
+    /// A portion of the SOL is taken as a fee according to the global configuration.
     ///
     /// # Arguments
     ///
@@ -1022,18 +1021,21 @@ impl PumpFun {
     /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The token account or token balance cannot be fetched
-    /// - The global account or bonding curve account cannot be fetched
+    /// - The token account cannot be found
+    /// - The bonding curve account cannot be found
     /// - The sell price calculation fails
-    /// - Token account closing operations fail (when applicable)
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
     ///
     /// # Examples
     ///
@@ -1047,233 +1049,2167 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let amount_tokens = Some(1000); // Sell 1000 tokens
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Sell 1000 tokens with 2% max slippage
+    /// let amount_tokens = Some(1000);
     /// let slippage_bps = Some(200); // 2%
     ///
-    /// let sell_instructions = client.get_sell_instructions(mint, amount_tokens, slippage_bps).await?;
+    /// let signature = client.sell(token_mint, amount_tokens, slippage_bps, None).await?;
+    /// println!("Tokens sold! Signature: {}", signature);
     ///
-    /// // Or to sell all tokens:
-    /// let sell_all_instructions = client.get_sell_instructions(mint, None, None).await?;
+    /// // Or sell all tokens with default slippage (5%)
+    /// let signature = client.sell(token_mint, None, None, None).await?;
+    /// println!("All tokens sold! Signature: {}", signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_sell_instructions(
+    pub async fn sell(
         &self,
         mint: Pubkey,
         amount_token: Option<u64>,
         slippage_basis_points: Option<u64>,
-    ) -> Result<Vec<Instruction>, error::ClientError> {
-        // Get ATA
-        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
-
-        // Get token balance
-        let token_balance = if amount_token.is_none() || cfg!(feature = "close-ata") {
-            // We need the balance if amount_token is None OR if the close-ata feature is enabled
-            let balance = self.rpc.get_token_account_balance(&ata).await?;
-            Some(balance.amount.parse::<u64>().unwrap())
-        } else {
-            None
-        };
-
-        // Determine amount to sell
-        let amount = amount_token.unwrap_or_else(|| token_balance.unwrap());
-
-        // Calculate min sol output
-        let global_account = self.get_global_account().await?;
-        let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
-        let min_sol_output = bonding_curve_account
-            .get_sell_price(amount, global_account.fee_basis_points)
-            .map_err(error::ClientError::BondingCurveError)?;
-        let min_sol_output = utils::calculate_with_slippage_sell(
-            min_sol_output,
-            slippage_basis_points.unwrap_or(500),
-        );
-
-        let mut instructions = Vec::new();
-
-        // Add sell instruction
-        instructions.push(instructions::sell(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &bonding_curve_account.creator,
-            instructions::Sell {
-                amount,
-                min_sol_output,
-            },
-        ));
-
-        // Close account if balance equals amount
-        #[cfg(feature = "close-ata")]
-        {
-            // Token balance should be guaranteed to be available at this point
-            // due to our fetch logic in the beginning of the function
-            if let Some(balance) = token_balance {
-                // Only close the account if we're selling all tokens
-                if balance == amount {
-                    let token_program = constants::accounts::TOKEN_PROGRAM;
-
-                    // Verify the token account exists before attempting to close it
-                    if self.rpc.get_account(&ata).await.is_ok() {
-                        // Create instruction to close the ATA
-                        let close_instruction = close_account(
-                            &token_program,
-                            &ata,
-                            &self.payer.pubkey(),
-                            &self.payer.pubkey(),
-                            &[&self.payer.pubkey()],
-                        )
-                        .map_err(|err| {
-                            error::ClientError::OtherError(format!(
-                                "Failed to create close account instruction: pubkey={}: {}",
-                                ata, err
-                            ))
-                        })?;
-
-                        instructions.push(close_instruction);
-                    } else {
-                        // Log warning but don't fail the transaction if account doesn't exist
-                        eprintln!(
-                            "Warning: Cannot close token account {}, it doesn't exist",
-                            ata
-                        );
-                    }
-                }
-            } else {
-                // This case should not occur due to our balance fetch logic,
-                // but handle it gracefully just in case
-                eprintln!("Warning: Token balance unavailable, not closing account");
-            }
-        }
-
-        Ok(instructions)
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add sell instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell(mint, amount_token, slippage_basis_points)
+            .await?
+            .send()
+            .await
     }
 
-    pub fn get_sell_instructions_offline_prepared(
+    /// Sells tokens and reports the settlement of the trade
+    ///
+    /// Sends the same transaction as `sell`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the SOL actually received and the fees
+    /// actually paid.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report of the sale if successful, or a ClientError if the
+    /// operation fails
+    #[cfg(feature = "stream")]
+    pub async fn sell_and_settle(
         &self,
-        mint: &Pubkey,
-        creator: &Pubkey,
-        amount_sol: u64,
+        mint: Pubkey,
         amount_token: Option<u64>,
         slippage_basis_points: Option<u64>,
-        global_account: &GlobalAccount,
-        close_ata: bool,
-    ) -> Vec<Instruction> {
-        // Get ATA
-        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
-
-        // Determine amount to sell
-        let amount = amount_token.unwrap();
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell(mint, amount_token, slippage_basis_points)
+            .await?
+            .send_and_settle()
+            .await
+    }
 
-        // Calculate min sol output
-        let min_sol_output = utils::calculate_with_slippage_sell(
-            amount_sol,
+    /// Simulates a sale of tokens
+    ///
+    /// Builds the same transaction as `sell` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
+    pub async fn simulate_sell(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell(mint, amount_token, slippage_basis_points)
+            .await?
+            .simulate()
+            .await
+    }
+
+    /// Exports a sale of tokens for external signing
+    ///
+    /// Builds the same transaction as `sell` without the payer's signature, see
+    /// `TxBuilder::export`, so that it can be approved by an external wallet or multisig.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `nonce` - Optional durable nonce account, with the payer as authority, used instead of
+    ///   a recent blockhash. Without one, the transaction expires about a minute after the
+    ///   export, once its blockhash is no longer valid
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the base64 encoded transaction if successful, or a ClientError if the operation fails
+    pub async fn export_sell(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        nonce: Option<Pubkey>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<String, error::ClientError> {
+        self.export_tx(nonce, priority_fee)
+            .sell(mint, amount_token, slippage_basis_points)
+            .await?
+            .export()
+            .await
+    }
+
+    /// Sells tokens back to the bonding curve until an exact amount of SOL is received
+    ///
+    /// Quotes the tokens needed to receive `amount_sol` after the protocol and creator fees,
+    /// and sells them with slippage protection.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_sol` - Amount of SOL to receive after fees, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The bonding curve account cannot be found
+    /// - The bonding curve cannot pay out the requested amount
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Sell until 2 SOL are received, with 1% max slippage
+    /// let signature = client
+    ///     .sell_for_sol(mint, sol_to_lamports(2.0), Some(100), None)
+    ///     .await?;
+    /// println!("Tokens sold! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sell_for_sol(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add sell instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell_for_sol(mint, amount_sol, slippage_basis_points)
+            .await?
+            .send()
+            .await
+    }
+
+    /// Sells tokens until an exact amount of SOL is received and reports the settlement
+    /// of the trade
+    ///
+    /// Sends the same transaction as `sell_for_sol`, then fetches it once confirmed, see
+    /// `TxBuilder::send_and_settle`, to report the tokens actually sold and the fees
+    /// actually paid.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_sol` - Amount of SOL to receive after fees, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report of the sale if successful, or a ClientError if the
+    /// operation fails
+    #[cfg(feature = "stream")]
+    pub async fn sell_for_sol_and_settle(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell_for_sol(mint, amount_sol, slippage_basis_points)
+            .await?
+            .send_and_settle()
+            .await
+    }
+
+    /// Simulates a sale of tokens until an exact amount of SOL is received
+    ///
+    /// Builds the same transaction as `sell_for_sol` and simulates it against the current state
+    /// of the cluster without sending it, so that no SOL is spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_sol` - Amount of SOL to receive after fees, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation result, or a ClientError if the operation fails. A transaction
+    /// failing in the simulation is reported in the result rather than as an error.
+    pub async fn simulate_sell_for_sol(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<SimulationResult, error::ClientError> {
+        self.tx()
+            .priority_fee(priority_fee)
+            .sell_for_sol(mint, amount_sol, slippage_basis_points)
+            .await?
+            .simulate()
+            .await
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn compile_instructions(
+        &self,
+        instructions: &Vec<Instruction>,
+        recent_blockhash: &Hash,
+    ) -> Result<PumpFunTransaction, error::ClientError> {
+        get_transaction_offline_prepared(
+            recent_blockhash,
+            self.rpc.clone(),
+            self.payer.clone(),
+            instructions,
+            None,
+            self.transaction_format,
+            Some(&self.lookup_tables),
+        )
+    }
+
+    /// Signs instructions against a durable nonce, so the transaction can be sent later
+    ///
+    /// An instruction advancing the nonce is prepended to the instructions, with the payer
+    /// as nonce authority. Unlike transactions signed with a recent blockhash, the signed
+    /// transaction stays valid until the nonce is advanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions of the transaction, such as the ones returned by
+    ///   `buy_instructions_offline_prepared` or `sell_instructions_offline_prepared`
+    /// * `nonce` - Public key of a nonce account whose authority is the payer
+    /// * `nonce_blockhash` - Current value of the nonce, see `get_nonce_account`
+    ///
+    /// # Returns
+    ///
+    /// Returns a signed transaction in the format of the client if successful, or a ClientError
+    /// if the operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let cluster = Cluster::devnet(CommitmentConfig::confirmed(), PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let creator = pubkey!("Cr3atorPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let nonce = Arc::new(Keypair::new());
+    /// client.create_nonce_account(nonce.clone(), None).await?;
+    ///
+    /// // Prepare and sign the order ahead
+    /// let global = client.get_global_account().await?;
+    /// let nonce_account = client.get_nonce_account(&nonce.pubkey()).await?;
+    /// let instructions = client.buy_instructions_offline_prepared(
+    ///     &mint, &creator, sol_to_lamports(0.1), 1_000_000, None, None, None, &global,
+    /// );
+    /// let transaction = client.compile_instructions_with_nonce(
+    ///     &instructions,
+    ///     &nonce.pubkey(),
+    ///     &nonce_account.blockhash(),
+    /// )?;
+    ///
+    /// // Send it later, once the order should be placed
+    /// let signature = client.rpc.send_and_confirm_transaction(&transaction).await?;
+    /// println!("Order placed! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compile_instructions_with_nonce(
+        &self,
+        instructions: &[Instruction],
+        nonce: &Pubkey,
+        nonce_blockhash: &Hash,
+    ) -> Result<PumpFunTransaction, error::ClientError> {
+        let mut nonce_instructions = vec![advance_nonce_account(nonce, &self.payer.pubkey())];
+        nonce_instructions.extend_from_slice(instructions);
+        self.compile_instructions(&nonce_instructions, nonce_blockhash)
+    }
+
+    /// Attaches external signatures to an exported transaction, then sends and confirms it
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - Base64 encoded transaction, such as one returned by `export_buy`
+    /// * `signatures` - Public keys of the external signers with their signatures of the
+    ///   transaction message
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction cannot be decoded
+    /// - A public key is not a required signer of the transaction, or its signature is invalid
+    /// - A required signature is still missing
+    /// - Transaction execution on Solana fails
+    pub async fn send_signed_transaction(
+        &self,
+        transaction: &str,
+        signatures: &[(Pubkey, Signature)],
+    ) -> Result<Signature, error::ClientError> {
+        let mut transaction = decode_transaction(transaction)?;
+        add_signatures(&mut transaction, signatures)?;
+
+        if transaction.verify_with_results().contains(&false) {
+            return Err(error::ClientError::OtherError(
+                "Transaction is missing required signatures".to_string(),
+            ));
+        }
+
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .await
+            .map_err(error::ClientError::SolanaClientError)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn sell_instructions_offline_prepared(
+        &self,
+        mint: &Pubkey,
+        creator: &Pubkey,
+        amount_sol: u64,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        global_account: &GlobalAccount,
+        close_ata: bool,
+    ) -> Vec<Instruction> {
+        // Add sell instruction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instructions(self.get_sell_instructions_offline_prepared(
+                mint,
+                creator,
+                amount_sol,
+                amount_token,
+                slippage_basis_points,
+                global_account,
+                close_ata,
+            ))
+            .build()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn sell_offline_prepared(
+        &self,
+        mint: &Pubkey,
+        creator: &Pubkey,
+        amount_sol: u64,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        global_account: &GlobalAccount,
+        close_ata: bool,
+        recent_blockhash: &Hash,
+    ) -> Result<Signature, error::ClientError> {
+        // Add sell instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instructions(self.get_sell_instructions_offline_prepared(
+                mint,
+                creator,
+                amount_sol,
+                amount_token,
+                slippage_basis_points,
+                global_account,
+                close_ata,
+            ))
+            .send_with_blockhash(recent_blockhash)
+            .await
+    }
+
+    /// Collects the creator fees accumulated in the payer's creator vault
+    ///
+    /// Every buy and sell of a token pays a creator fee into the vault of the token's
+    /// creator. This method withdraws the collectable balance of the payer's vault,
+    /// leaving behind only the lamports needed to keep the vault rent exempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let balance = client.get_creator_vault_balance(&payer.pubkey()).await?;
+    /// if balance > 0 {
+    ///     let signature = client.collect_creator_fee(None).await?;
+    ///     println!("Collected {} lamports! Signature: {}", balance, signature);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collect_creator_fee(
+        &self,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add collect creator fee instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(instructions::collect_creator_fee(&self.payer.pubkey()))
+            .send()
+            .await
+    }
+
+    /// Initializes the payer's volume accumulator for token incentives
+    ///
+    /// Buys with volume tracking enabled record the traded SOL volume in the buyer's
+    /// volume accumulator. The buy instruction creates the accumulator on demand; this
+    /// method creates it ahead of time.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The payer's volume accumulator already exists
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let signature = client.init_user_volume_accumulator(None).await?;
+    /// println!("Volume accumulator initialized! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn init_user_volume_accumulator(
+        &self,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add init user volume accumulator instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(instructions::init_user_volume_accumulator(
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+            ))
+            .send()
+            .await
+    }
+
+    /// Syncs the payer's volume accumulator with the global volume accumulator
+    ///
+    /// Credits the incentive tokens earned by the volume of completed incentive days to the
+    /// payer's unclaimed balance, so that it is reflected in the account data before claiming.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The payer's volume accumulator does not exist
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let signature = client.sync_user_volume_accumulator(None).await?;
+    /// let accumulator = client.get_user_volume_accumulator(&payer.pubkey()).await?;
+    /// println!("Unclaimed tokens: {}", accumulator.total_unclaimed_tokens);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync_user_volume_accumulator(
+        &self,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add sync user volume accumulator instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(instructions::sync_user_volume_accumulator(
+                &self.payer.pubkey(),
+            ))
+            .send()
+            .await
+    }
+
+    /// Claims the incentive tokens earned by the payer's trading volume
+    ///
+    /// Transfers the payer's unclaimed incentive tokens to their associated token account
+    /// for the incentive mint, creating the token account if needed. The incentive mint
+    /// and its token program are read from the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global volume accumulator or the incentive mint cannot be fetched
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let global = client.get_global_volume_accumulator().await?;
+    /// let user = client.get_user_volume_accumulator(&payer.pubkey()).await?;
+    /// let now = std::time::SystemTime::now()
+    ///     .duration_since(std::time::UNIX_EPOCH)?
+    ///     .as_secs() as i64;
+    /// if user.get_total_unclaimed_tokens(&global, now) > 0 {
+    ///     let signature = client.claim_token_incentives(None).await?;
+    ///     println!("Incentives claimed! Signature: {}", signature);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn claim_token_incentives(
+        &self,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Resolve the incentive mint and the token program owning it
+        let global_volume_accumulator = self.get_global_volume_accumulator().await?;
+        let mint = global_volume_accumulator.mint;
+        let token_program = self
+            .rpc
+            .get_account(&mint)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?
+            .owner;
+
+        // Add claim token incentives instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(instructions::claim_token_incentives(
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+                &mint,
+                &token_program,
+            ))
+            .send()
+            .await
+    }
+
+    /// Closes the payer's volume accumulator and reclaims its rent
+    ///
+    /// The accumulator has to be claimed before it can be closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The payer's volume accumulator still holds unclaimed tokens
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let signature = client.close_user_volume_accumulator(None).await?;
+    /// println!("Volume accumulator closed! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn close_user_volume_accumulator(
+        &self,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Add close user volume accumulator instruction, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(instructions::close_user_volume_accumulator(
+                &self.payer.pubkey(),
+            ))
+            .send()
+            .await
+    }
+
+    /// Creates and funds a durable nonce account, with the payer as nonce authority
+    ///
+    /// The account is funded with the minimum balance for rent exemption. Its nonce value
+    /// can then be used instead of a recent blockhash, so that transactions signed ahead
+    /// remain valid until they are sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - Signer of the new nonce account, such as a keypair
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The minimum balance for rent exemption cannot be fetched
+    /// - The nonce account already exists
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let nonce = Arc::new(Keypair::new());
+    /// let signature = client.create_nonce_account(nonce.clone(), None).await?;
+    /// println!("Nonce account {} created! Signature: {}", nonce.pubkey(), signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_nonce_account(
+        &self,
+        nonce: impl Signer + Send + Sync + 'static,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        let lamports = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(solana_nonce::state::State::size())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        // Add create and initialize nonce account instructions, then sign, send and confirm the transaction
+        self.tx()
+            .priority_fee(priority_fee)
+            .instructions(create_nonce_account(
+                &self.payer.pubkey(),
+                &nonce.pubkey(),
+                &self.payer.pubkey(),
+                lamports,
+            ))
+            .signer(nonce)
+            .send()
+            .await
+    }
+
+    /// Creates an address lookup table of the accounts used by Pump.fun trades
+    ///
+    /// The table stores the static Pump.fun accounts, including the fee recipients of the
+    /// global configuration, and the accounts of the given users, with the payer as table
+    /// authority. The table can be used once the slot of its last extension has passed,
+    /// for instance by loading it with `load_lookup_tables`.
+    ///
+    /// # Arguments
+    ///
+    /// * `users` - Public keys of the users whose accounts are stored in the table
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the address of the lookup table if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global configuration account or a recent slot cannot be fetched
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// let mut client = PumpFun::new(payer.clone(), cluster);
+    /// let table = client.create_lookup_table(&[payer.pubkey()], None).await?;
+    ///
+    /// // Compile the transactions of the client against the table
+    /// client.load_lookup_tables(&[table]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_lookup_table(
+        &self,
+        users: &[Pubkey],
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Pubkey, error::ClientError> {
+        let global = self.get_global_account().await?;
+        let recent_slot = self
+            .rpc
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        // Add create lookup table instruction, then sign, send and confirm the transaction
+        let (create_ix, table) = alt_instruction::create_lookup_table(
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            recent_slot,
+        );
+        self.tx()
+            .priority_fee(priority_fee)
+            .instruction(create_ix)
+            .send()
+            .await?;
+
+        self.extend_lookup_table(
+            table,
+            utils::lookup_table::get_trade_accounts(&global, users),
+            priority_fee,
+        )
+        .await?;
+
+        Ok(table)
+    }
+
+    /// Extends an address lookup table with new addresses
+    ///
+    /// The addresses are added in batches of `MAX_ADDRESSES_PER_EXTEND`, one transaction
+    /// per batch, with the payer as table authority.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Public key of the lookup table, whose authority is the payer
+    /// * `addresses` - Addresses to add to the table
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the signatures of the transactions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The table does not exist or its authority is not the payer
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    pub async fn extend_lookup_table(
+        &self,
+        table: Pubkey,
+        addresses: Vec<Pubkey>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Vec<Signature>, error::ClientError> {
+        let mut signatures = Vec::new();
+        for batch in addresses.chunks(utils::lookup_table::MAX_ADDRESSES_PER_EXTEND) {
+            // Add extend lookup table instruction, then sign, send and confirm the transaction
+            let signature = self
+                .tx()
+                .priority_fee(priority_fee)
+                .instruction(alt_instruction::extend_lookup_table(
+                    table,
+                    self.payer.pubkey(),
+                    Some(self.payer.pubkey()),
+                    batch.to_vec(),
+                ))
+                .send()
+                .await?;
+            signatures.push(signature);
+        }
+
+        Ok(signatures)
+    }
+
+    /// Subscribes to real-time events from the Pump.fun program
+    ///
+    /// This method establishes a WebSocket connection to the Solana cluster and subscribes
+    /// to program log events from the Pump.fun program. It parses the emitted events into
+    /// structured data types and delivers them through the provided callback function.
+    ///
+    /// Event types include:
+    /// - `CreateEvent`: Emitted when a new token is created
+    /// - `TradeEvent`: Emitted when tokens are bought or sold
+    /// - `CompleteEvent`: Emitted when a bonding curve operation completes
+    /// - `SetParamsEvent`: Emitted when global parameters are updated
+    ///
+    /// # Arguments
+    ///
+    /// * `mentioned` - Optional public key to filter events by mentions. If None, subscribes to all Pump.fun events
+    /// * `commitment` - Optional commitment level for the subscription. If None, uses the
+    ///   default from the cluster configuration
+    /// * `callback` - A function that will be called for each event with the following parameters:
+    ///   * `signature`: The transaction signature as a String
+    ///   * `event`: The parsed PumpFunEvent if successful, or None if parsing failed
+    ///   * `error`: Any error that occurred during parsing, or None if successful
+    ///   * `response`: The complete RPC logs response for additional context
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Subscription` object that manages the lifecycle of the subscription.
+    /// When this object is dropped, the subscription is automatically terminated. If
+    /// the subscription cannot be established, returns a ClientError.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection cannot be established
+    /// - The subscription request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::{sync::Arc, error::Error};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// // Subscribe to token events
+    /// let subscription = client.subscribe(None, None, |signature, event, error, _| {
+    ///     match event {
+    ///         Some(pumpfun::common::stream::PumpFunEvent::Create(create_event)) => {
+    ///             println!("New token created: {} ({})", create_event.name, create_event.symbol);
+    ///             println!("Mint address: {}", create_event.mint);
+    ///         },
+    ///         Some(pumpfun::common::stream::PumpFunEvent::Trade(trade_event)) => {
+    ///             let action = if trade_event.is_buy { "bought" } else { "sold" };
+    ///             println!(
+    ///                 "User {} {} {} tokens for {} SOL",
+    ///                 trade_event.user,
+    ///                 action,
+    ///                 trade_event.token_amount,
+    ///                 trade_event.sol_amount as f64 / 1_000_000_000.0
+    ///             );
+    ///         },
+    ///         Some(event) => println!("Other event received: {:#?}", event),
+    ///         None => {
+    ///             if let Some(err) = error {
+    ///                 eprintln!("Error parsing event in tx {}: {}", signature, err);
+    ///             }
+    ///         }
+    ///     }
+    /// }).await?;
+    ///
+    /// // Keep the subscription active
+    /// // When no longer needed, drop the subscription to unsubscribe
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn subscribe<F>(
+        &self,
+        mentioned: Option<String>,
+        commitment: Option<solana_sdk::commitment_config::CommitmentConfig>,
+        callback: F,
+    ) -> Result<common::stream::Subscription, error::ClientError>
+    where
+        F: Fn(
+                String,
+                Option<common::stream::PumpFunEvent>,
+                Option<Box<dyn std::error::Error + Send + Sync>>,
+                solana_client::rpc_response::Response<solana_client::rpc_response::RpcLogsResponse>,
+            ) + Send
+            + Sync
+            + 'static,
+    {
+        common::stream::subscribe(self.cluster.clone(), mentioned, commitment, callback).await
+    }
+
+    /// Creates compute budget instructions for priority fees
+    ///
+    /// Generates Solana compute budget instructions based on the provided priority fee
+    /// configuration. These instructions are used to set the maximum compute units a
+    /// transaction can consume and the price per compute unit, which helps prioritize
+    /// transaction processing during network congestion.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Priority fee configuration containing optional unit limit and unit price
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of instructions to set compute budget parameters, which can be
+    /// empty if no priority fee parameters are provided. When the unit limit is estimated
+    /// automatically, the limit instruction uses `unit_limit`, or the maximum limit if None,
+    /// until it is rewritten with the estimate before sending. The price instruction uses
+    /// the price known without accessing the network, and is omitted for strategies
+    /// resolved from the network until the transaction is sent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{PriorityFee, PriorityFeeStrategy}};
+    /// # use solana_sdk::instruction::Instruction;
+    /// #
+    /// // Set both compute unit limit and price
+    /// let priority_fee = PriorityFee {
+    ///     unit_limit: Some(200_000),
+    ///     unit_price: Some(PriorityFeeStrategy::Fixed(1_000)), // 1000 micro-lamports per compute unit
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let compute_instructions: Vec<Instruction> = PumpFun::get_priority_fee_instructions(&priority_fee);
+    ///
+    /// // Estimate the limit from a simulation with a 10% margin when sending
+    /// let priority_fee = PriorityFee::new(None, Some(1_000)).with_auto_unit_limit(1_000);
+    ///
+    /// // Pay the 75th percentile of recent fees for the traded accounts, up to 50_000
+    /// let priority_fee = PriorityFee::new(Some(200_000), None).with_unit_price(
+    ///     PriorityFeeStrategy::Percentile {
+    ///         percentile: 75,
+    ///         max_price: Some(50_000),
+    ///     },
+    /// );
+    /// ```
+    pub fn get_priority_fee_instructions(priority_fee: &PriorityFee) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+
+        let unit_limit = match priority_fee.unit_limit_margin_basis_points {
+            Some(_) => priority_fee
+                .unit_limit
+                .or(Some(PriorityFee::MAX_UNIT_LIMIT)),
+            None => priority_fee.unit_limit,
+        };
+        if let Some(limit) = unit_limit {
+            let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
+            instructions.push(limit_ix);
+        }
+
+        if let Some(price) = priority_fee
+            .unit_price
+            .and_then(|strategy| strategy.get_static_price())
+        {
+            let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
+            instructions.push(price_ix);
+        }
+
+        instructions
+    }
+
+    /// Creates an instruction for initializing a new token
+    ///
+    /// Generates a Solana instruction to create a new token with a bonding curve on Pump.fun.
+    /// This instruction will initialize the token mint, metadata, and bonding curve accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the new token mint account that will be created
+    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    ///
+    /// # Returns
+    ///
+    /// Returns a Solana instruction for creating a new token
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = Keypair::new();
+    /// let metadata_response = utils::create_token_metadata(
+    ///     utils::CreateTokenMetadata {
+    ///         name: "Example Token".to_string(),
+    ///         symbol: "EXTKN".to_string(),
+    ///         description: "An example token".to_string(),
+    ///         file: "path/to/image.png".to_string(),
+    ///         twitter: None,
+    ///         telegram: None,
+    ///         website: None,
+    ///     }
+    /// ).await?;
+    ///
+    /// let create_instruction = client.get_create_instruction(&mint.pubkey(), metadata_response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_create_instruction(
+        &self,
+        mint: &Pubkey,
+        ipfs: utils::TokenMetadataResponse,
+    ) -> Instruction {
+        instructions::create(
+            &self.payer.pubkey(),
+            mint,
+            instructions::Create {
+                name: ipfs.metadata.name,
+                symbol: ipfs.metadata.symbol,
+                uri: ipfs.metadata.image,
+                creator: self.payer.pubkey(),
+            },
+        )
+    }
+
+    /// Generates instructions for buying tokens from a bonding curve
+    ///
+    /// Creates a set of Solana instructions needed to purchase tokens using SOL. These
+    /// instructions may include creating an associated token account if needed, and the actual
+    /// buy instruction with slippage protection.
+    ///
+    /// The token amount is quoted net of the protocol and creator fees resolved from the
+    /// fee configuration for the curve's market cap, so that `amount_sol` covers the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The buy price calculation fails
+    /// - Token account-related operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let amount_sol = sol_to_lamports(0.01); // 0.01 SOL
+    /// let slippage_bps = Some(300); // 3%
+    /// let track_volume = Some(true); // Track this buy in volume stats
+    ///
+    /// let buy_instructions = client.get_buy_instructions(mint, amount_sol, track_volume, slippage_bps).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_buy_instructions(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and calculate buy amounts
+        let (global_account, bonding_curve_account, fees) = self.get_buy_accounts(&mint).await?;
+        let buy_amount = match &bonding_curve_account {
+            Some(bonding_curve) => bonding_curve.get_buy_quote(amount_sol, &fees),
+            None => global_account.get_initial_buy_quote(amount_sol, &fees),
+        }?
+        .token_amount;
+        let buy_amount_with_slippage =
+            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
+
+        Ok(self
+            .get_buy_instructions_for_amount(
+                &mint,
+                &global_account.fee_recipient,
+                &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
+                buy_amount,
+                buy_amount_with_slippage,
+                track_volume,
+            )
+            .await)
+    }
+
+    /// Generates instructions for buying an exact amount of tokens from a bonding curve
+    ///
+    /// Quotes the SOL needed to buy `amount_token` tokens, including the protocol and
+    /// creator fees, and uses it with slippage as the maximum SOL cost of the buy. The
+    /// instructions may include creating an associated token account if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units, capped at the curve's real token reserves
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The buy quote calculation fails
+    /// - Token account-related operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let amount_token = 10_000_000_000_000; // 10M tokens with 6 decimals
+    /// let instructions = client
+    ///     .get_buy_exact_tokens_instructions(mint, amount_token, None, Some(300))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_buy_exact_tokens_instructions(
+        &self,
+        mint: Pubkey,
+        amount_token: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and calculate the SOL cost of the tokens
+        let (global_account, bonding_curve_account, fees) = self.get_buy_accounts(&mint).await?;
+        let quote = match &bonding_curve_account {
+            Some(bonding_curve) => bonding_curve.get_buy_quote_exact_tokens(amount_token, &fees),
+            None => global_account.get_initial_buy_quote_exact_tokens(amount_token, &fees),
+        }?;
+        let max_sol_cost = utils::calculate_with_slippage_buy(
+            quote.gross_sol_amount,
+            slippage_basis_points.unwrap_or(500),
+        );
+
+        Ok(self
+            .get_buy_instructions_for_amount(
+                &mint,
+                &global_account.fee_recipient,
+                &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
+                quote.token_amount,
+                max_sol_cost,
+                track_volume,
+            )
+            .await)
+    }
+
+    /// Fetches the accounts needed to quote a buy
+    ///
+    /// Returns the global account, the bonding curve account or None if the curve is not
+    /// created yet, and the fees applying to the curve.
+    async fn get_buy_accounts(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<
+        (
+            GlobalAccount,
+            Option<accounts::BondingCurveAccount>,
+            accounts::Fees,
+        ),
+        error::ClientError,
+    > {
+        let global_account = self.get_global_account().await?;
+        let fee_config = self.find_fee_config_account().await?;
+
+        let bonding_curve_pda =
+            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+        let bonding_curve_account = if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
+            None
+        } else {
+            Some(self.get_bonding_curve_account(mint).await?)
+        };
+
+        let fees = global_account.get_fees(fee_config.as_ref(), bonding_curve_account.as_ref());
+        Ok((global_account, bonding_curve_account, fees))
+    }
+
+    /// Generates the instructions buying a quoted amount of tokens
+    #[cfg_attr(not(feature = "create-ata"), allow(unused_mut))]
+    async fn get_buy_instructions_for_amount(
+        &self,
+        mint: &Pubkey,
+        fee_recipient: &Pubkey,
+        creator: &Pubkey,
+        amount: u64,
+        max_sol_cost: u64,
+        track_volume: Option<bool>,
+    ) -> Vec<Instruction> {
+        // Add buy instruction
+        let mut instructions = vec![instructions::buy(
+            &self.payer.pubkey(),
+            mint,
+            fee_recipient,
+            creator,
+            instructions::Buy {
+                amount,
+                max_sol_cost,
+                track_volume,
+            },
+        )];
+
+        // Create Associated Token Account if needed, ahead of the buy
+        #[cfg(feature = "create-ata")]
+        {
+            let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
+            if self.rpc.get_account(&ata).await.is_err() {
+                instructions.insert(
+                    0,
+                    create_associated_token_account(
+                        &self.payer.pubkey(),
+                        &self.payer.pubkey(),
+                        mint,
+                        &constants::accounts::TOKEN_PROGRAM,
+                    ),
+                );
+            }
+        }
+
+        instructions
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "create-ata"), allow(unused_mut))]
+    pub fn get_buy_instructions_offline_prepared(
+        &self,
+        mint: &Pubkey,
+        creator: &Pubkey,
+        amount_sol: u64,
+        buy_amount: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        global_account: &GlobalAccount,
+    ) -> Vec<Instruction> {
+        let buy_amount_with_slippage =
+            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
+
+        // Add buy instruction
+        let mut instructions = vec![instructions::buy(
+            &self.payer.pubkey(),
+            mint,
+            &global_account.fee_recipient,
+            creator,
+            instructions::Buy {
+                amount: buy_amount,
+                max_sol_cost: buy_amount_with_slippage,
+                track_volume,
+            },
+        )];
+
+        // Create Associated Token Account if needed, ahead of the buy
+        #[cfg(feature = "create-ata")]
+        {
+            instructions.insert(
+                0,
+                create_associated_token_account(
+                    &self.payer.pubkey(),
+                    &self.payer.pubkey(),
+                    mint,
+                    &constants::accounts::TOKEN_PROGRAM,
+                ),
+            );
+        }
+
+        instructions
+    }
+
+    /// Generates instructions for selling tokens back to a bonding curve
+    ///
+    /// Creates a set of Solana instructions needed to sell tokens in exchange for SOL. These
+    /// instructions include the sell instruction with slippage protection and may include
+    /// closing the associated token account if all tokens are being sold and the feature
+    /// is enabled.
+    ///
+    /// The minimum SOL output is quoted net of the protocol and creator fees resolved from
+    /// the fee configuration for the curve's market cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The token account or token balance cannot be fetched
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The sell price calculation fails
+    /// - Token account closing operations fail (when applicable)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let amount_tokens = Some(1000); // Sell 1000 tokens
+    /// let slippage_bps = Some(200); // 2%
+    ///
+    /// let sell_instructions = client.get_sell_instructions(mint, amount_tokens, slippage_bps).await?;
+    ///
+    /// // Or to sell all tokens:
+    /// let sell_all_instructions = client.get_sell_instructions(mint, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sell_instructions(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get ATA
+        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
+
+        // Get token balance
+        let token_balance = if amount_token.is_none() || cfg!(feature = "close-ata") {
+            // We need the balance if amount_token is None OR if the close-ata feature is enabled
+            Some(self.get_token_balance(&ata).await?)
+        } else {
+            None
+        };
+
+        // Determine amount to sell
+        let amount = amount_token.unwrap_or_else(|| token_balance.unwrap());
+
+        // Calculate min sol output
+        let global_account = self.get_global_account().await?;
+        let fee_config = self.find_fee_config_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
+        let fees = global_account.get_fees(fee_config.as_ref(), Some(&bonding_curve_account));
+        let min_sol_output = bonding_curve_account
+            .get_sell_quote(amount, &fees)?
+            .net_sol_amount;
+        let min_sol_output = utils::calculate_with_slippage_sell(
+            min_sol_output,
+            slippage_basis_points.unwrap_or(500),
+        );
+
+        self.get_sell_instructions_for_amount(
+            &mint,
+            &global_account.fee_recipient,
+            &bonding_curve_account.creator,
+            amount,
+            min_sol_output,
+            token_balance,
+        )
+        .await
+    }
+
+    /// Generates instructions for selling tokens until an exact amount of SOL is received
+    ///
+    /// Quotes the tokens needed to receive `amount_sol` after the protocol and creator fees,
+    /// and sells them with slippage protection. The instructions may include closing the
+    /// associated token account if all tokens are being sold and the feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_sol` - Amount of SOL to receive after fees, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The bonding curve cannot pay out the requested amount
+    /// - The token balance is lower than the tokens needed
+    /// - Token account-related operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let instructions = client
+    ///     .get_sell_for_sol_instructions(mint, sol_to_lamports(2.0), Some(300))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sell_for_sol_instructions(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Calculate the tokens to sell
+        let global_account = self.get_global_account().await?;
+        let fee_config = self.find_fee_config_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
+        let fees = global_account.get_fees(fee_config.as_ref(), Some(&bonding_curve_account));
+        let quote = bonding_curve_account.get_sell_quote_exact_sol(amount_sol, &fees)?;
+        let min_sol_output = utils::calculate_with_slippage_sell(
+            quote.net_sol_amount,
             slippage_basis_points.unwrap_or(500),
         );
 
-        let mut instructions = Vec::new();
+        // Get token balance to check the quoted amount can be sold, and to decide whether
+        // the token account can be closed
+        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
+        let token_balance = self.get_token_balance(&ata).await?;
+        if quote.token_amount > token_balance {
+            return Err(error::ClientError::OtherError(format!(
+                "Insufficient token balance: selling {} tokens, balance is {}",
+                quote.token_amount, token_balance
+            )));
+        }
+
+        self.get_sell_instructions_for_amount(
+            &mint,
+            &global_account.fee_recipient,
+            &bonding_curve_account.creator,
+            quote.token_amount,
+            min_sol_output,
+            Some(token_balance),
+        )
+        .await
+    }
+
+    /// Fetches the balance of a token account in base units
+    async fn get_token_balance(&self, ata: &Pubkey) -> Result<u64, error::ClientError> {
+        let balance = self.rpc.get_token_account_balance(ata).await?;
+        balance.amount.parse::<u64>().map_err(|err| {
+            error::ClientError::OtherError(format!(
+                "Invalid token balance: pubkey={}: {}",
+                ata, err
+            ))
+        })
+    }
+
+    /// Generates the instructions selling a quoted amount of tokens
+    ///
+    /// The token account is closed when `token_balance` equals the amount sold and the
+    /// `close-ata` feature is enabled.
+    #[cfg_attr(not(feature = "close-ata"), allow(unused_mut, unused_variables))]
+    async fn get_sell_instructions_for_amount(
+        &self,
+        mint: &Pubkey,
+        fee_recipient: &Pubkey,
+        creator: &Pubkey,
+        amount: u64,
+        min_sol_output: u64,
+        token_balance: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Add sell instruction
+        let mut instructions = vec![instructions::sell(
+            &self.payer.pubkey(),
+            mint,
+            fee_recipient,
+            creator,
+            instructions::Sell {
+                amount,
+                min_sol_output,
+            },
+        )];
+
+        // Close account if we're selling all tokens
+        #[cfg(feature = "close-ata")]
+        if token_balance == Some(amount) {
+            let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
+            let token_program = constants::accounts::TOKEN_PROGRAM;
+
+            // Only close the token account if it exists, there is nothing to close otherwise
+            if self.rpc.get_account(&ata).await.is_ok() {
+                // Create instruction to close the ATA
+                let close_instruction = close_account(
+                    &token_program,
+                    &ata,
+                    &self.payer.pubkey(),
+                    &self.payer.pubkey(),
+                    &[&self.payer.pubkey()],
+                )
+                .map_err(|err| {
+                    error::ClientError::OtherError(format!(
+                        "Failed to create close account instruction: pubkey={}: {}",
+                        ata, err
+                    ))
+                })?;
+
+                instructions.push(close_instruction);
+            }
+        }
+
+        Ok(instructions)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "close-ata"), allow(unused_mut, unused_variables))]
+    pub fn get_sell_instructions_offline_prepared(
+        &self,
+        mint: &Pubkey,
+        creator: &Pubkey,
+        amount_sol: u64,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        global_account: &GlobalAccount,
+        close_ata: bool,
+    ) -> Vec<Instruction> {
+        // Determine amount to sell
+        let amount = amount_token.unwrap();
+
+        // Calculate min sol output
+        let min_sol_output =
+            utils::calculate_with_slippage_sell(amount_sol, slippage_basis_points.unwrap_or(500));
+
+        // Add sell instruction
+        let mut instructions = vec![instructions::sell(
+            &self.payer.pubkey(),
+            mint,
+            &global_account.fee_recipient,
+            creator,
+            instructions::Sell {
+                amount,
+                min_sol_output,
+            },
+        )];
+
+        // Close account if balance equals amount
+        #[cfg(feature = "close-ata")]
+        if close_ata {
+            let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
+            let token_program = constants::accounts::TOKEN_PROGRAM;
+            match close_account(
+                &token_program,
+                &ata,
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+                &[&self.payer.pubkey()],
+            ) {
+                Ok(close_instruction) => instructions.push(close_instruction),
+                Err(e) => println!("Failed to get close ata instructions, {:?}", e),
+            }
+        }
+
+        instructions
+    }
+
+    /// Gets the Program Derived Address (PDA) for the global state account
+    ///
+    /// Derives the address of the global state account using the program ID and a
+    /// constant seed. The global state account contains program-wide configuration
+    /// such as fee settings and fee recipient.
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the GLOBAL_SEED
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let global_pda: Pubkey = PumpFun::get_global_pda();
+    /// println!("Global state account: {}", global_pda);
+    /// ```
+    pub fn get_global_pda() -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::find_program_address(seeds, program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for the mint authority
+    ///
+    /// Derives the address of the mint authority PDA using the program ID and a
+    /// constant seed. The mint authority PDA is the authority that can mint new
+    /// tokens for any token created through the Pump.fun program.
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let mint_authority: Pubkey = PumpFun::get_mint_authority_pda();
+    /// println!("Mint authority account: {}", mint_authority);
+    /// ```
+    pub fn get_mint_authority_pda() -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::find_program_address(seeds, program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    ///
+    /// Derives the address of a token's bonding curve account using the program ID,
+    /// a constant seed, and the token mint address. The bonding curve account stores
+    /// the state and parameters that govern the token's price dynamics.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// if let Some(bonding_curve) = PumpFun::get_bonding_curve_pda(&mint) {
+    ///     println!("Bonding curve account: {}", bonding_curve);
+    /// }
+    /// ```
+    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
+        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
+        pda.map(|pubkey| pubkey.0)
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    ///
+    /// Derives the address of a token's metadata account following the Metaplex Token Metadata
+    /// standard. The metadata account stores information about the token such as name,
+    /// symbol, and URI pointing to additional metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key for the token's metadata account
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let metadata_pda = PumpFun::get_metadata_pda(&mint);
+    /// println!("Token metadata account: {}", metadata_pda);
+    /// ```
+    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
+        let seeds: &[&[u8]; 3] = &[
+            constants::seeds::METADATA_SEED,
+            constants::accounts::MPL_TOKEN_METADATA.as_ref(),
+            mint.as_ref(),
+        ];
+        let program_id: &Pubkey = &constants::accounts::MPL_TOKEN_METADATA;
+        Pubkey::find_program_address(seeds, program_id).0
+    }
 
-        // Add sell instruction
-        instructions.push(instructions::sell(
-            &self.payer,
-            mint,
-            &global_account.fee_recipient,
-            creator,
-            instructions::Sell {
-                amount,
-                min_sol_output,
-            },
-        ));
+    /// Gets the global state account data containing program-wide configuration
+    ///
+    /// Fetches and deserializes the global state account which contains program-wide
+    /// configuration parameters such as:
+    /// - Fee basis points for trading
+    /// - Fee recipient account
+    /// - Bonding curve parameters
+    /// - Other platform-wide settings
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account cannot be found on-chain
+    /// - The account is not owned by the expected program or has another discriminator
+    /// - The account data cannot be properly deserialized
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let global = client.get_global_account().await?;
+    /// println!("Fee basis points: {}", global.fee_basis_points);
+    /// println!("Fee recipient: {}", global.fee_recipient);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
+        Ok(self.get_global_account_versioned().await?.value)
+    }
 
-        // Close account if balance equals amount
-        #[cfg(feature = "close-ata")]
-        {
-            if close_ata
-            {
-                let token_program = constants::accounts::TOKEN_PROGRAM;
-                let _ = match close_account(
-                    &token_program,
-                    &ata,
-                    &self.payer.pubkey(),
-                    &self.payer.pubkey(),
-                    &[&self.payer.pubkey()],
-                )
-                {
-                    Ok(close_instruction)=>
-                    {
-                        instructions.push(close_instruction);
-                    },
-                    Err(e)=> {
-                        println!("Failed to get close ata instructions, {:?}", e);
-                    }
-                };
-            }
-        }
-        instructions
+    /// Gets the global configuration account and the version of its layout
+    ///
+    /// Same as `get_global_account`, but also reports which known layout the account
+    /// data was decoded with. Fields missing from older layouts take their default value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized GlobalAccount and the version of its layout if successful,
+    /// or a ClientError if the operation fails
+    pub async fn get_global_account_versioned(
+        &self,
+    ) -> Result<Decoded<accounts::GlobalAccount>, error::ClientError> {
+        let global: Pubkey = Self::get_global_pda();
+
+        let account = self
+            .rpc
+            .get_account(&global)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        accounts::decode_account_versioned(
+            &account,
+            &constants::accounts::PUMPFUN,
+            accounts::GlobalAccount::DISCRIMINATOR,
+            accounts::GlobalAccount::decode_versioned,
+        )
+    }
+
+    /// Gets a token's bonding curve account data containing pricing parameters
+    ///
+    /// Fetches and deserializes a token's bonding curve account which contains the
+    /// state and parameters that determine the token's price dynamics, including:
+    /// - Current supply
+    /// - Reserve balance
+    /// - Bonding curve parameters
+    /// - Other token-specific configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The bonding curve PDA cannot be derived
+    /// - The account cannot be found on-chain
+    /// - The account is not owned by the expected program or has another discriminator
+    /// - The account data cannot be properly deserialized
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let bonding_curve = client.get_bonding_curve_account(&mint).await?;
+    /// println!("Bonding Curve Account: {:#?}", bonding_curve);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bonding_curve_account(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
+        Ok(self.get_bonding_curve_account_versioned(mint).await?.value)
     }
 
+    /// Gets a token's bonding curve account and the version of its layout
+    ///
+    /// Same as `get_bonding_curve_account`, but also reports which known layout the
+    /// account data was decoded with. Fields missing from older layouts take their
+    /// default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized BondingCurveAccount and the version of its layout if
+    /// successful, or a ClientError if the operation fails
+    pub async fn get_bonding_curve_account_versioned(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<Decoded<accounts::BondingCurveAccount>, error::ClientError> {
+        let bonding_curve_pda =
+            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        let account = self
+            .rpc
+            .get_account(&bonding_curve_pda)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
 
-    /// Gets the Program Derived Address (PDA) for the global state account
+        accounts::decode_account_versioned(
+            &account,
+            &constants::accounts::PUMPFUN,
+            accounts::BondingCurveAccount::DISCRIMINATOR,
+            accounts::BondingCurveAccount::decode_versioned,
+        )
+    }
+
+    /// Gets the fee configuration account data containing the tiered trading fees
     ///
-    /// Derives the address of the global state account using the program ID and a
-    /// constant seed. The global state account contains program-wide configuration
-    /// such as fee settings and fee recipient.
+    /// Fetches and deserializes the fee configuration account of the Pump.fun fee program,
+    /// which defines the protocol, creator and LP fees charged at each market cap tier.
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key derived from the GLOBAL_SEED
+    /// Returns the deserialized FeeConfig if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account cannot be found on-chain
+    /// - The account is not owned by the expected program or has another discriminator
+    /// - The account data cannot be properly deserialized
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let global = client.get_global_account().await?;
+    /// let fee_config = client.get_fee_config_account().await?;
+    /// let bonding_curve = client.get_bonding_curve_account(&mint).await?;
+    /// let fees = global.get_fees(Some(&fee_config), Some(&bonding_curve));
+    /// println!("Protocol fee: {} bps, creator fee: {} bps", fees.protocol_fee_bps, fees.creator_fee_bps);
+    /// # Ok(())
+    /// # }
     /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::pubkey::Pubkey;
-    /// #
-    /// let global_pda: Pubkey = PumpFun::get_global_pda();
-    /// println!("Global state account: {}", global_pda);
+    pub async fn get_fee_config_account(&self) -> Result<accounts::FeeConfig, error::ClientError> {
+        let account = self
+            .rpc
+            .get_account(&constants::accounts::FEE_CONFIG)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        accounts::decode_account(
+            &account,
+            &constants::accounts::FEE_CONFIG_PROGRAM,
+            accounts::FeeConfig::DISCRIMINATOR,
+        )
+    }
+
+    /// Gets the fee configuration account, or None if it does not exist
+    ///
+    /// Trading falls back to the fees of the global account when the fee configuration
+    /// does not exist, while any other failure to fetch or decode it is returned.
+    async fn find_fee_config_account(
+        &self,
+    ) -> Result<Option<accounts::FeeConfig>, error::ClientError> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(&constants::accounts::FEE_CONFIG, self.rpc.commitment())
+            .await?
+            .value;
+
+        match account {
+            Some(account) => accounts::decode_account(
+                &account,
+                &constants::accounts::FEE_CONFIG_PROGRAM,
+                accounts::FeeConfig::DISCRIMINATOR,
+            )
+            .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets how close a token's bonding curve is to graduating
+    ///
+    /// Fetches the global account, the fee configuration and the bonding curve, and
+    /// measures the curve's progress against the initial real token reserves.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the graduation progress of the curve, or a ClientError if the operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let progress = client.get_graduation_progress(&mint).await?;
+    /// println!(
+    ///     "{:.2}% sold, {} lamports to complete",
+    ///     progress.progress_percent, progress.sol_to_complete
+    /// );
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_global_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn get_graduation_progress(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<accounts::GraduationProgress, error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let fee_config = self.find_fee_config_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+
+        let fees = global_account.get_fees(fee_config.as_ref(), Some(&bonding_curve_account));
+        Ok(bonding_curve_account.get_graduation_progress(&global_account, &fees)?)
     }
 
-    /// Gets the Program Derived Address (PDA) for the mint authority
+    /// Gets the current market cap of a token in USD
+    ///
+    /// # Arguments
     ///
-    /// Derives the address of the mint authority PDA using the program ID and a
-    /// constant seed. The mint authority PDA is the authority that can mint new
-    /// tokens for any token created through the Pump.fun program.
+    /// * `mint` - Public key of the token mint
+    /// * `price_source` - Source of the USD price of SOL
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
+    /// Returns the market cap in USD, or a ClientError if the bonding curve or the SOL
+    /// price cannot be fetched
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::{price::HttpJsonSolPrice, types::{Cluster, PriorityFee}}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let price_source = HttpJsonSolPrice::coingecko()?;
+    /// let market_cap = client.get_market_cap_usd(&mint, &price_source).await?;
+    /// println!("Market cap: ${:.2}", market_cap);
+    /// # Ok(())
+    /// # }
     /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::pubkey::Pubkey;
-    /// #
-    /// let mint_authority: Pubkey = PumpFun::get_mint_authority_pda();
-    /// println!("Mint authority account: {}", mint_authority);
-    /// ```
-    pub fn get_mint_authority_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn get_market_cap_usd(
+        &self,
+        mint: &Pubkey,
+        price_source: &dyn common::price::SolPriceSource,
+    ) -> Result<f64, error::ClientError> {
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+        let sol_price_usd = price_source.get_sol_price_usd().await?;
+        Ok(bonding_curve_account.get_market_cap_usd(sol_price_usd))
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    /// Gets the creator vault address (for claiming pump creator fees)
     ///
-    /// Derives the address of a token's bonding curve account using the program ID,
-    /// a constant seed, and the token mint address. The bonding curve account stores
-    /// the state and parameters that govern the token's price dynamics.
+    /// Derives the token creator's vault using the program ID,
+    /// a constant seed, and the creator's address.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `creator` - Public key of the token's creator
     ///
     /// # Returns
     ///
@@ -1285,69 +3221,113 @@ impl PumpFun {
     /// # use pumpfun::PumpFun;
     /// # use solana_sdk::{pubkey, pubkey::Pubkey};
     /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// if let Some(bonding_curve) = PumpFun::get_bonding_curve_pda(&mint) {
-    ///     println!("Bonding curve account: {}", bonding_curve);
+    /// let creator = pubkey!("Amya8kr2bzEY9kyXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// if let Some(bonding_curve) = PumpFun::get_creator_vault_pda(&creator) {
+    ///     println!("Creator vault address: {}", creator);
     /// }
     /// ```
-    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
-        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
+    pub fn get_creator_vault_pda(creator: &Pubkey) -> Option<Pubkey> {
+        let seeds: &[&[u8]; 2] = &[constants::seeds::CREATOR_VAULT_SEED, creator.as_ref()];
         let program_id: &Pubkey = &constants::accounts::PUMPFUN;
         let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
         pda.map(|pubkey| pubkey.0)
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    /// Gets the creator fees that can currently be collected from a creator's vault
     ///
-    /// Derives the address of a token's metadata account following the Metaplex Token Metadata
-    /// standard. The metadata account stores information about the token such as name,
-    /// symbol, and URI pointing to additional metadata.
+    /// The creator vault is a system account holding the creator fees paid by traders.
+    /// The collectable amount is the vault balance minus the lamports that have to stay
+    /// in the vault to keep it rent exempt.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `creator` - Public key of the token's creator
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key for the token's metadata account
+    /// Returns the collectable balance in lamports, which is zero if the vault does not
+    /// exist yet, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The creator vault PDA cannot be derived
+    /// - The vault balance or the rent exemption minimum cannot be fetched
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let creator = pubkey!("Amya8kr2bzEY9kyXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let balance = client.get_creator_vault_balance(&creator).await?;
+    /// println!("Collectable creator fees: {} lamports", balance);
+    /// # Ok(())
+    /// # }
     /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
-    /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let metadata_pda = PumpFun::get_metadata_pda(&mint);
-    /// println!("Token metadata account: {}", metadata_pda);
-    /// ```
-    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
-        let seeds: &[&[u8]; 3] = &[
-            constants::seeds::METADATA_SEED,
-            constants::accounts::MPL_TOKEN_METADATA.as_ref(),
-            mint.as_ref(),
-        ];
-        let program_id: &Pubkey = &constants::accounts::MPL_TOKEN_METADATA;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn get_creator_vault_balance(
+        &self,
+        creator: &Pubkey,
+    ) -> Result<u64, error::ClientError> {
+        let creator_vault = Self::get_creator_vault_pda(creator).ok_or_else(|| {
+            error::ClientError::OtherError(format!(
+                "Failed to derive creator vault: creator={}",
+                creator
+            ))
+        })?;
+
+        let balance = self
+            .rpc
+            .get_balance(&creator_vault)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        let rent_exempt_minimum = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(0)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(balance.saturating_sub(rent_exempt_minimum))
     }
 
-    /// Gets the global state account data containing program-wide configuration
+    /// Returns the PDA of a user volume accumulator account.
     ///
-    /// Fetches and deserializes the global state account which contains program-wide
-    /// configuration parameters such as:
-    /// - Fee basis points for trading
-    /// - Fee recipient account
-    /// - Bonding curve parameters
-    /// - Other platform-wide settings
+    /// # Arguments
+    /// * `user` - Public key of the user.
+    ///
+    /// # Returns
+    /// PDA of the corresponding user volume accumulator account.
+    pub fn get_user_volume_accumulator_pda(user: &Pubkey) -> Pubkey {
+        let (user_volume_accumulator, _bump) = Pubkey::find_program_address(
+            &[
+                constants::seeds::USER_VOLUME_ACCUMULATOR_SEED,
+                user.as_ref(),
+            ],
+            &constants::accounts::PUMPFUN,
+        );
+        user_volume_accumulator
+    }
+
+    /// Gets the global volume accumulator account data
+    ///
+    /// Fetches and deserializes the global volume accumulator, which holds the incentive
+    /// mint and the SOL volume and incentive supply of every day of the incentive period.
     ///
     /// # Returns
     ///
-    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
+    /// Returns the deserialized GlobalVolumeAccumulator if successful, or a ClientError if the operation fails
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The account cannot be found on-chain
+    /// - The account is not owned by the expected program or has another discriminator
     /// - The account data cannot be properly deserialized
     ///
     /// # Examples
@@ -1361,53 +3341,101 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let global = client.get_global_account().await?;
-    /// println!("Fee basis points: {}", global.fee_basis_points);
-    /// println!("Fee recipient: {}", global.fee_recipient);
+    /// let accumulator = client.get_global_volume_accumulator().await?;
+    /// println!("Incentive mint: {}", accumulator.mint);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
-        let global: Pubkey = Self::get_global_pda();
-
+    pub async fn get_global_volume_accumulator(
+        &self,
+    ) -> Result<accounts::GlobalVolumeAccumulator, error::ClientError> {
         let account = self
             .rpc
-            .get_account(&global)
+            .get_account(&constants::accounts::GLOBAL_VOLUME_ACCUMULATOR)
             .await
             .map_err(error::ClientError::SolanaClientError)?;
 
-        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::GlobalAccount>(&account.data)
-            .map_err(error::ClientError::BorshError)
+        accounts::decode_account(
+            &account,
+            &constants::accounts::PUMPFUN,
+            accounts::GlobalVolumeAccumulator::DISCRIMINATOR,
+        )
     }
 
-    /// Gets a token's bonding curve account data containing pricing parameters
+    /// Gets a user's volume accumulator account data
     ///
-    /// Fetches and deserializes a token's bonding curve account which contains the
-    /// state and parameters that determine the token's price dynamics, including:
-    /// - Current supply
-    /// - Reserve balance
-    /// - Bonding curve parameters
-    /// - Other token-specific configuration
+    /// Fetches and deserializes the volume accumulator of a user, which tracks the SOL
+    /// volume the user traded and the incentive tokens they earned and claimed.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `user` - Public key of the user
     ///
     /// # Returns
     ///
-    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
+    /// Returns the deserialized UserVolumeAccumulator if successful, or a ClientError if the operation fails
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The bonding curve PDA cannot be derived
     /// - The account cannot be found on-chain
+    /// - The account is not owned by the expected program or has another discriminator
     /// - The account data cannot be properly deserialized
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let accumulator = client.get_user_volume_accumulator(&payer.pubkey()).await?;
+    /// println!("Current SOL volume: {}", accumulator.current_sol_volume);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_volume_accumulator(
+        &self,
+        user: &Pubkey,
+    ) -> Result<accounts::UserVolumeAccumulator, error::ClientError> {
+        let account = self
+            .rpc
+            .get_account(&Self::get_user_volume_accumulator_pda(user))
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        accounts::decode_account(
+            &account,
+            &constants::accounts::PUMPFUN,
+            accounts::UserVolumeAccumulator::DISCRIMINATOR,
+        )
+    }
+
+    /// Gets the state of a durable nonce account
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - Public key of the nonce account
+    ///
+    /// # Returns
+    ///
+    /// Returns the data of the initialized nonce account, whose `blockhash` is the current
+    /// nonce value, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account cannot be found on-chain
+    /// - The account is not owned by the system program or is not an initialized nonce account
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
     /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
     /// # use std::sync::Arc;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -1415,72 +3443,160 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let bonding_curve = client.get_bonding_curve_account(&mint).await?;
-    /// println!("Bonding Curve Account: {:#?}", bonding_curve);
+    /// let nonce = pubkey!("NonceAccountPubk3yXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let nonce_account = client.get_nonce_account(&nonce).await?;
+    /// println!("Nonce value: {}", nonce_account.blockhash());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_bonding_curve_account(
+    pub async fn get_nonce_account(
         &self,
-        mint: &Pubkey,
-    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
-        let bonding_curve_pda =
-            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+        nonce: &Pubkey,
+    ) -> Result<solana_nonce::state::Data, error::ClientError> {
+        let account =
+            nonce_utils::get_account_with_commitment(&self.rpc, nonce, self.rpc.commitment())
+                .await
+                .map_err(|err| {
+                    error::ClientError::OtherError(format!("Failed to get nonce account: {}", err))
+                })?;
+
+        nonce_utils::data_from_account(&account).map_err(|err| {
+            error::ClientError::OtherError(format!("Invalid nonce account: {}", err))
+        })
+    }
 
+    /// Gets an address lookup table with its addresses
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Public key of the lookup table
+    ///
+    /// # Returns
+    ///
+    /// Returns the lookup table if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account cannot be found on-chain
+    /// - The account is not an address lookup table
+    pub async fn get_lookup_table(
+        &self,
+        table: &Pubkey,
+    ) -> Result<AddressLookupTableAccount, error::ClientError> {
         let account = self
             .rpc
-            .get_account(&bonding_curve_pda)
+            .get_account(table)
             .await
             .map_err(error::ClientError::SolanaClientError)?;
 
-        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::BondingCurveAccount>(&account.data)
-            .map_err(error::ClientError::BorshError)
+        utils::lookup_table::decode_lookup_table(*table, &account)
     }
 
-    /// Gets the creator vault address (for claiming pump creator fees)
+    /// Fetches address lookup tables and uses them for the transactions of the client
     ///
-    /// Derives the token creator's vault using the program ID,
-    /// a constant seed, and the creator's address.
+    /// Tables already loaded are refreshed with their current addresses. Since only v0
+    /// transactions can use lookup tables, the transaction format of the client is set
+    /// to `TransactionFormat::V0`.
     ///
     /// # Arguments
     ///
-    /// * `creator` - Public key of the token's creator
+    /// * `tables` - Public keys of the lookup tables
     ///
     /// # Returns
     ///
-    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    /// Returns Ok if all tables were loaded, or a ClientError if a table cannot be fetched
+    pub async fn load_lookup_tables(
+        &mut self,
+        tables: &[Pubkey],
+    ) -> Result<(), error::ClientError> {
+        for table in tables {
+            let table = self.get_lookup_table(table).await?;
+            match self
+                .lookup_tables
+                .iter_mut()
+                .find(|loaded| loaded.key == table.key)
+            {
+                Some(loaded) => *loaded = table,
+                None => self.lookup_tables.push(table),
+            }
+        }
+
+        self.transaction_format = TransactionFormat::V0;
+        Ok(())
+    }
+
+    /// Gets the settlement report of a confirmed transaction
+    ///
+    /// Fetches the transaction at the commitment of the client, or at the confirmed
+    /// commitment if the client uses the processed one, and extracts the events emitted
+    /// by the Pump.fun program. The report holds the amounts actually exchanged and the
+    /// fees actually paid by its trades, along with its slot, block time and fee.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report if successful, or a ClientError if the operation fails.
+    /// A failed transaction is reported in the settlement rather than as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction cannot be found at the commitment
+    /// - The transaction has no status metadata
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::{Keypair, Signature}};
+    /// # use std::{str::FromStr, sync::Arc};
     /// #
-    /// let creator = pubkey!("Amya8kr2bzEY9kyXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// if let Some(bonding_curve) = PumpFun::get_creator_vault_pda(&creator) {
-    ///     println!("Creator vault address: {}", creator);
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let signature = Signature::from_str("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW")?;
+    /// let settlement = client.get_settlement(&signature).await?;
+    /// if let Some(trade) = settlement.trade() {
+    ///     println!(
+    ///         "Traded {} tokens for {} lamports, paying {} lamports of fees in slot {}",
+    ///         trade.token_amount,
+    ///         trade.sol_amount,
+    ///         settlement.get_trade_fees() + settlement.transaction_fee,
+    ///         settlement.slot
+    ///     );
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_creator_vault_pda(creator: &Pubkey) -> Option<Pubkey> {
-        let seeds: &[&[u8]; 2] = &[constants::seeds::CREATOR_VAULT_SEED, creator.as_ref()];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
-        pda.map(|pubkey| pubkey.0)
-    }
+    #[cfg(feature = "stream")]
+    pub async fn get_settlement(
+        &self,
+        signature: &Signature,
+    ) -> Result<common::settlement::Settlement, error::ClientError> {
+        // Transactions cannot be fetched at the processed commitment
+        let commitment = match self.rpc.commitment() {
+            commitment if commitment.is_at_least_confirmed() => commitment,
+            _ => CommitmentConfig::confirmed(),
+        };
+        let config = solana_client::rpc_config::RpcTransactionConfig {
+            encoding: Some(solana_transaction_status_client_types::UiTransactionEncoding::Base64),
+            commitment: Some(commitment),
+            max_supported_transaction_version: Some(0),
+        };
+        let transaction = self
+            .rpc
+            .get_transaction_with_config(signature, config)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
 
-    /// Returns the PDA of a user volume accumulator account.
-    ///
-    /// # Arguments
-    /// * `user` - Public key of the user.
-    ///
-    /// # Returns
-    /// PDA of the corresponding user volume accumulator account.
-    pub fn get_user_volume_accumulator_pda(user: &Pubkey) -> Pubkey {
-        let (user_volume_accumulator, _bump) = Pubkey::find_program_address(
-            &[b"user_volume_accumulator", user.as_ref()],
-            &constants::accounts::PUMPFUN,
-        );
-        user_volume_accumulator
+        common::settlement::Settlement::new(*signature, transaction)
     }
 }
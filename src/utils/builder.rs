@@ -0,0 +1,1356 @@
+//! Composable transaction builder for the Pump.fun client
+//!
+//! This module provides `TxBuilder`, which chains token operations and arbitrary
+//! instructions into a single transaction. The builder collects the instructions,
+//! the additional signers and the address lookup tables of the transaction, prepends
+//! the compute budget instructions of the priority fee, and can then build, sign,
+//! simulate or send the transaction. Transactions can use a durable nonce instead of a
+//! recent blockhash, so they can be signed ahead and sent later.
+
+use std::sync::Arc;
+#[cfg(feature = "stream")]
+use std::time::Duration;
+
+#[cfg(feature = "stream")]
+use solana_client::client_error::ClientErrorKind;
+use solana_client::rpc_client::SerializableTransaction;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, hash::Hash, instruction::Instruction,
+    message::AddressLookupTableAccount, pubkey::Pubkey, signature::Signature, signer::Signer,
+};
+use solana_system_interface::instruction::advance_nonce_account;
+
+use super::{
+    confirmation::{ConfirmationStatus, ConfirmationTracker},
+    transaction::{
+        encode_transaction, get_transaction_offline_prepared, get_unsigned_transaction,
+        PumpFunTransaction,
+    },
+    CreateTokenMetadata, TokenMetadataResponse,
+};
+#[cfg(feature = "stream")]
+use crate::common::settlement::Settlement;
+use crate::{
+    common::{
+        simulation::SimulationResult,
+        types::{PriorityFee, PriorityFeeStrategy, TransactionFormat},
+    },
+    constants, error, PumpFun,
+};
+
+/// Number of attempts to fetch a confirmed transaction not yet indexed by the RPC node
+#[cfg(feature = "stream")]
+const SETTLEMENT_ATTEMPTS: u32 = 10;
+
+/// Delay between the attempts to fetch a confirmed transaction
+#[cfg(feature = "stream")]
+const SETTLEMENT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Builder chaining operations into a single transaction
+///
+/// Created with `PumpFun::tx`. Token operations such as `create`, `buy` and `sell` fetch
+/// the accounts they need when they are added, so they are async and return the builder
+/// on success. Instructions, memos, signers and lookup tables are added synchronously.
+///
+/// The compute budget instructions of the priority fee are prepended to the instructions
+/// when the transaction is built, and the client's payer always signs and pays for it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+/// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, signer::Signer};
+/// # use std::sync::Arc;
+/// #
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let payer = Arc::new(Keypair::new());
+/// # let cluster = Cluster::devnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// # let client = PumpFun::new(payer, cluster);
+/// let mint = Keypair::new();
+/// let mint_pubkey = mint.pubkey();
+/// let metadata = CreateTokenMetadata {
+///     name: "My Token".to_string(),
+///     symbol: "MYTKN".to_string(),
+///     description: "A test token created with Pump.fun".to_string(),
+///     file: "path/to/image.png".to_string(),
+///     twitter: None,
+///     telegram: None,
+///     website: None,
+/// };
+///
+/// // Create a token, buy 0.1 SOL worth of it and attach a memo in one transaction
+/// let signature = client
+///     .tx()
+///     .create(mint, metadata)
+///     .await?
+///     .buy(mint_pubkey, sol_to_lamports(0.1), None, Some(500))
+///     .await?
+///     .memo("launch")
+///     .send()
+///     .await?;
+/// println!("Token launched! Signature: {}", signature);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TxBuilder<'a> {
+    client: &'a PumpFun,
+    instructions: Vec<Instruction>,
+    signers: Vec<Arc<dyn Signer + Send + Sync>>,
+    priority_fee: PriorityFee,
+    nonce: Option<Pubkey>,
+    format: TransactionFormat,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl<'a> TxBuilder<'a> {
+    /// Creates an empty transaction builder for a client
+    ///
+    /// The builder uses the priority fee of the cluster configuration until another one
+    /// is set with `priority_fee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client whose RPC connection and payer are used for the transaction
+    pub fn new(client: &'a PumpFun) -> Self {
+        Self {
+            client,
+            instructions: Vec::new(),
+            signers: Vec::new(),
+            priority_fee: client.cluster.priority_fee,
+            nonce: None,
+            format: client.transaction_format,
+            lookup_tables: client.lookup_tables.clone(),
+        }
+    }
+
+    /// Sets the priority fee of the transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    pub fn priority_fee(mut self, priority_fee: Option<PriorityFee>) -> Self {
+        self.priority_fee = priority_fee.unwrap_or(self.client.cluster.priority_fee);
+        self
+    }
+
+    /// Adds the creation of a new token, uploading its metadata to IPFS first
+    ///
+    /// The mint keypair is added to the signers of the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Signer of the new token mint account that will be created, such as a keypair
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata upload to IPFS fails
+    pub async fn create(
+        self,
+        mint: impl Signer + Send + Sync + 'static,
+        metadata: CreateTokenMetadata,
+    ) -> Result<Self, error::ClientError> {
+        let ipfs: TokenMetadataResponse = super::create_token_metadata(metadata)
+            .await
+            .map_err(error::ClientError::UploadMetadataError)?;
+
+        let create_ix = self.client.get_create_instruction(&mint.pubkey(), ipfs);
+        Ok(self.instruction(create_ix).signer(mint))
+    }
+
+    /// Adds a buy of tokens for an amount of SOL
+    ///
+    /// Buys of a token created earlier in the same transaction are quoted against the
+    /// initial bonding curve of the global configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the accounts cannot be fetched or the buy quote calculation fails
+    pub async fn buy(
+        self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Self, error::ClientError> {
+        let buy_ix = self
+            .client
+            .get_buy_instructions(mint, amount_sol, track_volume, slippage_basis_points)
+            .await?;
+        Ok(self.instructions(buy_ix))
+    }
+
+    /// Adds a buy of an exact amount of tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units, capped at the curve's real token reserves
+    /// * `track_volume` - Optional flag to track the buy in the volume accumulators
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the accounts cannot be fetched or the buy quote calculation fails
+    pub async fn buy_exact_tokens(
+        self,
+        mint: Pubkey,
+        amount_token: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Self, error::ClientError> {
+        let buy_ix = self
+            .client
+            .get_buy_exact_tokens_instructions(
+                mint,
+                amount_token,
+                track_volume,
+                slippage_basis_points,
+            )
+            .await?;
+        Ok(self.instructions(buy_ix))
+    }
+
+    /// Adds a sale of tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the accounts cannot be fetched or the sell price calculation fails
+    pub async fn sell(
+        self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Self, error::ClientError> {
+        let sell_ix = self
+            .client
+            .get_sell_instructions(mint, amount_token, slippage_basis_points)
+            .await?;
+        Ok(self.instructions(sell_ix))
+    }
+
+    /// Adds a sale of tokens until an exact amount of SOL is received
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_sol` - Amount of SOL to receive after fees, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the accounts cannot be fetched or the bonding curve cannot pay out
+    /// the requested amount
+    pub async fn sell_for_sol(
+        self,
+        mint: Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Self, error::ClientError> {
+        let sell_ix = self
+            .client
+            .get_sell_for_sol_instructions(mint, amount_sol, slippage_basis_points)
+            .await?;
+        Ok(self.instructions(sell_ix))
+    }
+
+    /// Adds an instruction to the transaction
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Adds several instructions to the transaction, in order
+    pub fn instructions(mut self, instructions: impl IntoIterator<Item = Instruction>) -> Self {
+        self.instructions.extend(instructions);
+        self
+    }
+
+    /// Adds a memo to the transaction with the SPL Memo program
+    ///
+    /// # Arguments
+    ///
+    /// * `memo` - UTF-8 text recorded in the transaction logs
+    pub fn memo(self, memo: impl AsRef<str>) -> Self {
+        let memo_ix = get_memo_instruction(memo.as_ref());
+        self.instruction(memo_ix)
+    }
+
+    /// Adds a signer to the transaction, in addition to the payer
+    ///
+    /// Any signer can be added: keypairs, presigners or remote signers, either owned or
+    /// shared through an `Arc`. Signers already added, and the payer itself, are ignored.
+    pub fn signer(mut self, signer: impl Signer + Send + Sync + 'static) -> Self {
+        let pubkey = signer.pubkey();
+        if pubkey != self.client.payer.pubkey()
+            && !self.signers.iter().any(|added| added.pubkey() == pubkey)
+        {
+            self.signers.push(Arc::new(signer));
+        }
+        self
+    }
+
+    /// Uses a durable nonce instead of a recent blockhash for the transaction
+    ///
+    /// An instruction advancing the nonce, with the payer as nonce authority, is prepended
+    /// to the instructions of the transaction. Transactions signed by `sign`, `send` and
+    /// `submit` use the current value of the nonce, fetched from the network, while
+    /// `sign_with_blockhash` expects that value to be given, so that the transaction can be
+    /// signed offline and sent later.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - Public key of a nonce account whose authority is the payer
+    pub fn durable_nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the format of the transaction, legacy or v0
+    ///
+    /// The builder uses the transaction format of the client until another one is set.
+    pub fn format(mut self, format: TransactionFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Adds address lookup tables used to compile the transaction message, in addition to
+    /// the tables of the client
+    ///
+    /// Only v0 transactions use lookup tables, see `format`.
+    pub fn lookup_tables(
+        mut self,
+        lookup_tables: impl IntoIterator<Item = AddressLookupTableAccount>,
+    ) -> Self {
+        self.lookup_tables.extend(lookup_tables);
+        self
+    }
+
+    /// Builds the instructions of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the nonce advance instruction when a durable nonce is used, the compute
+    /// budget instructions of the priority fee, then the instructions added to the builder
+    pub fn build(&self) -> Vec<Instruction> {
+        self.build_with_priority_fee(&self.priority_fee)
+    }
+
+    /// Signs the transaction with a recent blockhash fetched from the network
+    ///
+    /// The price strategy of the priority fee is resolved for a first attempt. When the
+    /// priority fee estimates the compute unit limit automatically, the transaction is
+    /// simulated first and signed with the estimated limit.
+    ///
+    /// # Returns
+    ///
+    /// Returns a signed transaction in the format of the builder if successful, or a ClientError
+    /// if the operation fails
+    pub async fn sign(&self) -> Result<PumpFunTransaction, error::ClientError> {
+        let recent_blockhash = self.get_latest_blockhash().await?;
+        let instructions = self
+            .get_estimated_instructions(&recent_blockhash, 0)
+            .await?;
+        self.sign_instructions(&instructions, &recent_blockhash)
+    }
+
+    /// Signs the transaction with a given blockhash, without accessing the network
+    ///
+    /// The compute unit limit is not estimated, even when the priority fee estimates it
+    /// automatically: the limit used for simulations is kept. Likewise, only prices known
+    /// without accessing the network are set, see `PriorityFeeStrategy::get_static_price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `recent_blockhash` - Recent blockhash determining the validity window of the transaction,
+    ///   or the value of the durable nonce when one is used
+    ///
+    /// # Returns
+    ///
+    /// Returns a signed transaction in the format of the builder if successful, or a ClientError
+    /// if the operation fails
+    pub fn sign_with_blockhash(
+        &self,
+        recent_blockhash: &Hash,
+    ) -> Result<PumpFunTransaction, error::ClientError> {
+        self.sign_instructions(&self.build(), recent_blockhash)
+    }
+
+    /// Exports the transaction for external signing, with a recent blockhash fetched from the network
+    ///
+    /// The payer does not sign the transaction, which is meant to be signed by an external
+    /// wallet or multisig, for instance with a `NullSigner` of its address as payer of the
+    /// client. Signers added to the builder, such as the mint of a created token, sign it
+    /// partially. The priority fee is resolved as when signing.
+    ///
+    /// A recent blockhash is only valid for about a minute, after which the exported
+    /// transaction can no longer land. Use `durable_nonce` when the external signing may
+    /// take longer.
+    ///
+    /// # Returns
+    ///
+    /// Returns the base64 encoded transaction in the format of the builder if successful, or a
+    /// ClientError if the operation fails
+    pub async fn export(&self) -> Result<String, error::ClientError> {
+        let recent_blockhash = self.get_latest_blockhash().await?;
+        let instructions = self
+            .get_estimated_instructions(&recent_blockhash, 0)
+            .await?;
+        self.export_instructions(&instructions, &recent_blockhash)
+    }
+
+    /// Exports the transaction for external signing with a given blockhash, without accessing the network
+    ///
+    /// As with `sign_with_blockhash`, the compute unit limit is not estimated and only prices
+    /// known without accessing the network are set.
+    ///
+    /// # Arguments
+    ///
+    /// * `recent_blockhash` - Recent blockhash determining the validity window of the transaction,
+    ///   or the value of the durable nonce when one is used
+    ///
+    /// # Returns
+    ///
+    /// Returns the base64 encoded transaction in the format of the builder if successful, or a
+    /// ClientError if the operation fails
+    pub fn export_with_blockhash(
+        &self,
+        recent_blockhash: &Hash,
+    ) -> Result<String, error::ClientError> {
+        self.export_instructions(&self.build(), recent_blockhash)
+    }
+
+    /// Simulates the signed transaction against the current state of the cluster
+    ///
+    /// The transaction is not sent, so no SOL is spent. A transaction failing in the
+    /// simulation is not an error: the failure is reported in the simulation result.
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation result, including the compute units consumed, the decoded
+    /// logs, the emitted events and the program error of a failure, or a ClientError if
+    /// the transaction cannot be signed or simulated
+    pub async fn simulate(&self) -> Result<SimulationResult, error::ClientError> {
+        let recent_blockhash = self.get_latest_blockhash().await?;
+        let priority_fee = self.get_priority_fee(0).await?;
+        let instructions = self.build_with_priority_fee(&priority_fee);
+        let transaction = self.sign_instructions(&instructions, &recent_blockhash)?;
+        self.simulate_transaction(&transaction).await
+    }
+
+    /// Estimates the compute unit limit of the transaction
+    ///
+    /// Simulates the transaction and adds the margin of the priority fee to the compute
+    /// units it consumed. A failed simulation gives no estimate, since the transaction
+    /// stopped before consuming all the compute units it needs.
+    ///
+    /// # Returns
+    ///
+    /// Returns the estimated unit limit, None if the priority fee does not estimate the
+    /// unit limit or the RPC node did not report the consumed compute units, or a
+    /// ClientError if the transaction cannot be signed or simulated, or fails in the
+    /// simulation
+    pub async fn estimate_unit_limit(&self) -> Result<Option<u32>, error::ClientError> {
+        if self.priority_fee.unit_limit_margin_basis_points.is_none() {
+            return Ok(None);
+        }
+
+        let simulation = self.simulate().await?;
+        if let Some(error) = simulation.error {
+            return Err(error::ClientError::SolanaClientError(error.into()));
+        }
+
+        Ok(simulation
+            .units_consumed
+            .and_then(|units_consumed| self.priority_fee.get_estimated_unit_limit(units_consumed)))
+    }
+
+    /// Signs, sends and confirms the transaction
+    ///
+    /// With an escalating price strategy, a transaction that fails to confirm for a reason
+    /// other than a transaction error is tracked until it lands, fails or its blockhash
+    /// expires. Only once the blockhash expired without the transaction being seen by the
+    /// cluster is it signed again with a new blockhash and a higher price, until the
+    /// maximum number of attempts is reached, so that the operations cannot run twice.
+    /// Transactions using a durable nonce expire once the nonce was advanced by another
+    /// transaction, and are then signed again with the new value of the nonce.
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn send(&self) -> Result<Signature, error::ClientError> {
+        let max_attempts = self
+            .priority_fee
+            .unit_price
+            .map_or(1, |strategy| strategy.get_max_attempts());
+
+        let mut attempt = 0;
+        loop {
+            let (recent_blockhash, last_valid_block_height) =
+                self.get_blockhash_with_validity().await?;
+            let instructions = self
+                .get_estimated_instructions(&recent_blockhash, attempt)
+                .await?;
+            let transaction = self.sign_instructions(&instructions, &recent_blockhash)?;
+
+            let err = match self.send_transaction(&transaction).await {
+                Err(error::ClientError::SolanaClientError(err))
+                    if attempt + 1 < max_attempts && err.get_transaction_error().is_none() =>
+                {
+                    err
+                }
+                result => return result,
+            };
+
+            // The transaction may still land, so it is only signed again once expired
+            let tracker = self.track(transaction, last_valid_block_height);
+            match tracker.wait().await? {
+                ConfirmationStatus::Landed { .. } => return Ok(*tracker.signature()),
+                ConfirmationStatus::Failed { error, .. } => {
+                    return Err(error::ClientError::SolanaClientError(error.into()))
+                }
+                ConfirmationStatus::Expired if attempt + 1 < max_attempts => attempt += 1,
+                ConfirmationStatus::Expired => {
+                    return Err(error::ClientError::SolanaClientError(err))
+                }
+            }
+        }
+    }
+
+    /// Signs, sends and confirms the transaction, then gets its settlement report
+    ///
+    /// The transaction is sent as with `send`. As RPC nodes may index a confirmed
+    /// transaction with a short delay, fetching it is retried a few times.
+    ///
+    /// Requires the `stream` feature, which provides the event types of the report.
+    ///
+    /// # Returns
+    ///
+    /// Returns the settlement report of the transaction if successful, or a ClientError if
+    /// the operation fails
+    #[cfg(feature = "stream")]
+    pub async fn send_and_settle(&self) -> Result<Settlement, error::ClientError> {
+        let signature = self.send().await?;
+
+        let mut attempt = 1;
+        loop {
+            match self.client.get_settlement(&signature).await {
+                // The RPC node returns no transaction until it is indexed
+                Err(error::ClientError::SolanaClientError(err))
+                    if attempt < SETTLEMENT_ATTEMPTS
+                        && matches!(err.kind(), ClientErrorKind::SerdeJson(_)) =>
+                {
+                    attempt += 1;
+                    tokio::time::sleep(SETTLEMENT_POLL_INTERVAL).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Signs and sends the transaction without waiting for its confirmation
+    ///
+    /// The price strategy of the priority fee is resolved for a first attempt, and the
+    /// compute unit limit is estimated as when sending. The returned tracker reports
+    /// whether the transaction landed, failed or expired, and rebroadcasts it meanwhile.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tracker of the sent transaction if successful, or a ClientError if the
+    /// transaction cannot be signed or sent. The tracker of a transaction using a durable
+    /// nonce reports it expired once the nonce was advanced by another transaction.
+    pub async fn submit(
+        &self,
+    ) -> Result<ConfirmationTracker<PumpFunTransaction>, error::ClientError> {
+        let (recent_blockhash, last_valid_block_height) =
+            self.get_blockhash_with_validity().await?;
+        let instructions = self
+            .get_estimated_instructions(&recent_blockhash, 0)
+            .await?;
+        let transaction = self.sign_instructions(&instructions, &recent_blockhash)?;
+
+        self.client
+            .rpc
+            .send_transaction(&transaction)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(self.track(transaction, last_valid_block_height))
+    }
+
+    /// Signs the transaction with a given blockhash, then sends and confirms it
+    ///
+    /// # Arguments
+    ///
+    /// * `recent_blockhash` - Recent blockhash determining the validity window of the transaction,
+    ///   or the value of the durable nonce when one is used
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn send_with_blockhash(
+        &self,
+        recent_blockhash: &Hash,
+    ) -> Result<Signature, error::ClientError> {
+        let instructions = self.get_estimated_instructions(recent_blockhash, 0).await?;
+        let transaction = self.sign_instructions(&instructions, recent_blockhash)?;
+        self.send_transaction(&transaction).await
+    }
+
+    /// Builds the instructions of the transaction with the compute unit price resolved for
+    /// an attempt and the estimated compute unit limit
+    ///
+    /// The unit limit is kept when the priority fee does not estimate it, when the RPC
+    /// node does not report the consumed compute units, or when the simulation fails, so
+    /// that the failure is reported when the transaction is sent.
+    async fn get_estimated_instructions(
+        &self,
+        recent_blockhash: &Hash,
+        attempt: u32,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let priority_fee = self.get_priority_fee(attempt).await?;
+        let mut instructions = self.build_with_priority_fee(&priority_fee);
+        if priority_fee.unit_limit_margin_basis_points.is_none() {
+            return Ok(instructions);
+        }
+
+        let transaction = self.sign_instructions(&instructions, recent_blockhash)?;
+        let simulation = self.simulate_transaction(&transaction).await?;
+        if let Some(unit_limit) = simulation
+            .units_consumed
+            .filter(|_| simulation.is_success())
+            .and_then(|units_consumed| self.priority_fee.get_estimated_unit_limit(units_consumed))
+        {
+            set_unit_limit(&mut instructions, unit_limit);
+        }
+
+        Ok(instructions)
+    }
+
+    /// Builds the instructions of the transaction with a priority fee
+    fn build_with_priority_fee(&self, priority_fee: &PriorityFee) -> Vec<Instruction> {
+        let mut instructions: Vec<Instruction> = self
+            .nonce
+            .iter()
+            .map(|nonce| advance_nonce_account(nonce, &self.client.payer.pubkey()))
+            .collect();
+        instructions.extend(PumpFun::get_priority_fee_instructions(priority_fee));
+        instructions.extend(self.instructions.iter().cloned());
+        instructions
+    }
+
+    /// Gets the priority fee with the price strategy resolved for an attempt
+    async fn get_priority_fee(&self, attempt: u32) -> Result<PriorityFee, error::ClientError> {
+        let Some(strategy) = self.priority_fee.unit_price else {
+            return Ok(self.priority_fee);
+        };
+
+        let recent_fees = match strategy {
+            PriorityFeeStrategy::Percentile { .. } => self
+                .client
+                .rpc
+                .get_recent_prioritization_fees(&self.get_writable_accounts())
+                .await
+                .map_err(error::ClientError::SolanaClientError)?
+                .iter()
+                .map(|fee| fee.prioritization_fee)
+                .collect(),
+            _ => Vec::new(),
+        };
+        let price = strategy.get_price(attempt, &recent_fees);
+
+        Ok(self
+            .priority_fee
+            .with_unit_price(PriorityFeeStrategy::Fixed(price)))
+    }
+
+    /// Gets the accounts written by the Pump.fun instructions, other than the signers
+    ///
+    /// These accounts, such as the bonding curve and the fee recipient, scope the recent
+    /// prioritization fees to the transactions competing for the same accounts.
+    fn get_writable_accounts(&self) -> Vec<Pubkey> {
+        let mut accounts = Vec::new();
+        for account in self
+            .instructions
+            .iter()
+            .filter(|instruction| instruction.program_id == constants::accounts::PUMPFUN)
+            .flat_map(|instruction| &instruction.accounts)
+        {
+            if account.is_writable && !account.is_signer && !accounts.contains(&account.pubkey) {
+                accounts.push(account.pubkey);
+            }
+        }
+        accounts
+    }
+
+    /// Encodes a transaction with the given instructions and blockhash, without the payer's signature
+    fn export_instructions(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: &Hash,
+    ) -> Result<String, error::ClientError> {
+        let signers = self.get_signers();
+        let transaction = get_unsigned_transaction(
+            recent_blockhash,
+            &self.client.payer.pubkey(),
+            instructions,
+            Some(&signers),
+            self.format,
+            Some(&self.lookup_tables[..]),
+        )?;
+        encode_transaction(&transaction)
+    }
+
+    /// Signs a transaction with the given instructions and blockhash
+    fn sign_instructions(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: &Hash,
+    ) -> Result<PumpFunTransaction, error::ClientError> {
+        let signers = self.get_signers();
+        get_transaction_offline_prepared(
+            recent_blockhash,
+            self.client.rpc.clone(),
+            self.client.payer.clone(),
+            instructions,
+            Some(&signers),
+            self.format,
+            Some(&self.lookup_tables[..]),
+        )
+    }
+
+    /// Simulates a signed transaction
+    async fn simulate_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> Result<SimulationResult, error::ClientError> {
+        let response = self
+            .client
+            .rpc
+            .simulate_transaction(transaction)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(SimulationResult::new(
+            *transaction.get_signature(),
+            response.value,
+        ))
+    }
+
+    /// Fetches a recent blockhash for the transaction validity window, or the value of
+    /// the durable nonce when one is used
+    async fn get_latest_blockhash(&self) -> Result<Hash, error::ClientError> {
+        if let Some(nonce) = self.nonce {
+            return Ok(self.client.get_nonce_account(&nonce).await?.blockhash());
+        }
+
+        self.client
+            .rpc
+            .get_latest_blockhash()
+            .await
+            .map_err(error::ClientError::SolanaClientError)
+    }
+
+    /// Fetches a recent blockhash with the last block height at which it is valid, or the
+    /// value of the durable nonce when one is used
+    ///
+    /// A durable nonce has no last valid block height, so `u64::MAX` is returned with it:
+    /// its expiry is tracked with the nonce account instead, see `track`.
+    async fn get_blockhash_with_validity(&self) -> Result<(Hash, u64), error::ClientError> {
+        if let Some(nonce) = self.nonce {
+            let nonce_account = self.client.get_nonce_account(&nonce).await?;
+            return Ok((nonce_account.blockhash(), u64::MAX));
+        }
+
+        self.client
+            .rpc
+            .get_latest_blockhash_with_commitment(self.client.rpc.commitment())
+            .await
+            .map_err(error::ClientError::SolanaClientError)
+    }
+
+    /// Creates a tracker of a sent transaction, expiring with its durable nonce when one is used
+    fn track(
+        &self,
+        transaction: PumpFunTransaction,
+        last_valid_block_height: u64,
+    ) -> ConfirmationTracker<PumpFunTransaction> {
+        let tracker = ConfirmationTracker::new(
+            self.client.rpc.clone(),
+            transaction,
+            last_valid_block_height,
+            self.client.rpc.commitment(),
+        );
+        match self.nonce {
+            Some(nonce) => tracker.with_durable_nonce(nonce),
+            None => tracker,
+        }
+    }
+
+    /// Sends and confirms a signed transaction
+    async fn send_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> Result<Signature, error::ClientError> {
+        self.client
+            .rpc
+            .send_and_confirm_transaction(transaction)
+            .await
+            .map_err(error::ClientError::SolanaClientError)
+    }
+
+    /// Gets the additional signers of the transaction
+    fn get_signers(&self) -> Vec<&dyn Signer> {
+        self.signers
+            .iter()
+            .map(|signer| signer.as_ref() as &dyn Signer)
+            .collect()
+    }
+}
+
+/// Rewrites the compute unit limit instruction of the priority fee with a new limit
+fn set_unit_limit(instructions: &mut [Instruction], unit_limit: u32) {
+    let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(unit_limit);
+    if let Some(instruction) = instructions.iter_mut().find(|instruction| {
+        instruction.program_id == limit_ix.program_id
+            && instruction.data.first() == limit_ix.data.first()
+    }) {
+        *instruction = limit_ix;
+    }
+}
+
+/// Creates an SPL Memo instruction without signers
+fn get_memo_instruction(memo: &str) -> Instruction {
+    Instruction {
+        program_id: constants::accounts::MEMO_PROGRAM,
+        accounts: Vec::new(),
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::types::Cluster, instructions, utils::transaction::decode_transaction};
+    use base64::Engine;
+    use solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::RpcClientConfig,
+        rpc_request::RpcRequest,
+        rpc_sender::{RpcSender, RpcTransportStats},
+    };
+    use solana_nonce::{
+        state::{DurableNonce, State},
+        versions::Versions,
+    };
+    use solana_rpc_client::mock_sender::MockSender;
+    use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+
+    fn get_client(priority_fee: PriorityFee) -> PumpFun {
+        let cluster = Cluster::localnet(CommitmentConfig::confirmed(), priority_fee);
+        PumpFun::new(Arc::new(Keypair::new()), cluster)
+    }
+
+    #[test]
+    fn test_build() {
+        let priority_fee = PriorityFee::new(Some(200_000), Some(1_000));
+        let client = get_client(priority_fee);
+        let instruction = instructions::collect_creator_fee(&client.payer.pubkey());
+
+        let instructions = client
+            .tx()
+            .instruction(instruction.clone())
+            .memo("hello")
+            .build();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(
+            instructions[..2],
+            PumpFun::get_priority_fee_instructions(&priority_fee)
+        );
+        assert_eq!(instructions[2], instruction);
+        assert_eq!(
+            instructions[3].program_id,
+            constants::accounts::MEMO_PROGRAM
+        );
+        assert_eq!(instructions[3].data, b"hello");
+
+        // Priority fee overridden for the transaction only
+        let instructions = client
+            .tx()
+            .priority_fee(Some(PriorityFee::default()))
+            .instructions(vec![instruction.clone(), instruction])
+            .build();
+        assert_eq!(instructions.len(), 2);
+    }
+
+    #[test]
+    fn test_sign_with_blockhash() {
+        let client = get_client(PriorityFee::default());
+        let mint = Arc::new(Keypair::new());
+        let blockhash = Hash::new_unique();
+
+        let transaction = client
+            .tx()
+            .instruction(instructions::create(
+                &client.payer.pubkey(),
+                &mint.pubkey(),
+                instructions::Create {
+                    name: "Token".to_string(),
+                    symbol: "TKN".to_string(),
+                    uri: "https://example.com".to_string(),
+                    creator: client.payer.pubkey(),
+                },
+            ))
+            .signer(mint.clone())
+            .signer(mint.clone())
+            .signer(client.payer.clone())
+            .sign_with_blockhash(&blockhash)
+            .unwrap();
+
+        assert_eq!(transaction.get_recent_blockhash(), &blockhash);
+        assert_ne!(transaction.get_signature(), &Signature::default());
+        assert_eq!(transaction.format(), TransactionFormat::default());
+
+        // Format overridden for the transaction only
+        let transaction = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .format(TransactionFormat::V0)
+            .sign_with_blockhash(&blockhash)
+            .unwrap();
+        assert_eq!(transaction.format(), TransactionFormat::V0);
+    }
+
+    #[test]
+    fn test_durable_nonce() {
+        let priority_fee = PriorityFee::new(Some(200_000), Some(1_000));
+        let client = get_client(priority_fee);
+        let nonce = Pubkey::new_unique();
+        let nonce_blockhash = Hash::new_unique();
+        let builder = client
+            .tx()
+            .durable_nonce(nonce)
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()));
+
+        // The nonce is advanced first, before the compute budget instructions
+        let instructions = builder.build();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(
+            instructions[0],
+            advance_nonce_account(&nonce, &client.payer.pubkey())
+        );
+        assert_eq!(
+            instructions[1..3],
+            PumpFun::get_priority_fee_instructions(&priority_fee)
+        );
+
+        let transaction = builder.sign_with_blockhash(&nonce_blockhash).unwrap();
+        assert_eq!(transaction.get_recent_blockhash(), &nonce_blockhash);
+        assert!(transaction.uses_durable_nonce());
+
+        // Exported transactions use the nonce too, so they do not expire before approval
+        let exported = builder.export_with_blockhash(&nonce_blockhash).unwrap();
+        let transaction = decode_transaction(&exported).unwrap();
+        assert_eq!(transaction.get_recent_blockhash(), &nonce_blockhash);
+        assert!(transaction.uses_durable_nonce());
+    }
+
+    #[test]
+    fn test_auto_unit_limit() {
+        let priority_fee = PriorityFee::new(None, Some(1_000)).with_auto_unit_limit(1_000);
+        assert_eq!(priority_fee.get_estimated_unit_limit(50_000), Some(55_000));
+        assert_eq!(priority_fee.get_estimated_unit_limit(50_001), Some(55_002));
+        assert_eq!(
+            priority_fee.get_estimated_unit_limit(1_300_000),
+            Some(PriorityFee::MAX_UNIT_LIMIT)
+        );
+        assert_eq!(
+            PriorityFee::new(Some(200_000), None).get_estimated_unit_limit(50_000),
+            None
+        );
+
+        // Transactions are simulated with the maximum limit, then rewritten with the estimate
+        let client = get_client(priority_fee);
+        let mut instructions = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .build();
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(PriorityFee::MAX_UNIT_LIMIT)
+        );
+
+        set_unit_limit(&mut instructions, 55_000);
+        assert_eq!(
+            instructions[..2],
+            [
+                ComputeBudgetInstruction::set_compute_unit_limit(55_000),
+                ComputeBudgetInstruction::set_compute_unit_price(1_000),
+            ]
+        );
+    }
+
+    /// Transport failing the first signature status query, as a network error would
+    struct FlakySender {
+        inner: MockSender,
+        failed: AtomicBool,
+        sent: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl RpcSender for FlakySender {
+        async fn send(
+            &self,
+            request: RpcRequest,
+            params: serde_json::Value,
+        ) -> solana_client::client_error::Result<serde_json::Value> {
+            match request {
+                RpcRequest::SendTransaction => {
+                    self.sent.fetch_add(1, Ordering::SeqCst);
+                }
+                RpcRequest::GetSignatureStatuses if !self.failed.swap(true, Ordering::SeqCst) => {
+                    return Err(std::io::Error::other("connection reset").into());
+                }
+                _ => {}
+            }
+            self.inner.send(request, params).await
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            self.inner.get_transport_stats()
+        }
+
+        fn url(&self) -> String {
+            self.inner.url()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_estimate_unit_limit() {
+        let get_estimating_client = |error: serde_json::Value| {
+            let mut client =
+                get_client(PriorityFee::new(None, Some(1_000)).with_auto_unit_limit(1_000));
+            let mut mocks = HashMap::new();
+            mocks.insert(
+                RpcRequest::SimulateTransaction,
+                serde_json::json!({
+                    "context": { "slot": 1 },
+                    "value": { "err": error, "logs": [], "unitsConsumed": 50_000 },
+                }),
+            );
+            client.rpc = Arc::new(RpcClient::new_mock_with_mocks(
+                "succeeds".to_string(),
+                mocks,
+            ));
+            client
+        };
+
+        let client = get_estimating_client(serde_json::Value::Null);
+        let builder = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()));
+        assert_eq!(builder.estimate_unit_limit().await.unwrap(), Some(55_000));
+
+        let client = get_estimating_client(serde_json::Value::Null);
+        let builder = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()));
+        let instructions = builder
+            .get_estimated_instructions(&Hash::new_unique(), 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(55_000)
+        );
+
+        // Failed simulations do not consume all the units the transaction needs
+        let error = serde_json::json!({ "InstructionError": [0, { "Custom": 6002 }] });
+        let client = get_estimating_client(error.clone());
+        let builder = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()));
+        let err = builder.estimate_unit_limit().await.unwrap_err();
+        assert!(matches!(
+            err,
+            error::ClientError::SolanaClientError(err) if err.get_transaction_error().is_some()
+        ));
+
+        let client = get_estimating_client(error);
+        let builder = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()));
+        let instructions = builder
+            .get_estimated_instructions(&Hash::new_unique(), 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(PriorityFee::MAX_UNIT_LIMIT)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_error_not_escalated() {
+        let strategy = PriorityFeeStrategy::Escalate {
+            initial_price: 1_000,
+            increase_basis_points: 5_000,
+            max_price: 3_000,
+            max_attempts: 3,
+        };
+        let mut client =
+            get_client(PriorityFee::new(Some(200_000), None).with_unit_price(strategy));
+        let sent = Arc::new(AtomicUsize::new(0));
+        client.rpc = Arc::new(RpcClient::new_sender(
+            FlakySender {
+                inner: MockSender::new("succeeds"),
+                failed: AtomicBool::new(false),
+                sent: sent.clone(),
+            },
+            RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+        ));
+
+        // The transaction landing after a network error is not signed and sent again
+        let signature = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .format(TransactionFormat::Legacy)
+            .send()
+            .await
+            .unwrap();
+        assert_ne!(signature, Signature::default());
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+    }
+
+    /// Sender whose durable nonce is advanced by another transaction after each new
+    /// transaction sent, the first one never being seen by the cluster
+    struct NonceSender {
+        inner: MockSender,
+        failed: AtomicBool,
+        signatures: Arc<Mutex<Vec<serde_json::Value>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RpcSender for NonceSender {
+        async fn send(
+            &self,
+            request: RpcRequest,
+            params: serde_json::Value,
+        ) -> solana_client::client_error::Result<serde_json::Value> {
+            match request {
+                RpcRequest::SendTransaction => {
+                    let signature = self.inner.send(request, params).await?;
+                    let mut signatures = self.signatures.lock().unwrap();
+                    if !signatures.contains(&signature) {
+                        signatures.push(signature.clone());
+                    }
+                    return Ok(signature);
+                }
+                RpcRequest::GetAccountInfo => {
+                    let advanced = self.signatures.lock().unwrap().len() as u8;
+                    let state = Versions::new(State::new_initialized(
+                        &Pubkey::default(),
+                        DurableNonce::from_blockhash(&Hash::new_from_array([advanced; 32])),
+                        5_000,
+                    ));
+                    let data = bincode::serialize(&state).unwrap();
+                    return Ok(serde_json::json!({
+                        "context": { "slot": 1 },
+                        "value": {
+                            "lamports": 1_447_680,
+                            "data": [base64::engine::general_purpose::STANDARD.encode(&data), "base64"],
+                            "owner": constants::accounts::SYSTEM_PROGRAM.to_string(),
+                            "executable": false,
+                            "rentEpoch": 0,
+                            "space": data.len(),
+                        },
+                    }));
+                }
+                RpcRequest::GetSignatureStatuses
+                    if self.signatures.lock().unwrap().first() == Some(&params[0][0]) =>
+                {
+                    if !self.failed.swap(true, Ordering::SeqCst) {
+                        return Err(std::io::Error::other("connection reset").into());
+                    }
+                    return Ok(serde_json::json!({
+                        "context": { "slot": 1 },
+                        "value": [null],
+                    }));
+                }
+                _ => {}
+            }
+            self.inner.send(request, params).await
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            self.inner.get_transport_stats()
+        }
+
+        fn url(&self) -> String {
+            self.inner.url()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_durable_nonce_advanced() {
+        let strategy = PriorityFeeStrategy::Escalate {
+            initial_price: 1_000,
+            increase_basis_points: 5_000,
+            max_price: 3_000,
+            max_attempts: 2,
+        };
+        let mut client =
+            get_client(PriorityFee::new(Some(200_000), None).with_unit_price(strategy));
+        let signatures = Arc::new(Mutex::new(Vec::new()));
+        client.rpc = Arc::new(RpcClient::new_sender(
+            NonceSender {
+                inner: MockSender::new("succeeds"),
+                failed: AtomicBool::new(false),
+                signatures: signatures.clone(),
+            },
+            RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+        ));
+
+        // The first transaction expired with the nonce, so it is signed again with the new value
+        let signature = client
+            .tx()
+            .durable_nonce(Pubkey::new_unique())
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .format(TransactionFormat::Legacy)
+            .send()
+            .await
+            .unwrap();
+        let signatures = signatures.lock().unwrap();
+        assert_eq!(signatures.len(), 2);
+        assert_eq!(signatures[1], signature.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_submit_durable_nonce_advanced() {
+        let mut client = get_client(PriorityFee::default());
+        client.rpc = Arc::new(RpcClient::new_sender(
+            NonceSender {
+                inner: MockSender::new("succeeds"),
+                failed: AtomicBool::new(true),
+                signatures: Arc::new(Mutex::new(Vec::new())),
+            },
+            RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+        ));
+
+        // The tracker expires once the nonce was advanced by another transaction
+        let tracker = client
+            .tx()
+            .durable_nonce(Pubkey::new_unique())
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .format(TransactionFormat::Legacy)
+            .submit()
+            .await
+            .unwrap();
+        assert_eq!(
+            tracker.poll().await.unwrap(),
+            Some(ConfirmationStatus::Expired)
+        );
+    }
+
+    #[test]
+    fn test_priority_fee_strategy() {
+        let fees = [0, 100, 5_000, 200, 1_000];
+        assert_eq!(PriorityFeeStrategy::Fixed(1_000).get_price(3, &fees), 1_000);
+
+        let percentile = |percentile, max_price| PriorityFeeStrategy::Percentile {
+            percentile,
+            max_price,
+        };
+        assert_eq!(percentile(50, None).get_price(0, &fees), 200);
+        assert_eq!(percentile(75, None).get_price(0, &fees), 1_000);
+        assert_eq!(percentile(100, None).get_price(0, &fees), 5_000);
+        assert_eq!(percentile(100, Some(2_000)).get_price(0, &fees), 2_000);
+        assert_eq!(percentile(0, None).get_price(0, &fees), 0);
+        assert_eq!(percentile(50, None).get_price(0, &[]), 0);
+        assert_eq!(percentile(50, None).get_static_price(), None);
+
+        let escalate = PriorityFeeStrategy::Escalate {
+            initial_price: 1_000,
+            increase_basis_points: 5_000,
+            max_price: 3_000,
+            max_attempts: 5,
+        };
+        let prices: Vec<u64> = (0..5)
+            .map(|attempt| escalate.get_price(attempt, &[]))
+            .collect();
+        assert_eq!(prices, [1_000, 1_500, 2_250, 3_000, 3_000]);
+        assert_eq!(escalate.get_static_price(), Some(1_000));
+        assert_eq!(escalate.get_max_attempts(), 5);
+
+        // Fixed prices keep the numeric format of the configuration
+        let priority_fee = PriorityFee::new(Some(200_000), Some(1_000));
+        let json = serde_json::to_string(&priority_fee).unwrap();
+        assert!(json.contains("\"unit_price\":1000"));
+        let priority_fee: PriorityFee = serde_json::from_str(
+            r#"{"unit_limit":200000,"unit_price":{"percentile":75,"max_price":null}}"#,
+        )
+        .unwrap();
+        assert_eq!(priority_fee.unit_price, Some(percentile(75, None)));
+    }
+
+    #[test]
+    fn test_writable_accounts() {
+        let client = get_client(PriorityFee::default());
+        let mint = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let buy = instructions::buy(
+            &client.payer.pubkey(),
+            &mint,
+            &fee_recipient,
+            &client.payer.pubkey(),
+            instructions::Buy {
+                amount: 1_000,
+                max_sol_cost: 1_000,
+                track_volume: None,
+            },
+        );
+
+        let accounts = client
+            .tx()
+            .instruction(buy.clone())
+            .instruction(buy)
+            .memo("ignored")
+            .get_writable_accounts();
+        assert!(accounts.contains(&fee_recipient));
+        assert!(accounts.contains(&PumpFun::get_bonding_curve_pda(&mint).unwrap()));
+        assert!(!accounts.contains(&client.payer.pubkey()));
+        assert!(!accounts.contains(&mint));
+        assert_eq!(
+            accounts.len(),
+            accounts
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+    }
+}
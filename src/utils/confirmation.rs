@@ -0,0 +1,417 @@
+//! Tracking of sent transactions until they land or expire
+//!
+//! This module provides `ConfirmationTracker`, returned when a transaction is sent
+//! without waiting for its confirmation. The tracker polls the signature status of the
+//! transaction, rebroadcasts it while it has not been seen by the cluster, and reports
+//! whether it landed, failed or expired once its blockhash is no longer valid, or once
+//! its durable nonce was advanced.
+//!
+//! Trackers are independent from each other, so several transactions can be sent and
+//! tracked concurrently.
+
+use std::{sync::Arc, time::Duration};
+
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, nonce_utils::nonblocking as nonce_utils,
+    rpc_client::SerializableTransaction, rpc_config::RpcSendTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature,
+    transaction::TransactionError,
+};
+use tokio::time::{sleep, Instant};
+
+use crate::error;
+
+/// Final status of a tracked transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmationStatus {
+    /// The transaction landed and succeeded at the tracked commitment
+    Landed {
+        /// Slot in which the transaction was processed
+        slot: u64,
+    },
+    /// The transaction landed at the tracked commitment but failed
+    Failed {
+        /// Slot in which the transaction was processed
+        slot: u64,
+        /// Error of the transaction
+        error: TransactionError,
+    },
+    /// The blockhash of the transaction expired, or its durable nonce was advanced by
+    /// another transaction, before the transaction landed
+    Expired,
+}
+
+/// Tracker of a sent transaction
+///
+/// Created by `TxBuilder::submit` once the transaction is sent, or with `new` for
+/// transactions sent by other means.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::confirmation::ConfirmationStatus};
+/// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+/// # use std::sync::Arc;
+/// #
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let payer = Arc::new(Keypair::new());
+/// # let cluster = Cluster::devnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// # let client = PumpFun::new(payer, cluster);
+/// let first_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+/// let second_mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+///
+/// // Fire two orders without waiting for their confirmation
+/// let first = client.tx().buy(first_mint, sol_to_lamports(0.1), None, None).await?.submit().await?;
+/// let second = client.tx().buy(second_mint, sol_to_lamports(0.1), None, None).await?.submit().await?;
+/// println!("Sent {} and {}", first.signature(), second.signature());
+///
+/// // Track both orders concurrently
+/// let (first, second) = tokio::join!(first.wait(), second.wait());
+/// for status in [first?, second?] {
+///     match status {
+///         ConfirmationStatus::Landed { slot } => println!("Landed in slot {}", slot),
+///         ConfirmationStatus::Failed { error, .. } => println!("Failed: {}", error),
+///         ConfirmationStatus::Expired => println!("Expired"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConfirmationTracker<T> {
+    rpc: Arc<RpcClient>,
+    transaction: T,
+    last_valid_block_height: u64,
+    nonce: Option<Pubkey>,
+    commitment: CommitmentConfig,
+    poll_interval: Duration,
+    rebroadcast_interval: Duration,
+}
+
+impl<T: SerializableTransaction> ConfirmationTracker<T> {
+    /// Default interval between two polls of the signature status
+    pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Default interval between two rebroadcasts of the transaction
+    pub const DEFAULT_REBROADCAST_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Creates a tracker for a sent transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - RPC client used to poll the signature status and rebroadcast the transaction
+    /// * `transaction` - Signed transaction that was sent
+    /// * `last_valid_block_height` - Last block height at which the blockhash of the transaction is valid
+    /// * `commitment` - Commitment level at which the transaction is considered landed
+    pub fn new(
+        rpc: Arc<RpcClient>,
+        transaction: T,
+        last_valid_block_height: u64,
+        commitment: CommitmentConfig,
+    ) -> Self {
+        Self {
+            rpc,
+            transaction,
+            last_valid_block_height,
+            nonce: None,
+            commitment,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            rebroadcast_interval: Self::DEFAULT_REBROADCAST_INTERVAL,
+        }
+    }
+
+    /// Tracks a transaction using a durable nonce instead of a recent blockhash
+    ///
+    /// The transaction expires once the value of the nonce differs from its blockhash,
+    /// since it can no longer land then. The last valid block height is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - Public key of the nonce account advanced by the transaction
+    pub fn with_durable_nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the interval between two polls of the signature status
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the interval between two rebroadcasts of the transaction
+    pub fn with_rebroadcast_interval(mut self, rebroadcast_interval: Duration) -> Self {
+        self.rebroadcast_interval = rebroadcast_interval;
+        self
+    }
+
+    /// Gets the signature of the tracked transaction
+    pub fn signature(&self) -> &Signature {
+        self.transaction.get_signature()
+    }
+
+    /// Gets the signed transaction being tracked
+    pub fn transaction(&self) -> &T {
+        &self.transaction
+    }
+
+    /// Gets the last block height at which the blockhash of the transaction is valid
+    pub fn last_valid_block_height(&self) -> u64 {
+        self.last_valid_block_height
+    }
+
+    /// Polls the status of the transaction once
+    ///
+    /// # Returns
+    ///
+    /// Returns the final status of the transaction, None if it is still pending, or a
+    /// ClientError if the status, block height or nonce account cannot be fetched
+    pub async fn poll(&self) -> Result<Option<ConfirmationStatus>, error::ClientError> {
+        // The expiry is checked first, so that a transaction landing in the last valid
+        // block, or advancing its own nonce, before the status is fetched is not
+        // reported as expired
+        let expired = self.is_expired().await?;
+        let status = self
+            .rpc
+            .get_signature_statuses(&[*self.signature()])
+            .await?
+            .value
+            .into_iter()
+            .next()
+            .flatten();
+
+        match status {
+            Some(status) if status.satisfies_commitment(self.commitment) => {
+                Ok(Some(match status.err {
+                    Some(error) => ConfirmationStatus::Failed {
+                        slot: status.slot,
+                        error,
+                    },
+                    None => ConfirmationStatus::Landed { slot: status.slot },
+                }))
+            }
+            // Processed transactions cannot expire anymore
+            Some(_) => Ok(None),
+            None => Ok(expired.then_some(ConfirmationStatus::Expired)),
+        }
+    }
+
+    /// Checks whether the transaction can no longer land if it has not landed yet
+    async fn is_expired(&self) -> Result<bool, error::ClientError> {
+        let Some(nonce) = self.nonce else {
+            let block_height = self
+                .rpc
+                .get_block_height_with_commitment(self.commitment)
+                .await?;
+            return Ok(block_height > self.last_valid_block_height);
+        };
+
+        let account = nonce_utils::get_account_with_commitment(&self.rpc, &nonce, self.commitment)
+            .await
+            .map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to get nonce account: {}", err))
+            })?;
+        let nonce_account = nonce_utils::data_from_account(&account).map_err(|err| {
+            error::ClientError::OtherError(format!("Invalid nonce account: {}", err))
+        })?;
+        Ok(nonce_account.blockhash() != *self.transaction.get_recent_blockhash())
+    }
+
+    /// Waits until the transaction landed, failed or expired
+    ///
+    /// The signature status is polled at the poll interval, and the transaction is
+    /// rebroadcast at the rebroadcast interval while its blockhash is valid. Errors of
+    /// rebroadcasts are ignored, since the transaction may already have been processed.
+    ///
+    /// # Returns
+    ///
+    /// Returns the final status of the transaction, or a ClientError if the status or
+    /// block height cannot be fetched
+    pub async fn wait(&self) -> Result<ConfirmationStatus, error::ClientError> {
+        let mut last_broadcast = Instant::now();
+        loop {
+            if let Some(status) = self.poll().await? {
+                return Ok(status);
+            }
+
+            if last_broadcast.elapsed() >= self.rebroadcast_interval {
+                let _ = self.rebroadcast().await;
+                last_broadcast = Instant::now();
+            }
+
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Sends the transaction again, skipping the preflight checks
+    ///
+    /// # Returns
+    ///
+    /// Returns the signature of the transaction, or a ClientError if the transaction
+    /// cannot be sent
+    pub async fn rebroadcast(&self) -> Result<Signature, error::ClientError> {
+        let signature = self
+            .rpc
+            .send_transaction_with_config(
+                &self.transaction,
+                RpcSendTransactionConfig {
+                    skip_preflight: true,
+                    max_retries: Some(0),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants;
+    use base64::Engine;
+    use solana_client::rpc_request::RpcRequest;
+    use solana_nonce::{
+        state::{DurableNonce, State},
+        versions::Versions,
+    };
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::Transaction,
+    };
+    use std::collections::HashMap;
+
+    fn get_transaction(recent_blockhash: Hash) -> Transaction {
+        let payer = Keypair::new();
+        Transaction::new_signed_with_payer(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                vec![AccountMeta::new(payer.pubkey(), true)],
+            )],
+            Some(&payer.pubkey()),
+            &[&payer],
+            recent_blockhash,
+        )
+    }
+
+    fn get_tracker(rpc: RpcClient) -> ConfirmationTracker<Transaction> {
+        ConfirmationTracker::new(
+            Arc::new(rpc),
+            get_transaction(Hash::new_unique()),
+            1_500,
+            CommitmentConfig::confirmed(),
+        )
+        .with_poll_interval(Duration::from_millis(1))
+        .with_rebroadcast_interval(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_landed() {
+        let tracker = get_tracker(RpcClient::new_mock("succeeds".to_string()));
+        assert_eq!(
+            tracker.wait().await.unwrap(),
+            ConfirmationStatus::Landed { slot: 1 }
+        );
+
+        // Landed in the last valid block, with the blockhash expired by the time of the poll
+        let mut mocks = HashMap::new();
+        mocks.insert(RpcRequest::GetBlockHeight, serde_json::json!(1_501));
+        let tracker = get_tracker(RpcClient::new_mock_with_mocks(
+            "succeeds".to_string(),
+            mocks,
+        ));
+        assert_eq!(
+            tracker.poll().await.unwrap(),
+            Some(ConfirmationStatus::Landed { slot: 1 })
+        );
+
+        let tracker = get_tracker(RpcClient::new_mock("instruction_error".to_string()));
+        assert_eq!(
+            tracker.wait().await.unwrap(),
+            ConfirmationStatus::Failed {
+                slot: 1,
+                error: TransactionError::InstructionError(
+                    0,
+                    InstructionError::UninitializedAccount
+                ),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_pending_and_expired() {
+        // The mock reports a block height of 1234, below the last valid block height
+        let tracker = get_tracker(RpcClient::new_mock("sig_not_found".to_string()));
+        assert_eq!(tracker.poll().await.unwrap(), None);
+        assert_eq!(tracker.rebroadcast().await.unwrap(), *tracker.signature());
+
+        let mut mocks = HashMap::new();
+        mocks.insert(RpcRequest::GetBlockHeight, serde_json::json!(1_501));
+        let tracker = get_tracker(RpcClient::new_mock_with_mocks(
+            "sig_not_found".to_string(),
+            mocks,
+        ));
+        assert_eq!(tracker.wait().await.unwrap(), ConfirmationStatus::Expired);
+    }
+
+    /// Builds the getAccountInfo response of a nonce account holding a durable nonce
+    fn get_nonce_account_response(durable_nonce: DurableNonce) -> serde_json::Value {
+        let state = Versions::new(State::new_initialized(
+            &Pubkey::new_unique(),
+            durable_nonce,
+            5_000,
+        ));
+        let data = bincode::serialize(&state).unwrap();
+        serde_json::json!({
+            "context": { "slot": 1 },
+            "value": {
+                "lamports": 1_447_680,
+                "data": [base64::engine::general_purpose::STANDARD.encode(&data), "base64"],
+                "owner": constants::accounts::SYSTEM_PROGRAM.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": data.len(),
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_durable_nonce() {
+        let nonce = Pubkey::new_unique();
+        let durable_nonce = DurableNonce::from_blockhash(&Hash::new_unique());
+        let get_nonce_tracker = |nonce_value: DurableNonce| {
+            // The block height is past the last valid block height, which is ignored
+            let mut mocks = HashMap::new();
+            mocks.insert(RpcRequest::GetBlockHeight, serde_json::json!(1_501));
+            mocks.insert(
+                RpcRequest::GetAccountInfo,
+                get_nonce_account_response(nonce_value),
+            );
+            ConfirmationTracker::new(
+                Arc::new(RpcClient::new_mock_with_mocks(
+                    "sig_not_found".to_string(),
+                    mocks,
+                )),
+                get_transaction(*durable_nonce.as_hash()),
+                1_500,
+                CommitmentConfig::confirmed(),
+            )
+            .with_durable_nonce(nonce)
+            .with_poll_interval(Duration::from_millis(1))
+        };
+
+        // Pending while the nonce holds the blockhash of the transaction
+        let tracker = get_nonce_tracker(durable_nonce);
+        assert_eq!(tracker.poll().await.unwrap(), None);
+
+        // Expired once the nonce was advanced by another transaction
+        let tracker = get_nonce_tracker(DurableNonce::from_blockhash(&Hash::new_unique()));
+        assert_eq!(tracker.wait().await.unwrap(), ConfirmationStatus::Expired);
+    }
+}
@@ -0,0 +1,538 @@
+//! RPC transport spreading requests over several endpoints
+//!
+//! This module provides `FailoverSender`, the transport of the RPC client when the
+//! cluster configuration has several endpoints. Reads are sent to the healthiest
+//! endpoint first and fail over to the next ones when an endpoint cannot answer.
+//! Transactions are broadcast to every endpoint concurrently, and signature statuses
+//! are queried from every healthy endpoint so that the first confirmation is accepted.
+//!
+//! Endpoints are scored by their response latency and put aside for an increasing
+//! backoff period after each failure, so that a provider outage only costs one failed
+//! attempt until the provider recovers.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind, Result},
+    rpc_custom_error::{
+        JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED, JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    },
+    rpc_request::{RpcError, RpcRequest},
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use solana_rpc_client::http_sender::HttpSender;
+use solana_transaction_status_client_types::{TransactionConfirmationStatus, TransactionStatus};
+use tokio::{sync::mpsc, time::Instant};
+
+/// Backoff of an endpoint after its first consecutive failure, doubled on each failure
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Maximum backoff of a failing endpoint
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Weight of the latest response time in the average latency of an endpoint, in percent
+const LATENCY_WEIGHT_PERCENT: u32 = 20;
+
+/// Rank of a confirmed signature status, see `get_confirmation_rank`
+const CONFIRMED_RANK: u8 = 2;
+
+/// Health of an endpoint, updated with the outcome of each request
+#[derive(Debug, Clone, Copy, Default)]
+struct Health {
+    /// Moving average of the response time, or None before the first response
+    latency: Option<Duration>,
+    /// Number of consecutive failures
+    failures: u32,
+    /// Time until which the endpoint is put aside after a failure
+    retry_at: Option<Instant>,
+}
+
+impl Health {
+    fn is_available(&self, now: Instant) -> bool {
+        self.retry_at.is_none_or(|retry_at| retry_at <= now)
+    }
+}
+
+/// Endpoint of the failover transport
+struct Endpoint {
+    sender: Box<dyn RpcSender + Send + Sync>,
+    health: Mutex<Health>,
+}
+
+impl Endpoint {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let started = Instant::now();
+        let result = self.sender.send(request, params).await;
+
+        let mut health = self.health.lock().unwrap();
+        match &result {
+            Err(err) if is_endpoint_error(err) => {
+                health.failures += 1;
+                let backoff = BASE_BACKOFF.saturating_mul(1 << (health.failures - 1).min(6));
+                health.retry_at = Some(Instant::now() + backoff.min(MAX_BACKOFF));
+            }
+            _ => {
+                let elapsed = started.elapsed();
+                health.latency = Some(health.latency.map_or(elapsed, |latency| {
+                    (latency * (100 - LATENCY_WEIGHT_PERCENT) + elapsed * LATENCY_WEIGHT_PERCENT)
+                        / 100
+                }));
+                health.failures = 0;
+                health.retry_at = None;
+            }
+        }
+
+        result
+    }
+}
+
+/// RPC transport with failover over several endpoints
+///
+/// Created by `PumpFun::new` when the cluster configuration has fallback endpoints, or
+/// with `new` to build an RPC client over several endpoints.
+///
+/// Errors answered by an endpoint about the request itself, such as a failed preflight
+/// simulation, are returned as is. Only the errors showing that an endpoint cannot
+/// answer, such as connection failures, timeouts, rate limits or unhealthy nodes, make
+/// the request fail over to the next endpoint.
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::utils::failover::FailoverSender;
+/// use solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::RpcClientConfig};
+/// use solana_sdk::commitment_config::CommitmentConfig;
+///
+/// let sender = FailoverSender::new(vec![
+///     "https://api.mainnet-beta.solana.com".to_string(),
+///     "https://solana-rpc.publicnode.com".to_string(),
+/// ]);
+/// let rpc = RpcClient::new_sender(
+///     sender,
+///     RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+/// );
+/// ```
+pub struct FailoverSender {
+    endpoints: Vec<Arc<Endpoint>>,
+}
+
+impl FailoverSender {
+    /// Creates a failover transport over HTTP endpoints
+    ///
+    /// # Arguments
+    ///
+    /// * `urls` - HTTP endpoint URLs, in order of preference before any request is scored
+    pub fn new(urls: impl IntoIterator<Item = String>) -> Self {
+        Self::new_with_senders(
+            urls.into_iter()
+                .map(|url| Box::new(HttpSender::new(url)) as Box<dyn RpcSender + Send + Sync>)
+                .collect(),
+        )
+    }
+
+    /// Creates a failover transport over custom transports
+    ///
+    /// # Arguments
+    ///
+    /// * `senders` - Transports of the endpoints, in order of preference before any
+    ///   request is scored
+    ///
+    /// # Panics
+    ///
+    /// Panics if no transport is given
+    pub fn new_with_senders(senders: Vec<Box<dyn RpcSender + Send + Sync>>) -> Self {
+        assert!(!senders.is_empty(), "FailoverSender requires an endpoint");
+
+        Self {
+            endpoints: senders
+                .into_iter()
+                .map(|sender| {
+                    Arc::new(Endpoint {
+                        sender,
+                        health: Mutex::new(Health::default()),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the endpoints ordered by health
+    ///
+    /// Available endpoints come first, from the fastest to the slowest, with the
+    /// endpoints that never answered first so that they get scored. Endpoints put aside
+    /// after a failure come last, from the one retried the soonest.
+    fn get_ranked_endpoints(&self) -> Vec<Arc<Endpoint>> {
+        let now = Instant::now();
+        let mut ranked: Vec<(Health, Arc<Endpoint>)> = self
+            .endpoints
+            .iter()
+            .map(|endpoint| (*endpoint.health.lock().unwrap(), endpoint.clone()))
+            .collect();
+        ranked.sort_by_key(|(health, _)| match health.is_available(now) {
+            true => (false, health.latency, None),
+            false => (true, None, health.retry_at),
+        });
+
+        ranked.into_iter().map(|(_, endpoint)| endpoint).collect()
+    }
+
+    /// Sends a request to the endpoints in order of health until one answers
+    async fn send_with_failover(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let mut last_error = None;
+        for endpoint in self.get_ranked_endpoints() {
+            match endpoint.send(request, params.clone()).await {
+                Err(err) if is_endpoint_error(&err) => last_error = Some(err),
+                result => return result,
+            }
+        }
+
+        Err(last_error.expect("FailoverSender has at least one endpoint"))
+    }
+
+    /// Sends a transaction to every endpoint concurrently
+    ///
+    /// Returns the first successful answer, while the transaction keeps being sent to
+    /// the other endpoints. If every endpoint fails, the error answered about the
+    /// transaction itself is preferred over the errors of endpoints that cannot answer.
+    async fn broadcast(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let mut results = self.spawn_all(&self.endpoints, request, params);
+
+        let mut error: Option<ClientError> = None;
+        while let Some(result) = results.recv().await {
+            match result {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    if error.as_ref().is_none_or(is_endpoint_error) {
+                        error = Some(err);
+                    }
+                }
+            }
+        }
+
+        Err(error.expect("FailoverSender has at least one endpoint"))
+    }
+
+    /// Queries the statuses of signatures from every available endpoint concurrently
+    ///
+    /// The most advanced status reported by any endpoint is kept for each signature, and
+    /// the statuses are returned as soon as every signature is confirmed, so that the
+    /// first endpoint confirming a transaction is accepted. Otherwise every endpoint is
+    /// waited for, so that a lagging endpoint cannot hide a confirmation. Endpoints put
+    /// aside after a failure are only queried if none is available.
+    async fn get_signature_statuses(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let now = Instant::now();
+        let available: Vec<Arc<Endpoint>> = self
+            .endpoints
+            .iter()
+            .filter(|endpoint| endpoint.health.lock().unwrap().is_available(now))
+            .cloned()
+            .collect();
+        let endpoints = match available.is_empty() {
+            true => &self.endpoints,
+            false => &available,
+        };
+        let mut results = self.spawn_all(endpoints, request, params);
+
+        let mut merged: Option<serde_json::Value> = None;
+        let mut error = None;
+        while let Some(result) = results.recv().await {
+            match (result, &mut merged) {
+                (Ok(value), None) => merged = Some(value),
+                (Ok(value), Some(merged)) => merge_statuses(merged, &value),
+                (Err(err), _) => error = Some(err),
+            }
+            if merged.as_ref().is_some_and(is_all_confirmed) {
+                break;
+            }
+        }
+
+        merged.ok_or_else(|| error.expect("FailoverSender has at least one endpoint"))
+    }
+
+    /// Sends a request to several endpoints concurrently and returns their answers as
+    /// they arrive
+    ///
+    /// Requests keep running once the receiver is dropped, so that every endpoint gets
+    /// the request and its health is updated.
+    fn spawn_all(
+        &self,
+        endpoints: &[Arc<Endpoint>],
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> mpsc::Receiver<Result<serde_json::Value>> {
+        let (tx, rx) = mpsc::channel(endpoints.len());
+        for endpoint in endpoints {
+            let endpoint = endpoint.clone();
+            let params = params.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let _ = tx.send(endpoint.send(request, params).await).await;
+            });
+        }
+
+        rx
+    }
+}
+
+#[async_trait]
+impl RpcSender for FailoverSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        match request {
+            RpcRequest::SendTransaction => self.broadcast(request, params).await,
+            RpcRequest::GetSignatureStatuses => self.get_signature_statuses(request, params).await,
+            _ => self.send_with_failover(request, params).await,
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.sender.get_transport_stats())
+            .fold(RpcTransportStats::default(), |total, stats| {
+                RpcTransportStats {
+                    request_count: total.request_count + stats.request_count,
+                    elapsed_time: total.elapsed_time + stats.elapsed_time,
+                    rate_limited_time: total.rate_limited_time + stats.rate_limited_time,
+                }
+            })
+    }
+
+    fn url(&self) -> String {
+        self.endpoints[0].sender.url()
+    }
+}
+
+/// Returns true if the error shows that the endpoint cannot answer, rather than an
+/// answer about the request
+fn is_endpoint_error(err: &ClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::Io(_)
+        | ClientErrorKind::Reqwest(_)
+        | ClientErrorKind::Middleware(_)
+        | ClientErrorKind::RpcError(RpcError::RpcRequestError(_)) => true,
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => matches!(
+            *code,
+            JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+                | JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED
+        ),
+        _ => false,
+    }
+}
+
+/// Replaces the statuses of a `getSignatureStatuses` answer with the more advanced
+/// statuses of another answer
+fn merge_statuses(merged: &mut serde_json::Value, other: &serde_json::Value) {
+    let (Some(statuses), Some(others)) =
+        (merged["value"].as_array_mut(), other["value"].as_array())
+    else {
+        return;
+    };
+
+    for (status, other) in statuses.iter_mut().zip(others) {
+        if get_confirmation_rank(other) > get_confirmation_rank(status) {
+            *status = other.clone();
+        }
+    }
+}
+
+/// Returns true if every signature of a `getSignatureStatuses` answer is at least confirmed
+fn is_all_confirmed(value: &serde_json::Value) -> bool {
+    value["value"].as_array().is_some_and(|statuses| {
+        statuses
+            .iter()
+            .all(|status| get_confirmation_rank(status) >= CONFIRMED_RANK)
+    })
+}
+
+/// Ranks a status of a `getSignatureStatuses` answer, a missing status ranking lowest
+/// and a finalized one highest
+fn get_confirmation_rank(status: &serde_json::Value) -> u8 {
+    let Ok(status) = serde_json::from_value::<TransactionStatus>(status.clone()) else {
+        return 0;
+    };
+
+    match status.confirmation_status() {
+        TransactionConfirmationStatus::Processed => 1,
+        TransactionConfirmationStatus::Confirmed => CONFIRMED_RANK,
+        TransactionConfirmationStatus::Finalized => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Transport answering every request with the same result
+    struct TestSender {
+        url: String,
+        answer: Option<serde_json::Value>,
+        delay: Duration,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl TestSender {
+        fn boxed(
+            url: &str,
+            answer: Option<serde_json::Value>,
+            delay: Duration,
+        ) -> (Box<dyn RpcSender + Send + Sync>, Arc<AtomicUsize>) {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let sender = Self {
+                url: url.to_string(),
+                answer,
+                delay,
+                calls: calls.clone(),
+            };
+            (Box::new(sender), calls)
+        }
+    }
+
+    #[async_trait]
+    impl RpcSender for TestSender {
+        async fn send(
+            &self,
+            _request: RpcRequest,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.answer.clone().ok_or_else(|| {
+                ClientErrorKind::Io(std::io::Error::other("connection refused")).into()
+            })
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            self.url.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failover() {
+        let (down, down_calls) = TestSender::boxed("down", None, Duration::ZERO);
+        let (up, up_calls) = TestSender::boxed("up", Some(json!(1234)), Duration::ZERO);
+        let sender = FailoverSender::new_with_senders(vec![down, up]);
+        assert_eq!(sender.url(), "down");
+
+        // The first endpoint fails over to the second one, then is put aside
+        for _ in 0..3 {
+            let value = sender.send(RpcRequest::GetBlockHeight, json!([])).await;
+            assert_eq!(value.unwrap(), json!(1234));
+        }
+        assert_eq!(down_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(up_calls.load(Ordering::SeqCst), 3);
+
+        // Every endpoint failing returns the last error
+        let (first, _) = TestSender::boxed("first", None, Duration::ZERO);
+        let (second, _) = TestSender::boxed("second", None, Duration::ZERO);
+        let sender = FailoverSender::new_with_senders(vec![first, second]);
+        let err = sender
+            .send(RpcRequest::GetBlockHeight, json!([]))
+            .await
+            .unwrap_err();
+        assert!(is_endpoint_error(&err));
+    }
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let signature = json!("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW");
+        let (down, down_calls) = TestSender::boxed("down", None, Duration::ZERO);
+        let (slow, slow_calls) =
+            TestSender::boxed("slow", Some(signature.clone()), Duration::from_secs(60));
+        let (fast, fast_calls) = TestSender::boxed("fast", Some(signature.clone()), Duration::ZERO);
+        let sender = FailoverSender::new_with_senders(vec![down, slow, fast]);
+
+        // The fastest endpoint answers without waiting for the slow one
+        let value = tokio::time::timeout(
+            Duration::from_secs(5),
+            sender.send(RpcRequest::SendTransaction, json!([])),
+        )
+        .await
+        .unwrap();
+        assert_eq!(value.unwrap(), signature);
+        assert_eq!(down_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(slow_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(fast_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_signature_statuses() {
+        let status = json!({
+            "slot": 1,
+            "confirmations": null,
+            "err": null,
+            "status": {"Ok": null},
+            "confirmationStatus": "confirmed"
+        });
+        let processed = json!({
+            "slot": 2,
+            "confirmations": 0,
+            "err": null,
+            "status": {"Ok": null},
+            "confirmationStatus": "processed"
+        });
+        let lagging = json!({"context": {"slot": 1}, "value": [null, null]});
+        let processing =
+            json!({"context": {"slot": 2}, "value": [processed.clone(), processed.clone()]});
+        let first = json!({"context": {"slot": 1}, "value": [status.clone(), null]});
+        let second = json!({"context": {"slot": 1}, "value": [processed.clone(), status.clone()]});
+
+        let (lagging, _) = TestSender::boxed("lagging", Some(lagging), Duration::ZERO);
+        let (fast, _) = TestSender::boxed("processing", Some(processing.clone()), Duration::ZERO);
+        let (first, _) = TestSender::boxed("first", Some(first), Duration::from_millis(10));
+        let (second, _) = TestSender::boxed("second", Some(second), Duration::from_millis(20));
+        let (slow, _) = TestSender::boxed("slow", Some(json!(null)), Duration::from_secs(60));
+        let sender = FailoverSender::new_with_senders(vec![lagging, fast, first, second, slow]);
+
+        // Statuses seen by different endpoints are merged, confirmed statuses replacing
+        // processed ones, without waiting for the slow one once every status is confirmed
+        let value = tokio::time::timeout(
+            Duration::from_secs(5),
+            sender.send(RpcRequest::GetSignatureStatuses, json!([])),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(value["value"], json!([status.clone(), status]));
+
+        // Processed statuses are returned once every endpoint answered
+        let lagging = json!({"context": {"slot": 1}, "value": [null, null]});
+        let (lagging, _) = TestSender::boxed("lagging", Some(lagging), Duration::from_millis(10));
+        let (fast, _) = TestSender::boxed("processing", Some(processing), Duration::ZERO);
+        let sender = FailoverSender::new_with_senders(vec![lagging, fast]);
+        let value = sender
+            .send(RpcRequest::GetSignatureStatuses, json!([]))
+            .await
+            .unwrap();
+        assert_eq!(value["value"], json!([processed.clone(), processed]));
+    }
+}
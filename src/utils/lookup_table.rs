@@ -0,0 +1,222 @@
+//! Address lookup tables of the accounts used by Pump.fun trades
+//!
+//! This module lists the accounts worth storing in an address lookup table: the static
+//! accounts shared by every Pump.fun instruction, and the accounts of each trading user.
+//! Transactions compiled against such a table reference these accounts by a one-byte
+//! index instead of their 32-byte address, so that more instructions fit in a transaction.
+//! Only v0 transactions can use lookup tables, see `TransactionFormat`.
+
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_sdk::{account::Account, message::AddressLookupTableAccount, pubkey::Pubkey};
+
+use crate::{accounts::GlobalAccount, constants, error, PumpFun};
+
+/// Maximum number of addresses added by a single extend instruction, so that the
+/// transaction extending a table fits in the transaction size limit
+pub const MAX_ADDRESSES_PER_EXTEND: usize = 30;
+
+/// Gets the static accounts used by Pump.fun instructions
+///
+/// # Arguments
+///
+/// * `global` - Global configuration account, whose fee recipients are included
+///
+/// # Returns
+///
+/// Returns the deduplicated programs, PDAs and fee recipients shared by all users
+pub fn get_static_accounts(global: &GlobalAccount) -> Vec<Pubkey> {
+    let mut accounts = vec![
+        constants::accounts::PUMPFUN,
+        PumpFun::get_global_pda(),
+        PumpFun::get_mint_authority_pda(),
+        constants::accounts::EVENT_AUTHORITY,
+        constants::accounts::FEE_CONFIG,
+        constants::accounts::FEE_CONFIG_PROGRAM,
+        constants::accounts::GLOBAL_VOLUME_ACCUMULATOR,
+        constants::accounts::MPL_TOKEN_METADATA,
+        constants::accounts::SYSTEM_PROGRAM,
+        constants::accounts::TOKEN_PROGRAM,
+        constants::accounts::ASSOCIATED_TOKEN_PROGRAM,
+        constants::accounts::RENT,
+        global.fee_recipient,
+    ];
+    accounts.extend(global.fee_recipients);
+    dedup(accounts)
+}
+
+/// Gets the accounts of a user used by Pump.fun trades, regardless of the token traded
+///
+/// # Arguments
+///
+/// * `user` - Public key of the trading user
+///
+/// # Returns
+///
+/// Returns the user and its volume accumulator
+pub fn get_user_accounts(user: &Pubkey) -> Vec<Pubkey> {
+    vec![*user, PumpFun::get_user_volume_accumulator_pda(user)]
+}
+
+/// Gets the accounts worth storing in a lookup table for Pump.fun trades of some users
+///
+/// # Arguments
+///
+/// * `global` - Global configuration account, whose fee recipients are included
+/// * `users` - Public keys of the trading users
+///
+/// # Returns
+///
+/// Returns the deduplicated static accounts followed by the accounts of each user
+pub fn get_trade_accounts(global: &GlobalAccount, users: &[Pubkey]) -> Vec<Pubkey> {
+    dedup(
+        get_static_accounts(global)
+            .into_iter()
+            .chain(users.iter().flat_map(get_user_accounts)),
+    )
+}
+
+/// Decodes an address lookup table account
+///
+/// # Arguments
+///
+/// * `address` - Public key of the lookup table
+/// * `account` - Account data of the lookup table
+///
+/// # Returns
+///
+/// Returns the lookup table with its addresses, or a ClientError if the account is not
+/// an address lookup table
+pub fn decode_lookup_table(
+    address: Pubkey,
+    account: &Account,
+) -> Result<AddressLookupTableAccount, error::ClientError> {
+    if account.owner != solana_address_lookup_table_interface::program::id() {
+        return Err(error::ClientError::InvalidAccountOwner {
+            expected: solana_address_lookup_table_interface::program::id(),
+            found: account.owner,
+        });
+    }
+
+    let table = AddressLookupTable::deserialize(&account.data).map_err(|e| {
+        error::ClientError::OtherError(format!("Invalid address lookup table {}: {}", address, e))
+    })?;
+
+    Ok(AddressLookupTableAccount {
+        key: address,
+        addresses: table.addresses.to_vec(),
+    })
+}
+
+/// Removes duplicated addresses, keeping the first occurrence of each one
+fn dedup(addresses: impl IntoIterator<Item = Pubkey>) -> Vec<Pubkey> {
+    let mut deduped: Vec<Pubkey> = Vec::new();
+    for address in addresses {
+        if !deduped.contains(&address) {
+            deduped.push(address);
+        }
+    }
+    deduped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::types::{Cluster, PriorityFee, TransactionFormat},
+        instructions,
+        utils::transaction::PumpFunTransaction,
+    };
+    use base64::Engine;
+    use solana_address_lookup_table_interface::state::LookupTableMeta;
+    use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+    use solana_sdk::{
+        commitment_config::CommitmentConfig, hash::Hash, signature::Keypair, signer::Signer,
+    };
+    use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+    #[test]
+    fn test_trade_accounts() {
+        let fee_recipient = Pubkey::new_unique();
+        let global = GlobalAccount::new(
+            1,
+            true,
+            Pubkey::new_unique(),
+            fee_recipient,
+            1000,
+            1000,
+            500,
+            1000,
+            250,
+            Pubkey::new_unique(),
+            true,
+            100,
+            0,
+            [fee_recipient; 7],
+            Pubkey::new_unique(),
+        );
+        let user = Pubkey::new_unique();
+
+        let static_accounts = get_static_accounts(&global);
+        assert_eq!(static_accounts.len(), 13);
+        assert!(static_accounts.contains(&fee_recipient));
+        assert!(static_accounts.contains(&PumpFun::get_global_pda()));
+        assert!(static_accounts.contains(&constants::accounts::EVENT_AUTHORITY));
+
+        // Fee recipients and users listed twice are only stored once
+        let accounts = get_trade_accounts(&global, &[user, user]);
+        assert_eq!(accounts.len(), static_accounts.len() + 2);
+        assert_eq!(
+            accounts[static_accounts.len()..],
+            get_user_accounts(&user)[..]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_lookup_tables() {
+        let table = Pubkey::new_unique();
+        let data = AddressLookupTable {
+            meta: LookupTableMeta::new(Pubkey::new_unique()),
+            addresses: Cow::Owned(vec![constants::accounts::EVENT_AUTHORITY]),
+        }
+        .serialize_for_tests()
+        .unwrap();
+        let mut mocks = HashMap::new();
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            serde_json::json!({
+                "context": { "slot": 1 },
+                "value": {
+                    "lamports": 1_000_000,
+                    "data": [base64::engine::general_purpose::STANDARD.encode(&data), "base64"],
+                    "owner": solana_address_lookup_table_interface::program::id().to_string(),
+                    "executable": false,
+                    "rentEpoch": 0,
+                    "space": data.len(),
+                },
+            }),
+        );
+        let cluster = Cluster::localnet(CommitmentConfig::confirmed(), PriorityFee::default());
+        let mut client = PumpFun::new(Arc::new(Keypair::new()), cluster);
+        client.transaction_format = TransactionFormat::Legacy;
+        client.rpc = Arc::new(RpcClient::new_mock_with_mocks(
+            "succeeds".to_string(),
+            mocks,
+        ));
+
+        // Loading tables switches the client to v0 transactions, compiled against them
+        client.load_lookup_tables(&[table]).await.unwrap();
+        assert_eq!(client.transaction_format, TransactionFormat::V0);
+
+        let transaction = client
+            .tx()
+            .instruction(instructions::collect_creator_fee(&client.payer.pubkey()))
+            .sign_with_blockhash(&Hash::new_unique())
+            .unwrap();
+        let PumpFunTransaction::V0(transaction) = transaction else {
+            panic!("expected a v0 transaction");
+        };
+        let lookups = transaction.message.address_table_lookups().unwrap();
+        assert_eq!(lookups.len(), 1);
+        assert_eq!(lookups[0].account_key, table);
+    }
+}
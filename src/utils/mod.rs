@@ -3,6 +3,10 @@
 //! This module provides functionality for creating and managing token metadata,
 //! including uploading image and metadata to IPFS via the Pump.fun API.
 
+pub mod builder;
+pub mod confirmation;
+pub mod failover;
+pub mod lookup_table;
 pub mod transaction;
 
 use isahc::AsyncReadResponseExt;
@@ -1,16 +1,136 @@
 use std::sync::Arc;
 
+use base64::Engine;
+use serde::Serialize;
 use solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
-#[cfg(not(feature = "versioned-tx"))]
-use solana_sdk::transaction::Transaction;
-use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer, hash::Hash};
-#[cfg(feature = "versioned-tx")]
 use solana_sdk::{
-    message::{v0, AddressLookupTableAccount, VersionedMessage},
-    transaction::VersionedTransaction,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, AddressLookupTableAccount, Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
 };
 
-use crate::error;
+use crate::{common::types::TransactionFormat, error};
+
+/// Transaction in the legacy or v0 format
+///
+/// Returned by the functions building transactions, whose format is chosen at runtime.
+/// Both variants serialize to the wire format of their transaction, so the enum can be
+/// sent, simulated and encoded like the transaction it holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum PumpFunTransaction {
+    /// Legacy transaction
+    Legacy(Transaction),
+    /// Versioned transaction with a v0 message, which can use address lookup tables
+    V0(VersionedTransaction),
+}
+
+impl PumpFunTransaction {
+    /// Gets the format of the transaction
+    pub fn format(&self) -> TransactionFormat {
+        match self {
+            Self::Legacy(_) => TransactionFormat::Legacy,
+            Self::V0(_) => TransactionFormat::V0,
+        }
+    }
+
+    /// Gets the signatures of the transaction, in the order of its required signers
+    pub fn signatures(&self) -> &[Signature] {
+        match self {
+            Self::Legacy(transaction) => &transaction.signatures,
+            Self::V0(transaction) => &transaction.signatures,
+        }
+    }
+
+    /// Gets the serialized message of the transaction, which is signed by the signers
+    pub fn message_data(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy(transaction) => transaction.message_data(),
+            Self::V0(transaction) => transaction.message.serialize(),
+        }
+    }
+
+    /// Verifies the signatures of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns whether each signature is valid, in the order of the required signers
+    pub fn verify_with_results(&self) -> Vec<bool> {
+        match self {
+            Self::Legacy(transaction) => transaction.verify_with_results(),
+            Self::V0(transaction) => transaction.verify_with_results(),
+        }
+    }
+
+    /// Converts the transaction into a versioned transaction, keeping a legacy message
+    pub fn into_versioned(self) -> VersionedTransaction {
+        match self {
+            Self::Legacy(transaction) => transaction.into(),
+            Self::V0(transaction) => transaction,
+        }
+    }
+
+    /// Gets the public keys of the required signers and the signatures of the transaction
+    fn get_signers_mut(&mut self) -> (&[Pubkey], &mut [Signature]) {
+        let (account_keys, signatures) = match self {
+            Self::Legacy(transaction) => (
+                &transaction.message.account_keys[..],
+                &mut transaction.signatures,
+            ),
+            Self::V0(transaction) => (
+                transaction.message.static_account_keys(),
+                &mut transaction.signatures,
+            ),
+        };
+        (&account_keys[..signatures.len()], signatures)
+    }
+}
+
+impl From<Transaction> for PumpFunTransaction {
+    fn from(transaction: Transaction) -> Self {
+        Self::Legacy(transaction)
+    }
+}
+
+impl From<VersionedTransaction> for PumpFunTransaction {
+    fn from(transaction: VersionedTransaction) -> Self {
+        match transaction.message {
+            VersionedMessage::Legacy(_) => Self::Legacy(
+                transaction
+                    .into_legacy_transaction()
+                    .expect("legacy message converts to a legacy transaction"),
+            ),
+            VersionedMessage::V0(_) => Self::V0(transaction),
+        }
+    }
+}
+
+impl SerializableTransaction for PumpFunTransaction {
+    fn get_signature(&self) -> &Signature {
+        match self {
+            Self::Legacy(transaction) => transaction.get_signature(),
+            Self::V0(transaction) => transaction.get_signature(),
+        }
+    }
+
+    fn get_recent_blockhash(&self) -> &Hash {
+        match self {
+            Self::Legacy(transaction) => transaction.get_recent_blockhash(),
+            Self::V0(transaction) => transaction.get_recent_blockhash(),
+        }
+    }
+
+    fn uses_durable_nonce(&self) -> bool {
+        match self {
+            Self::Legacy(transaction) => transaction.uses_durable_nonce(),
+            Self::V0(transaction) => transaction.uses_durable_nonce(),
+        }
+    }
+}
 
 /// Constructs a signed transaction from a set of instructions and signers
 ///
@@ -23,16 +143,16 @@ use crate::error;
 /// * `rpc` - An Arc-wrapped RpcClient used to fetch the recent blockhash
 /// * `payer` - The primary account that will pay for the transaction fees
 /// * `instructions` - Slice of Solana instructions to include in the transaction
-/// * `additional_signers` - Optional slice of additional keypair signers that should sign the transaction,
+/// * `additional_signers` - Optional slice of additional signers that should sign the transaction,
 ///   in addition to the payer
+/// * `format` - Format of the transaction, legacy or v0
 /// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include,
-///   enabling versioned transactions with address table lookups
-///   (only available with "versioned-tx" feature)
+///   enabling address table lookups in v0 transactions (ignored by legacy transactions)
 ///
 /// # Returns
 ///
-/// Returns a signed Transaction (or VersionedTransaction when the "versioned-tx" feature is enabled)
-/// if successful, or a ClientError if the operation fails
+/// Returns a signed transaction in the requested format if successful, or a ClientError if the
+/// operation fails
 ///
 /// # Errors
 ///
@@ -40,18 +160,14 @@ use crate::error;
 /// - Failed to retrieve the recent blockhash from the network
 /// - Transaction creation fails due to invalid parameters
 /// - Transaction message compilation fails (for versioned transactions)
+/// - A signer required by the instructions is missing
 /// - Transaction signing fails
 ///
-/// # Feature flags
-///
-/// When compiled with the "versioned-tx" feature, this function returns a VersionedTransaction
-/// that supports Address Lookup Tables. Otherwise, it returns a standard Transaction.
-///
 /// # Examples
 ///
 /// ```no_run
 /// # use pumpfun::{
-/// #     common::types::{Cluster, PriorityFee},
+/// #     common::types::{Cluster, PriorityFee, TransactionFormat},
 /// #     utils::transaction::get_transaction,
 /// #     PumpFun,
 /// # };
@@ -74,8 +190,8 @@ use crate::error;
 ///     client.payer.clone(),
 ///     &instructions,
 ///     Some(&[&custom_signer]),
-/// #   #[cfg(feature = "versioned-tx")]
-/// #   None,
+///     TransactionFormat::Legacy,
+///     None,
 /// )
 /// .await?;
 ///
@@ -85,19 +201,19 @@ use crate::error;
 ///     client.payer.clone(),
 ///     &instructions,
 ///     None,
-/// #   #[cfg(feature = "versioned-tx")]
-/// #   None,
+///     TransactionFormat::Legacy,
+///     None,
 /// )
 /// .await?;
 ///
-/// // Create a versioned transaction with address lookup tables (when "versioned-tx" feature is enabled)
+/// // Create a versioned transaction with address lookup tables
 /// let lookup_tables: Vec<AddressLookupTableAccount> = Vec::new();
 /// let transaction = get_transaction(
 ///     client.rpc.clone(),
 ///     client.payer.clone(),
 ///     &instructions,
 ///     None,
-/// #   #[cfg(feature = "versioned-tx")]
+///     TransactionFormat::V0,
 ///     Some(&lookup_tables),
 /// )
 /// .await?;
@@ -106,125 +222,392 @@ use crate::error;
 /// ```
 pub async fn get_transaction(
     rpc: Arc<RpcClient>,
-    payer: Arc<Keypair>,
+    payer: Arc<dyn Signer + Send + Sync>,
     instructions: &[Instruction],
-    additional_signers: Option<&[&Keypair]>,
-    #[cfg(feature = "versioned-tx")] address_lookup_table_accounts: Option<
-        &[AddressLookupTableAccount],
-    >,
-) -> Result<impl SerializableTransaction, error::ClientError> {
+    additional_signers: Option<&[&dyn Signer]>,
+    format: TransactionFormat,
+    address_lookup_table_accounts: Option<&[AddressLookupTableAccount]>,
+) -> Result<PumpFunTransaction, error::ClientError> {
     // Get recent blockhash for transaction validity window
     let recent_blockhash = rpc
         .get_latest_blockhash()
         .await
         .map_err(error::ClientError::SolanaClientError)?;
 
+    get_transaction_offline_prepared(
+        &recent_blockhash,
+        rpc,
+        payer,
+        instructions,
+        additional_signers,
+        format,
+        address_lookup_table_accounts,
+    )
+}
+
+/// Constructs a signed transaction from a set of instructions and signers with a given blockhash
+///
+/// Same as `get_transaction`, without accessing the network.
+///
+/// # Arguments
+///
+/// * `recent_blockhash` - Recent blockhash determining the validity window of the transaction
+/// * `_rpc` - Unused RPC client, kept for symmetry with `get_transaction`
+/// * `payer` - The primary account that will pay for the transaction fees
+/// * `instructions` - Slice of Solana instructions to include in the transaction
+/// * `additional_signers` - Optional slice of additional signers that should sign the transaction,
+///   in addition to the payer
+/// * `format` - Format of the transaction, legacy or v0
+/// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include,
+///   enabling address table lookups in v0 transactions (ignored by legacy transactions)
+///
+/// # Returns
+///
+/// Returns a signed transaction in the requested format if successful, or a ClientError if the
+/// operation fails
+pub fn get_transaction_offline_prepared(
+    recent_blockhash: &Hash,
+    _rpc: Arc<RpcClient>,
+    payer: Arc<dyn Signer + Send + Sync>,
+    instructions: &[Instruction],
+    additional_signers: Option<&[&dyn Signer]>,
+    format: TransactionFormat,
+    address_lookup_table_accounts: Option<&[AddressLookupTableAccount]>,
+) -> Result<PumpFunTransaction, error::ClientError> {
     // Create a combined signers array with payer and additional signers
     let mut all_signers =
         Vec::with_capacity(1 + additional_signers.map_or(0, |signers| signers.len()));
-    all_signers.push(&*payer);
+    all_signers.push(payer.as_ref() as &dyn Signer);
 
     if let Some(signers) = additional_signers {
         all_signers.extend(signers);
     }
 
-    // Create and sign legacy transaction with all signers
-    #[cfg(not(feature = "versioned-tx"))]
-    let transaction = Transaction::new_signed_with_payer(
-        instructions,
-        Some(&payer.pubkey()),
-        &all_signers,
+    let mut transaction = compile_transaction(
         recent_blockhash,
-    );
-
-    // Create and sign versioned transaction with all signers
-    #[cfg(feature = "versioned-tx")]
-    let transaction = {
-        let message = match v0::Message::try_compile(
-            &payer.pubkey(),
-            instructions,
-            address_lookup_table_accounts.unwrap_or(&[]),
-            recent_blockhash,
-        ) {
-            Ok(msg) => VersionedMessage::V0(msg),
-            Err(e) => {
-                return Err(error::ClientError::OtherError(format!(
-                    "Failed to compile transaction message: {}",
-                    e
-                )))
-            }
-        };
+        &payer.pubkey(),
+        instructions,
+        format,
+        address_lookup_table_accounts,
+    )?;
 
-        match VersionedTransaction::try_new(message, &all_signers) {
-            Ok(tx) => tx,
-            Err(e) => {
-                return Err(error::ClientError::OtherError(format!(
-                    "Failed to sign transaction: {}",
-                    e
-                )))
-            }
-        }
-    };
+    // Every required signer has to sign the transaction
+    let (required_signers, _) = transaction.get_signers_mut();
+    if let Some(missing) = required_signers
+        .iter()
+        .find(|pubkey| !all_signers.iter().any(|signer| signer.pubkey() == **pubkey))
+    {
+        return Err(error::ClientError::OtherError(format!(
+            "Failed to sign transaction: missing signer {}",
+            missing
+        )));
+    }
 
+    sign_transaction(&mut transaction, &all_signers)?;
     Ok(transaction)
 }
 
-pub fn get_transaction_offline_prepared(
+/// Constructs a transaction from a set of instructions without the payer's signature
+///
+/// The transaction is meant to be handed off to external signers, such as a hardware
+/// wallet or a multisig, before being sent. Its signatures are left empty, except the
+/// ones of the additional signers, which sign it partially.
+///
+/// # Arguments
+///
+/// * `recent_blockhash` - Recent blockhash determining the validity window of the transaction
+/// * `payer` - Public key of the account that will pay for the transaction fees
+/// * `instructions` - Slice of Solana instructions to include in the transaction
+/// * `additional_signers` - Optional slice of additional signers that sign the transaction partially
+/// * `format` - Format of the transaction, legacy or v0
+/// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include,
+///   enabling address table lookups in v0 transactions (ignored by legacy transactions)
+///
+/// # Returns
+///
+/// Returns a partially signed transaction in the requested format if successful, or a
+/// ClientError if the operation fails
+pub fn get_unsigned_transaction(
     recent_blockhash: &Hash,
-    _rpc: Arc<RpcClient>,
-    payer: Arc<Keypair>,
+    payer: &Pubkey,
     instructions: &[Instruction],
-    additional_signers: Option<&[&Keypair]>,
-    #[cfg(feature = "versioned-tx")] address_lookup_table_accounts: Option<
-        &[AddressLookupTableAccount],
-    >,
-) -> Result<impl SerializableTransaction, error::ClientError>  {
-    // Create a combined signers array with payer and additional signers
-    let mut all_signers =
-        Vec::with_capacity(1 + additional_signers.map_or(0, |signers| signers.len()));
-    all_signers.push(&*payer);
+    additional_signers: Option<&[&dyn Signer]>,
+    format: TransactionFormat,
+    address_lookup_table_accounts: Option<&[AddressLookupTableAccount]>,
+) -> Result<PumpFunTransaction, error::ClientError> {
+    let mut transaction = compile_transaction(
+        recent_blockhash,
+        payer,
+        instructions,
+        format,
+        address_lookup_table_accounts,
+    )?;
 
-    if let Some(signers) = additional_signers {
-        all_signers.extend(signers);
+    // Sign partially with the additional signers
+    sign_transaction(&mut transaction, additional_signers.unwrap_or(&[]))?;
+    Ok(transaction)
+}
+
+/// Serializes a transaction to base64, the encoding used by wallets and the RPC API
+///
+/// # Arguments
+///
+/// * `transaction` - Signed, partially signed or unsigned transaction
+///
+/// # Returns
+///
+/// Returns the base64 encoded transaction, or a ClientError if it cannot be serialized
+pub fn encode_transaction(
+    transaction: &impl SerializableTransaction,
+) -> Result<String, error::ClientError> {
+    let data = bincode::serialize(transaction).map_err(|e| {
+        error::ClientError::OtherError(format!("Failed to serialize transaction: {}", e))
+    })?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(data))
+}
+
+/// Deserializes a base64 encoded transaction
+///
+/// Both legacy and v0 transactions are decoded, in their own format.
+///
+/// # Arguments
+///
+/// * `encoded` - Base64 encoded transaction, such as one returned by `encode_transaction`
+///
+/// # Returns
+///
+/// Returns the decoded transaction, or a ClientError if it cannot be deserialized
+pub fn decode_transaction(encoded: &str) -> Result<PumpFunTransaction, error::ClientError> {
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| {
+            error::ClientError::OtherError(format!("Failed to decode transaction: {}", e))
+        })?;
+    let transaction: VersionedTransaction = bincode::deserialize(&data).map_err(|e| {
+        error::ClientError::OtherError(format!("Failed to deserialize transaction: {}", e))
+    })?;
+    Ok(transaction.into())
+}
+
+/// Attaches external signatures to a transaction
+///
+/// Each signature is verified against the message of the transaction, and placed at the
+/// position of its signer among the required signers.
+///
+/// # Arguments
+///
+/// * `transaction` - Transaction to attach the signatures to
+/// * `signatures` - Public keys of the signers with their signatures of the transaction message
+///
+/// # Returns
+///
+/// Returns Ok if all signatures were attached, or a ClientError if a public key is not a
+/// required signer of the transaction or a signature is invalid
+pub fn add_signatures(
+    transaction: &mut PumpFunTransaction,
+    signatures: &[(Pubkey, Signature)],
+) -> Result<(), error::ClientError> {
+    for (pubkey, signature) in signatures {
+        set_signature(transaction, pubkey, *signature)?;
     }
+    Ok(())
+}
 
-    // Create and sign legacy transaction with all signers
-    #[cfg(not(feature = "versioned-tx"))]
-    let transaction = Transaction::new_signed_with_payer(
-        instructions,
-        Some(&payer.pubkey()),
-        &all_signers,
-        *recent_blockhash,
-    );
-
-    // Create and sign versioned transaction with all signers
-    #[cfg(feature = "versioned-tx")]
-    let transaction = {
-        let message = match v0::Message::try_compile(
-            &payer.pubkey(),
-            instructions,
-            address_lookup_table_accounts.unwrap_or(&[]),
-            *recent_blockhash,
-        ) {
-            Ok(msg) => VersionedMessage::V0(msg),
-            Err(e) => {
-                return Err(error::ClientError::OtherError(format!(
+/// Places a verified signature at the position of its signer
+fn set_signature(
+    transaction: &mut PumpFunTransaction,
+    pubkey: &Pubkey,
+    signature: Signature,
+) -> Result<(), error::ClientError> {
+    let message_data = transaction.message_data();
+    let (account_keys, signatures) = transaction.get_signers_mut();
+    let index = account_keys
+        .iter()
+        .position(|key| key == pubkey)
+        .ok_or_else(|| {
+            error::ClientError::OtherError(format!(
+                "{} is not a required signer of the transaction",
+                pubkey
+            ))
+        })?;
+
+    if !signature.verify(pubkey.as_ref(), &message_data) {
+        return Err(error::ClientError::OtherError(format!(
+            "Invalid signature of {}",
+            pubkey
+        )));
+    }
+
+    signatures[index] = signature;
+    Ok(())
+}
+
+/// Compiles the message of a transaction in the requested format, with empty signatures
+fn compile_transaction(
+    recent_blockhash: &Hash,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    format: TransactionFormat,
+    address_lookup_table_accounts: Option<&[AddressLookupTableAccount]>,
+) -> Result<PumpFunTransaction, error::ClientError> {
+    match format {
+        TransactionFormat::Legacy => Ok(PumpFunTransaction::Legacy(Transaction::new_unsigned(
+            Message::new_with_blockhash(instructions, Some(payer), recent_blockhash),
+        ))),
+        TransactionFormat::V0 => {
+            let message = v0::Message::try_compile(
+                payer,
+                instructions,
+                address_lookup_table_accounts.unwrap_or(&[]),
+                *recent_blockhash,
+            )
+            .map_err(|e| {
+                error::ClientError::OtherError(format!(
                     "Failed to compile transaction message: {}",
                     e
-                )))
-            }
-        };
+                ))
+            })?;
 
-        match VersionedTransaction::try_new(message, &all_signers) {
-            Ok(tx) => tx,
-            Err(e) => {
-                return Err(error::ClientError::OtherError(format!(
-                    "Failed to sign transaction: {}",
-                    e
-                )))
-            }
+            Ok(PumpFunTransaction::V0(VersionedTransaction {
+                signatures: vec![
+                    Signature::default();
+                    usize::from(message.header.num_required_signatures)
+                ],
+                message: VersionedMessage::V0(message),
+            }))
         }
+    }
+}
+
+/// Signs a transaction with some of its required signers
+fn sign_transaction(
+    transaction: &mut PumpFunTransaction,
+    signers: &[&dyn Signer],
+) -> Result<(), error::ClientError> {
+    let message_data = transaction.message_data();
+    let (account_keys, signatures) = transaction.get_signers_mut();
+    for signer in signers {
+        let pubkey = signer.pubkey();
+        let index = account_keys
+            .iter()
+            .position(|key| *key == pubkey)
+            .ok_or_else(|| {
+                error::ClientError::OtherError(format!(
+                    "{} is not a required signer of the transaction",
+                    pubkey
+                ))
+            })?;
+        signatures[index] = signer.try_sign_message(&message_data).map_err(|e| {
+            error::ClientError::OtherError(format!("Failed to sign transaction: {}", e))
+        })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        instruction::AccountMeta, signature::Keypair, signer::null_signer::NullSigner,
     };
 
-    Ok(transaction)
+    fn get_instruction(signers: &[&Keypair]) -> Instruction {
+        Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            signers
+                .iter()
+                .map(|signer| AccountMeta::new(signer.pubkey(), true))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_transaction_format() {
+        let payer = Arc::new(Keypair::new());
+        let mint = Keypair::new();
+        let blockhash = Hash::new_unique();
+        let instructions = vec![get_instruction(&[&payer, &mint])];
+
+        for format in [TransactionFormat::Legacy, TransactionFormat::V0] {
+            let transaction = get_transaction_offline_prepared(
+                &blockhash,
+                Arc::new(RpcClient::new_mock("succeeds".to_string())),
+                payer.clone(),
+                &instructions,
+                Some(&[&mint]),
+                format,
+                None,
+            )
+            .unwrap();
+            assert_eq!(transaction.format(), format);
+            assert_eq!(transaction.get_recent_blockhash(), &blockhash);
+            assert_eq!(transaction.verify_with_results(), vec![true, true]);
+
+            // Round trip through the wire format keeps the format
+            let decoded = decode_transaction(&encode_transaction(&transaction).unwrap()).unwrap();
+            assert_eq!(decoded, transaction);
+
+            // Missing signers are reported instead of leaving empty signatures
+            assert!(get_transaction_offline_prepared(
+                &blockhash,
+                Arc::new(RpcClient::new_mock("succeeds".to_string())),
+                payer.clone(),
+                &instructions,
+                None,
+                format,
+                None,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_unsigned_transaction() {
+        let payer = Keypair::new();
+        let mint = Keypair::new();
+        let instructions = vec![get_instruction(&[&payer, &mint])];
+
+        for format in [TransactionFormat::Legacy, TransactionFormat::V0] {
+            let transaction = get_unsigned_transaction(
+                &Hash::new_unique(),
+                &payer.pubkey(),
+                &instructions,
+                Some(&[&mint]),
+                format,
+                None,
+            )
+            .unwrap();
+
+            // Only the additional signer signed the exported transaction
+            let mut transaction =
+                decode_transaction(&encode_transaction(&transaction).unwrap()).unwrap();
+            assert_eq!(transaction.format(), format);
+            assert_eq!(transaction.verify_with_results(), vec![false, true]);
+
+            // Signatures of other accounts, or invalid ones, are rejected
+            let message = transaction.message_data();
+            let other = Keypair::new();
+            assert!(add_signatures(
+                &mut transaction,
+                &[(other.pubkey(), other.sign_message(&message))]
+            )
+            .is_err());
+            assert!(add_signatures(
+                &mut transaction,
+                &[(
+                    payer.pubkey(),
+                    NullSigner::new(&payer.pubkey()).sign_message(&message)
+                )]
+            )
+            .is_err());
+
+            add_signatures(
+                &mut transaction,
+                &[(payer.pubkey(), payer.sign_message(&message))],
+            )
+            .unwrap();
+            assert_eq!(transaction.verify_with_results(), vec![true, true]);
+        }
+    }
 }
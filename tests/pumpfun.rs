@@ -115,3 +115,30 @@ async fn test_04_sell_token() {
         .expect("Failed to sell tokens");
     println!("Signature: {}", signature);
 }
+
+#[cfg(not(skip_expensive_tests))]
+#[tokio::test]
+#[serial]
+async fn test_05_collect_creator_fee() {
+    if std::env::var("SKIP_EXPENSIVE_TESTS").is_ok() {
+        return;
+    }
+
+    let ctx = TestContext::default();
+
+    let balance = ctx
+        .client
+        .get_creator_vault_balance(&ctx.payer.pubkey())
+        .await
+        .expect("Failed to get creator vault balance");
+    println!("Creator vault balance: {}", balance);
+
+    if balance > 0 {
+        let signature = ctx
+            .client
+            .collect_creator_fee(None)
+            .await
+            .expect("Failed to collect creator fee");
+        println!("Signature: {}", signature);
+    }
+}